@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> FromBits for ComputeKey<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new compute key from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let group_size_in_bits = A::BaseField::size_in_bits();
+        if bits_le.len() != 2 * group_size_in_bits {
+            A::halt("Invalid compute key size in bits")
+        }
+
+        let (pk_sig_bits, pr_sig_bits) = bits_le.split_at(group_size_in_bits);
+        Self::from_groups(Group::from_bits_le(pk_sig_bits), Group::from_bits_le(pr_sig_bits))
+    }
+
+    /// Initializes a new compute key from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let group_size_in_bits = A::BaseField::size_in_bits();
+        if bits_be.len() != 2 * group_size_in_bits {
+            A::halt("Invalid compute key size in bits")
+        }
+
+        let (pk_sig_bits, pr_sig_bits) = bits_be.split_at(group_size_in_bits);
+        Self::from_groups(Group::from_bits_be(pk_sig_bits), Group::from_bits_be(pr_sig_bits))
+    }
+}
+
+impl<A: Aleo> ComputeKey<A> {
+    /// Initializes a compute key from `(pk_sig, pr_sig)`, deriving `sk_prf`.
+    fn from_groups(pk_sig: Group<A>, pr_sig: Group<A>) -> Self {
+        // Compute `sk_prf` := HashToScalar(G^sk_sig || G^r_sig).
+        let sk_prf = A::hash_to_scalar_psd4(&[pk_sig.to_x_coordinate(), pr_sig.to_x_coordinate()]);
+        // Output the compute key.
+        Self { pk_sig, pr_sig, sk_prf }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_from_bits(mode: Mode) -> Result<()> {
+        for i in 0..ITERATIONS {
+            // Generate a private key, compute key, view key, and address.
+            let (_private_key, compute_key, _view_key, _address) = generate_account()?;
+            let candidate = ComputeKey::<Circuit>::new(mode, compute_key);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                assert_eq!(compute_key, ComputeKey::from_bits_le(&candidate.to_bits_le()).eject_value());
+                assert_eq!(compute_key, ComputeKey::from_bits_be(&candidate.to_bits_be()).eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bits_constant() -> Result<()> {
+        check_from_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_bits_public() -> Result<()> {
+        check_from_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_bits_private() -> Result<()> {
+        check_from_bits(Mode::Private)
+    }
+}
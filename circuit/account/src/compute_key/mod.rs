@@ -14,16 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod from_bits;
 mod from_private_key;
 mod to_address;
+mod to_bits;
 
 #[cfg(test)]
 use snarkvm_circuit_types::environment::assert_scope;
 
 use crate::PrivateKey;
 use snarkvm_circuit_network::Aleo;
-use snarkvm_circuit_types::{environment::prelude::*, Address, Group, Scalar};
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Group, Scalar};
 
+#[derive(Clone)]
 pub struct ComputeKey<A: Aleo> {
     /// The signature public key `pk_sig` := G^sk_sig.
     pk_sig: Group<A>,
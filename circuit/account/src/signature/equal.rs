@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> Equal<Self> for Signature<A> {
+    type Output = Boolean<A>;
+
+    /// Returns `true` if `self` and `other` are equal.
+    fn is_equal(&self, other: &Self) -> Self::Output {
+        // Note: `sk_prf` is derived from `(pk_sig, pr_sig)`, so it is not compared.
+        self.challenge.is_equal(&other.challenge)
+            & self.response.is_equal(&other.response)
+            & self.compute_key.pk_sig().is_equal(other.compute_key.pk_sig())
+            & self.compute_key.pr_sig().is_equal(other.compute_key.pr_sig())
+    }
+
+    /// Returns `true` if `self` and `other` are *not* equal.
+    fn is_not_equal(&self, other: &Self) -> Self::Output {
+        !self.is_equal(other)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_equal() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Generate two signatures.
+            let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let first = console::Signature::sign(&private_key, &message, rng)?;
+            let second = console::Signature::sign(&private_key, &message, rng)?;
+
+            let a = Signature::<Circuit>::new(Mode::Private, first);
+            let b = Signature::<Circuit>::new(Mode::Private, first);
+            let c = Signature::<Circuit>::new(Mode::Private, second);
+
+            Circuit::scope(format!("Equal {i}"), || {
+                assert!(a.is_equal(&b).eject_value());
+                assert!(!a.is_not_equal(&b).eject_value());
+                assert!(!a.is_equal(&c).eject_value());
+                assert!(a.is_not_equal(&c).eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+}
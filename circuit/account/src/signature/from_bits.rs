@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new signature from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        let scalar_size_in_bits = A::ScalarField::size_in_bits();
+        if bits_le.len() != 2 * scalar_size_in_bits + 2 * A::BaseField::size_in_bits() {
+            A::halt("Invalid signature size in bits")
+        }
+
+        let (challenge_bits, bits_le) = bits_le.split_at(scalar_size_in_bits);
+        let (response_bits, compute_key_bits) = bits_le.split_at(scalar_size_in_bits);
+        Self {
+            challenge: Scalar::from_bits_le(challenge_bits),
+            response: Scalar::from_bits_le(response_bits),
+            compute_key: ComputeKey::from_bits_le(compute_key_bits),
+        }
+    }
+
+    /// Initializes a new signature from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        let scalar_size_in_bits = A::ScalarField::size_in_bits();
+        if bits_be.len() != 2 * scalar_size_in_bits + 2 * A::BaseField::size_in_bits() {
+            A::halt("Invalid signature size in bits")
+        }
+
+        let (challenge_bits, bits_be) = bits_be.split_at(scalar_size_in_bits);
+        let (response_bits, compute_key_bits) = bits_be.split_at(scalar_size_in_bits);
+        Self {
+            challenge: Scalar::from_bits_be(challenge_bits),
+            response: Scalar::from_bits_be(response_bits),
+            compute_key: ComputeKey::from_bits_be(compute_key_bits),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_from_bits(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Generate a signature.
+            let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = console::Signature::sign(&private_key, &message, rng)?;
+            let candidate = Signature::<Circuit>::new(mode, signature);
+
+            Circuit::scope(format!("{mode} {i}"), || {
+                assert_eq!(signature, Signature::from_bits_le(&candidate.to_bits_le()).eject_value());
+                assert_eq!(signature, Signature::from_bits_be(&candidate.to_bits_be()).eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bits_constant() -> Result<()> {
+        check_from_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_from_bits_public() -> Result<()> {
+        check_from_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_from_bits_private() -> Result<()> {
+        check_from_bits(Mode::Private)
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Returns the Keccak hash of the given input as bits.
+    pub fn hash(&self, input: &[Boolean<E>]) -> Vec<Boolean<E>> {
+        // Ensure the output size is supported.
+        if VARIANT == 0 || VARIANT > Self::RATE {
+            E::halt(format!("Invalid Keccak output size of {VARIANT} bits"))
+        }
+
+        // Absorb the padded input into the state.
+        let mut state = vec![Boolean::constant(false); PERMUTATION_WIDTH];
+        for block in Self::pad(input).chunks(Self::RATE) {
+            state.iter_mut().zip(block).for_each(|(bit, input_bit)| *bit ^= input_bit);
+            Self::permute(&mut state);
+        }
+        // Squeeze the output from the state.
+        state.truncate(VARIANT);
+        state
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    const ITERATIONS: usize = 3;
+
+    fn check_hash<const TYPE: u8>(
+        mode: Mode,
+        num_inputs: usize,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+        rng: &mut TestRng,
+    ) {
+        use console::Hash as H;
+
+        let native = console::Keccak::<TYPE, 256>::new();
+        let keccak = Keccak::<Circuit, TYPE, 256>::new();
+
+        for i in 0..ITERATIONS {
+            // Prepare the preimage.
+            let native_input = (0..num_inputs).map(|_| bool::rand(rng)).collect::<Vec<bool>>();
+            let input = native_input.iter().map(|v| Boolean::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Compute the native hash.
+            let expected = native.hash(&native_input).expect("Failed to hash native input");
+
+            // Compute the circuit hash.
+            Circuit::scope(format!("Keccak {mode} {i}"), || {
+                let candidate = keccak.hash(&input);
+                assert_eq!(expected, candidate.eject_value());
+                let case = format!("(mode = {mode}, num_inputs = {num_inputs})");
+                assert_scope!(case, num_constants, num_public, num_private, num_constraints);
+            });
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_keccak256_constant() {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Constant, 8, 0, 0, 0, 0, &mut rng);
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Constant, 1200, 0, 0, 0, 0, &mut rng);
+    }
+
+    #[test]
+    fn test_keccak256_public() {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Public, 8, 0, 0, 142132, 142132, &mut rng);
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Public, 1200, 0, 0, 306288, 306288, &mut rng);
+    }
+
+    #[test]
+    fn test_keccak256_private() {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Private, 8, 0, 0, 142132, 142132, &mut rng);
+        check_hash::<{ KeccakType::Keccak as u8 }>(Mode::Private, 1200, 0, 0, 306288, 306288, &mut rng);
+    }
+
+    #[test]
+    fn test_sha3_256_private() {
+        let mut rng = TestRng::default();
+
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Private, 8, 0, 0, 142156, 142156, &mut rng);
+        check_hash::<{ KeccakType::Sha3 as u8 }>(Mode::Private, 1200, 0, 0, 306288, 306288, &mut rng);
+    }
+}
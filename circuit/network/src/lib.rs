@@ -84,6 +84,12 @@ pub trait Aleo: Environment {
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[Boolean<Self>]) -> Field<Self>;
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Field<Self>;
 
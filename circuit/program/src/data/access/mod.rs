@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Identifier;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, U32};
+
+/// A helper type for accessing an entry in a register, struct, array, or record.
+/// Note: An access is always `Mode::Constant`, as it is statically defined in the program.
+#[derive(Clone)]
+pub enum Access<A: Aleo> {
+    /// The access is a member of a struct or record.
+    Member(Identifier<A>),
+    /// The access is an index into an array.
+    Index(U32<A>),
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Initializes a new access from a primitive.
+    fn new(_: Mode, access: Self::Primitive) -> Self {
+        match access {
+            Self::Primitive::Member(identifier) => Self::Member(Identifier::constant(identifier)),
+            Self::Primitive::Index(index) => Self::Index(U32::constant(index)),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Ejects the mode of the access.
+    fn eject_mode(&self) -> Mode {
+        match self {
+            Self::Member(identifier) => identifier.eject_mode(),
+            Self::Index(index) => index.eject_mode(),
+        }
+    }
+
+    /// Ejects the access.
+    fn eject_value(&self) -> Self::Primitive {
+        match self {
+            Self::Member(identifier) => console::Access::Member(identifier.eject_value()),
+            Self::Index(index) => console::Access::Index(index.eject_value()),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Debug for Access<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Display for Access<A> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.eject_value())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    #[test]
+    fn test_access() -> Result<()> {
+        for string in [".owner", ".foo_bar", "[0u32]", "[31u32]"] {
+            let expected = console::Access::<<Circuit as Environment>::Network>::from_str(string)?;
+            // Inject the access in each mode, and ensure it is always a constant.
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let candidate = Access::<Circuit>::new(mode, expected);
+                assert_eq!(Mode::Constant, candidate.eject_mode());
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(string, candidate.to_string());
+            }
+        }
+        Ok(())
+    }
+}
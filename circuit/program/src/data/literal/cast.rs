@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+#[cfg(console)]
+impl<A: Aleo> Literal<A> {
+    /// Casts the literal to the given literal type, preserving its value.
+    ///
+    /// The cast is unsatisfiable if the value is not representable in the given literal type:
+    ///   - A boolean, an integer, a field, or a scalar is cast by its integer value, where a boolean is `0` or `1`.
+    ///     A negative signed integer can only be cast to a signed integer.
+    ///   - A group or an address is cast as its **x-coordinate**, unless it is cast to a group or an address.
+    ///     Casting a field to a group or an address is unsatisfiable if the field is not a valid x-coordinate.
+    ///   - A string or a signature cannot be cast, except a signature to itself.
+    pub fn cast(&self, to_type: console::LiteralType) -> Result<Self> {
+        use console::LiteralType;
+
+        match self {
+            Self::Address(address) => match to_type {
+                LiteralType::Address => Ok(self.clone()),
+                LiteralType::Group => Ok(Self::Group(address.to_group())),
+                _ => cast_field(address.to_field(), to_type),
+            },
+            Self::Boolean(boolean) => cast_bits(std::slice::from_ref(boolean), false, to_type),
+            Self::Field(field) => cast_field(field.clone(), to_type),
+            Self::Group(group) => match to_type {
+                LiteralType::Address => Ok(Self::Address(Address::from_group(group.clone()))),
+                LiteralType::Group => Ok(self.clone()),
+                _ => cast_field(group.to_x_coordinate(), to_type),
+            },
+            Self::I8(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I16(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I32(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I64(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I128(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::U8(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U16(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U32(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U64(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U128(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::Scalar(scalar) => cast_field(scalar.to_field(), to_type),
+            Self::String(..) => bail!("Cannot cast a string to '{to_type}'"),
+            Self::Signature(..) => match to_type {
+                LiteralType::Signature => Ok(self.clone()),
+                _ => bail!("Cannot cast a signature to '{to_type}'"),
+            },
+        }
+    }
+}
+
+/// Casts the given field element to the given literal type.
+#[cfg(console)]
+fn cast_field<A: Aleo>(field: Field<A>, to_type: console::LiteralType) -> Result<Literal<A>> {
+    use console::LiteralType;
+
+    // Returns the lower bits of the field element, enforcing that its value is less than `2^num_bits`.
+    // Note: The sign bit of a signed integer is set to `false`, as the field element is not negative.
+    let to_integer_bits = |num_bits: usize, is_signed: bool| {
+        let mut bits_le = field.to_lower_bits_le(num_bits - is_signed as usize);
+        if is_signed {
+            bits_le.push(Boolean::constant(false));
+        }
+        bits_le
+    };
+
+    match to_type {
+        LiteralType::Address => Ok(Literal::Address(Address::from_field(field))),
+        LiteralType::Boolean => Ok(Literal::Boolean(field.to_lower_bits_le(1)[0].clone())),
+        LiteralType::Field => Ok(Literal::Field(field)),
+        LiteralType::Group => Ok(Literal::Group(Group::from_x_coordinate(field))),
+        LiteralType::I8 => Ok(Literal::I8(I8::from_bits_le(&to_integer_bits(8, true)))),
+        LiteralType::I16 => Ok(Literal::I16(I16::from_bits_le(&to_integer_bits(16, true)))),
+        LiteralType::I32 => Ok(Literal::I32(I32::from_bits_le(&to_integer_bits(32, true)))),
+        LiteralType::I64 => Ok(Literal::I64(I64::from_bits_le(&to_integer_bits(64, true)))),
+        LiteralType::I128 => Ok(Literal::I128(I128::from_bits_le(&to_integer_bits(128, true)))),
+        LiteralType::U8 => Ok(Literal::U8(U8::from_bits_le(&to_integer_bits(8, false)))),
+        LiteralType::U16 => Ok(Literal::U16(U16::from_bits_le(&to_integer_bits(16, false)))),
+        LiteralType::U32 => Ok(Literal::U32(U32::from_bits_le(&to_integer_bits(32, false)))),
+        LiteralType::U64 => Ok(Literal::U64(U64::from_bits_le(&to_integer_bits(64, false)))),
+        LiteralType::U128 => Ok(Literal::U128(U128::from_bits_le(&to_integer_bits(128, false)))),
+        LiteralType::Scalar => Ok(Literal::Scalar(Scalar::from_bits_le(&field.to_bits_le()))),
+        LiteralType::String => bail!("Cannot cast a field to a string"),
+        LiteralType::Signature => bail!("Cannot cast a field to a signature"),
+    }
+}
+
+/// Casts the given little-endian bits of an integer to the given literal type.
+/// If `is_signed` is `true`, the bits are interpreted in two's complement.
+#[cfg(console)]
+fn cast_bits<A: Aleo>(bits_le: &[Boolean<A>], is_signed: bool, to_type: console::LiteralType) -> Result<Literal<A>> {
+    use console::LiteralType;
+
+    // Retrieve the sign bit.
+    let sign = match is_signed {
+        true => bits_le.last().cloned().unwrap_or_else(|| Boolean::constant(false)),
+        false => Boolean::constant(false),
+    };
+    // Note: The sign bit of a signed integer is excluded, as it trivially matches itself.
+    let num_magnitude_bits = bits_le.len() - is_signed as usize;
+
+    // Ensures the value is not negative.
+    let assert_not_negative = || {
+        if is_signed {
+            A::assert(!&sign);
+        }
+    };
+
+    // Resizes the bits to the given integer size, enforcing that the value is representable.
+    let resize = |size: usize, to_signed: bool| {
+        // Ensure a negative value is not cast to an unsigned type.
+        if !to_signed {
+            assert_not_negative();
+        }
+        // Ensure the bits above the size of the integer (excluding its sign bit) equal the sign bit.
+        let start = size - to_signed as usize;
+        for bit in bits_le[..num_magnitude_bits].iter().skip(start) {
+            match is_signed {
+                true => A::assert_eq(bit, &sign),
+                false => A::assert(!bit),
+            }
+        }
+        // Sign-extend or truncate the bits to the given size.
+        (0..size).map(|i| bits_le.get(i).cloned().unwrap_or_else(|| sign.clone())).collect::<Vec<_>>()
+    };
+
+    match to_type {
+        LiteralType::Boolean => Ok(Literal::Boolean(resize(1, false)[0].clone())),
+        LiteralType::I8 => Ok(Literal::I8(I8::from_bits_le(&resize(8, true)))),
+        LiteralType::I16 => Ok(Literal::I16(I16::from_bits_le(&resize(16, true)))),
+        LiteralType::I32 => Ok(Literal::I32(I32::from_bits_le(&resize(32, true)))),
+        LiteralType::I64 => Ok(Literal::I64(I64::from_bits_le(&resize(64, true)))),
+        LiteralType::I128 => Ok(Literal::I128(I128::from_bits_le(&resize(128, true)))),
+        LiteralType::U8 => Ok(Literal::U8(U8::from_bits_le(&resize(8, false)))),
+        LiteralType::U16 => Ok(Literal::U16(U16::from_bits_le(&resize(16, false)))),
+        LiteralType::U32 => Ok(Literal::U32(U32::from_bits_le(&resize(32, false)))),
+        LiteralType::U64 => Ok(Literal::U64(U64::from_bits_le(&resize(64, false)))),
+        LiteralType::U128 => Ok(Literal::U128(U128::from_bits_le(&resize(128, false)))),
+        LiteralType::String => bail!("Cannot cast an integer to a string"),
+        LiteralType::Signature => bail!("Cannot cast an integer to a signature"),
+        LiteralType::Address | LiteralType::Field | LiteralType::Group | LiteralType::Scalar => {
+            // Ensure the value is not negative.
+            assert_not_negative();
+            match to_type {
+                LiteralType::Scalar => Ok(Literal::Scalar(Scalar::from_bits_le(bits_le))),
+                _ => cast_field(Field::from_bits_le(bits_le), to_type),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::{LiteralType, TestRng, Uniform};
+    use snarkvm_circuit_types::environment::assert_scope;
+
+    /// Casts the given literal string in the given mode, and checks the result and the constraint counts.
+    fn check_cast(
+        literal: &str,
+        mode: Mode,
+        to_type: LiteralType,
+        num_constants: u64,
+        num_public: u64,
+        num_private: u64,
+        num_constraints: u64,
+    ) {
+        // Compute the expected literal.
+        let expected =
+            console::Literal::<<Circuit as Environment>::Network>::from_str(literal).unwrap().cast(to_type).unwrap();
+        // Inject the literal.
+        let literal = Literal::<Circuit>::new(mode, console::Literal::from_str(literal).unwrap());
+
+        Circuit::scope(format!("{mode} {} as {to_type}", literal.to_type()), || {
+            let candidate = literal.cast(to_type).unwrap();
+            assert_eq!(expected, candidate.eject_value());
+            assert_scope!(num_constants, num_public, num_private, num_constraints);
+        });
+        Circuit::reset();
+    }
+
+    /// Casts the given literal string in the given mode, and checks that the circuit is not satisfied.
+    fn check_cast_fails(literal: &str, mode: Mode, to_type: LiteralType) {
+        // Ensure the console cast fails.
+        let console_literal = console::Literal::<<Circuit as Environment>::Network>::from_str(literal).unwrap();
+        assert!(console_literal.cast(to_type).is_err());
+        // Inject the literal.
+        let literal = Literal::<Circuit>::new(mode, console_literal);
+
+        Circuit::scope(format!("{mode} {} as {to_type}", literal.to_type()), || {
+            let _candidate = literal.cast(to_type).unwrap();
+            assert!(!Circuit::is_satisfied_in_scope());
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_cast_integers() {
+        check_cast("5u8", Mode::Private, LiteralType::U128, 0, 0, 0, 0);
+        check_cast("-5i8", Mode::Private, LiteralType::I128, 0, 0, 0, 0);
+        check_cast("127i8", Mode::Private, LiteralType::U8, 0, 0, 0, 1);
+        check_cast("255u64", Mode::Private, LiteralType::U8, 0, 0, 0, 56);
+        check_cast("-128i64", Mode::Private, LiteralType::I8, 0, 0, 0, 56);
+        check_cast("255u64", Mode::Constant, LiteralType::U8, 0, 0, 0, 0);
+        check_cast_fails("256u64", Mode::Private, LiteralType::U8);
+        check_cast_fails("-129i64", Mode::Private, LiteralType::I8);
+        check_cast_fails("128u8", Mode::Private, LiteralType::I8);
+        check_cast_fails("-1i8", Mode::Private, LiteralType::U8);
+    }
+
+    #[test]
+    fn test_cast_boolean() {
+        check_cast("true", Mode::Private, LiteralType::U8, 0, 0, 0, 0);
+        check_cast("true", Mode::Private, LiteralType::Field, 0, 0, 0, 0);
+        check_cast("1u32", Mode::Private, LiteralType::Boolean, 0, 0, 0, 31);
+        check_cast("0field", Mode::Private, LiteralType::Boolean, 0, 0, 1, 2);
+        check_cast_fails("2u8", Mode::Private, LiteralType::Boolean);
+        check_cast_fails("2field", Mode::Private, LiteralType::Boolean);
+    }
+
+    #[test]
+    fn test_cast_field_and_scalar() {
+        check_cast("12345u64", Mode::Private, LiteralType::Field, 0, 0, 0, 0);
+        check_cast("-5i64", Mode::Private, LiteralType::I64, 0, 0, 0, 0);
+        check_cast("12345field", Mode::Private, LiteralType::U16, 0, 0, 16, 17);
+        check_cast("12345field", Mode::Private, LiteralType::I16, 0, 0, 15, 16);
+        check_cast("12345field", Mode::Private, LiteralType::Scalar, 1, 0, 507, 511);
+        check_cast("12345scalar", Mode::Private, LiteralType::Field, 0, 0, 0, 0);
+        check_cast("12345u64", Mode::Private, LiteralType::Scalar, 0, 0, 0, 0);
+        check_cast_fails("-1i64", Mode::Private, LiteralType::Field);
+        check_cast_fails("65536field", Mode::Private, LiteralType::U16);
+        check_cast_fails("32768field", Mode::Private, LiteralType::I16);
+        check_cast_fails("-1field", Mode::Private, LiteralType::Scalar);
+    }
+
+    #[test]
+    fn test_cast_group_and_address() {
+        let mut rng = TestRng::default();
+
+        // Sample a random group element.
+        let group = console::Group::<<Circuit as Environment>::Network>::rand(&mut rng);
+        let (group, x_coordinate) = (group.to_string(), format!("{}", group.to_x_coordinate()));
+
+        check_cast(&group, Mode::Private, LiteralType::Field, 0, 0, 0, 0);
+        check_cast(&group, Mode::Private, LiteralType::Address, 0, 0, 0, 0);
+        check_cast(&x_coordinate, Mode::Private, LiteralType::Group, 4, 0, 15, 13);
+        check_cast(&x_coordinate, Mode::Private, LiteralType::Address, 4, 0, 15, 13);
+        check_cast(&x_coordinate, Mode::Constant, LiteralType::Group, 11, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_cast_string() {
+        let literal = Literal::<Circuit>::new(Mode::Private, console::Literal::from_str("\"hello\"").unwrap());
+        assert!(literal.cast(LiteralType::Field).is_err());
+        let literal = Literal::<Circuit>::new(Mode::Private, console::Literal::from_str("1field").unwrap());
+        assert!(literal.cast(LiteralType::String).is_err());
+    }
+}
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod cast;
 mod equal;
 mod from_bits;
 mod size_in_bits;
@@ -22,6 +23,7 @@ mod to_fields;
 mod to_type;
 mod variant;
 
+use snarkvm_circuit_account::Signature;
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::prelude::*;
 
@@ -60,6 +62,8 @@ pub enum Literal<A: Aleo> {
     Scalar(Scalar<A>),
     /// The string type.
     String(StringType<A>),
+    /// The Aleo signature type.
+    Signature(Box<Signature<A>>),
 }
 
 #[cfg(console)]
@@ -85,6 +89,7 @@ impl<A: Aleo> Inject for Literal<A> {
             Self::Primitive::U128(u128) => Self::U128(U128::new(mode, u128)),
             Self::Primitive::Scalar(scalar) => Self::Scalar(Scalar::new(mode, scalar)),
             Self::Primitive::String(string) => Self::String(StringType::new(mode, string)),
+            Self::Primitive::Signature(signature) => Self::Signature(Box::new(Signature::new(mode, *signature))),
         }
     }
 }
@@ -112,6 +117,7 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::U128(literal) => literal.eject_mode(),
             Self::Scalar(literal) => literal.eject_mode(),
             Self::String(literal) => literal.eject_mode(),
+            Self::Signature(literal) => literal.eject_mode(),
         }
     }
 
@@ -134,6 +140,7 @@ impl<A: Aleo> Eject for Literal<A> {
             Self::U128(literal) => Self::Primitive::U128(literal.eject_value()),
             Self::Scalar(literal) => Self::Primitive::Scalar(literal.eject_value()),
             Self::String(literal) => Self::Primitive::String(literal.eject_value()),
+            Self::Signature(literal) => Self::Primitive::Signature(Box::new(literal.eject_value())),
         }
     }
 }
@@ -160,6 +167,7 @@ impl<A: Aleo> Parser for Literal<A> {
             map(U128::parse, |literal| Self::U128(literal)),
             map(Scalar::parse, |literal| Self::Scalar(literal)),
             map(StringType::parse, |literal| Self::String(literal)),
+            map(Signature::parse, |literal| Self::Signature(Box::new(literal))),
         ))(string)
     }
 }
@@ -204,6 +212,7 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => U128::<A>::type_name(),
             Self::Scalar(..) => Scalar::<A>::type_name(),
             Self::String(..) => StringType::<A>::type_name(),
+            Self::Signature(..) => "signature",
         }
     }
 }
@@ -235,6 +244,7 @@ impl<A: Aleo> Display for Literal<A> {
             Self::U128(literal) => Display::fmt(literal, f),
             Self::Scalar(literal) => Display::fmt(literal, f),
             Self::String(literal) => Display::fmt(literal, f),
+            Self::Signature(literal) => Display::fmt(literal, f),
         }
     }
 }
@@ -47,6 +47,63 @@ impl<A: Aleo> ToFields for &Literal<A> {
             Literal::U128(literal) => vec![literal.to_field()],
             Literal::Scalar(literal) => vec![literal.to_field()],
             Literal::String(literal) => literal.to_fields(),
+            Literal::Signature(literal) => {
+                literal.to_bits_le().chunks(A::BaseField::size_in_data_bits()).map(Field::from_bits_le).collect()
+            }
         }
     }
 }
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use console::{LiteralType, TestRng};
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_signature_to_fields(mode: Mode) {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random signature.
+            let expected = console::Literal::<<Circuit as Environment>::Network>::sample(LiteralType::Signature, rng);
+            let signature = match &expected {
+                console::Literal::Signature(signature) => signature,
+                _ => unreachable!("Expected a signature"),
+            };
+
+            // Pack the console signature bits into field elements.
+            let expected_fields = signature
+                .to_bits_le()
+                .chunks(console::Field::<<Circuit as Environment>::Network>::size_in_data_bits())
+                .map(console::Field::from_bits_le)
+                .collect::<console::Result<Vec<_>>>()
+                .unwrap();
+
+            // Ensure the circuit encoding matches the console encoding.
+            let candidate = Literal::<Circuit>::new(mode, expected);
+            let candidate_fields = candidate.to_fields();
+            assert_eq!(expected_fields.len(), candidate_fields.len());
+            for (expected_field, candidate_field) in expected_fields.iter().zip_eq(&candidate_fields) {
+                assert_eq!(*expected_field, candidate_field.eject_value());
+            }
+            Circuit::reset();
+        }
+    }
+
+    #[test]
+    fn test_signature_to_fields_constant() {
+        check_signature_to_fields(Mode::Constant);
+    }
+
+    #[test]
+    fn test_signature_to_fields_public() {
+        check_signature_to_fields(Mode::Public);
+    }
+
+    #[test]
+    fn test_signature_to_fields_private() {
+        check_signature_to_fields(Mode::Private);
+    }
+}
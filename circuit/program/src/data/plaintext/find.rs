@@ -18,53 +18,42 @@ use super::*;
 
 impl<A: Aleo> Plaintext<A> {
     /// Returns the plaintext member from the given path.
-    pub fn find(&self, path: &[Identifier<A>]) -> Result<Plaintext<A>> {
+    pub fn find(&self, path: &[Access<A>]) -> Result<Plaintext<A>> {
         // Ensure the path is not empty.
         if path.is_empty() {
             A::halt("Attempted to find member with an empty path.")
         }
 
-        match self {
-            // Halts if the value is not a struct.
-            Self::Literal(..) => A::halt("Literal is not a struct"),
-            // Retrieve the value of the member (from the value).
-            Self::Struct(members, ..) => {
-                // Initialize the members starting from the top-level.
-                let mut submembers = members;
-
-                // Initialize the output.
-                let mut output = None;
-
-                // Iterate through the path to retrieve the value.
-                for (i, identifier) in path.iter().enumerate() {
-                    // If this is not the last item in the path, ensure the value is a struct.
-                    if i != path.len() - 1 {
-                        match submembers.get(identifier) {
-                            // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
-                            // Retrieve the member and update `submembers` for the next iteration.
-                            Some(Self::Struct(members, ..)) => submembers = members,
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in struct"),
-                        }
-                    }
-                    // Otherwise, return the final member.
-                    else {
-                        match submembers.get(identifier) {
-                            // Return the plaintext member.
-                            Some(plaintext) => output = Some(plaintext.clone()),
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in struct"),
-                        }
+        // Initialize the plaintext starting from the top-level.
+        let mut plaintext = self;
+
+        // Iterate through the path to retrieve the value.
+        for access in path.iter() {
+            plaintext = match (plaintext, access) {
+                // Retrieve the member from the struct.
+                (Self::Struct(members, ..), Access::Member(identifier)) => match members.get(identifier) {
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in struct"),
+                },
+                // Retrieve the element from the array.
+                (Self::Array(elements, ..), Access::Index(index)) => {
+                    // Ensure the index is a constant.
+                    ensure!(index.is_constant(), "Array index '{index}' must be a constant");
+                    match elements.get(*index.eject_value() as usize) {
+                        Some(element) => element,
+                        // Halts if the index is out of bounds.
+                        None => bail!("Index '{index}' is out of bounds in array"),
                     }
                 }
-
-                // Return the output.
-                match output {
-                    Some(output) => Ok(output),
-                    None => A::halt("Failed to locate member in struct from path"),
-                }
-            }
+                // Halts if the member access is not on a struct.
+                (_, Access::Member(identifier)) => bail!("'{identifier}' must be accessed from a struct"),
+                // Halts if the index access is not on an array.
+                (_, Access::Index(index)) => bail!("'{index}' must be accessed from an array"),
+            };
         }
+
+        // Return the output.
+        Ok(plaintext.clone())
     }
 }
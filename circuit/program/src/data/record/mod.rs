@@ -34,7 +34,7 @@ mod to_bits;
 mod to_commitment;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Plaintext, ProgramID, Visibility};
+use crate::{Access, Ciphertext, Identifier, Plaintext, ProgramID, Visibility};
 use snarkvm_circuit_account::{PrivateKey, ViewKey};
 use snarkvm_circuit_network::Aleo;
 use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar, U32};
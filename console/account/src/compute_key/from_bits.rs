@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBits for ComputeKey<N> {
+    /// Initializes a new compute key from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[bool]) -> Result<Self> {
+        let group_size_in_bits = Group::<N>::size_in_bits();
+        ensure!(bits_le.len() == 2 * group_size_in_bits, "Invalid compute key size in bits");
+
+        let (pk_sig_bits, pr_sig_bits) = bits_le.split_at(group_size_in_bits);
+        let pk_sig = Group::from_bits_le(pk_sig_bits)?;
+        let pr_sig = Group::from_bits_le(pr_sig_bits)?;
+        Self::try_from((pk_sig, pr_sig))
+    }
+
+    /// Initializes a new compute key from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[bool]) -> Result<Self> {
+        let group_size_in_bits = Group::<N>::size_in_bits();
+        ensure!(bits_be.len() == 2 * group_size_in_bits, "Invalid compute key size in bits");
+
+        let (pk_sig_bits, pr_sig_bits) = bits_be.split_at(group_size_in_bits);
+        let pk_sig = Group::from_bits_be(pk_sig_bits)?;
+        let pr_sig = Group::from_bits_be(pr_sig_bits)?;
+        Self::try_from((pk_sig, pr_sig))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new compute key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let expected = ComputeKey::try_from(private_key)?;
+
+            // Check the little-endian bit representation.
+            let bits_le = expected.to_bits_le();
+            assert_eq!(ComputeKey::<CurrentNetwork>::size_in_bits(), bits_le.len());
+            assert_eq!(expected, ComputeKey::from_bits_le(&bits_le)?);
+            assert!(ComputeKey::<CurrentNetwork>::from_bits_le(&bits_le[1..]).is_err());
+
+            // Check the big-endian bit representation.
+            let bits_be = expected.to_bits_be();
+            assert_eq!(ComputeKey::<CurrentNetwork>::size_in_bits(), bits_be.len());
+            assert_eq!(expected, ComputeKey::from_bits_be(&bits_be)?);
+            assert!(ComputeKey::<CurrentNetwork>::from_bits_be(&bits_be[1..]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -15,8 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod from_bits;
 mod serialize;
+mod size_in_bits;
 mod to_address;
+mod to_bits;
 mod try_from;
 
 #[cfg(feature = "private_key")]
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
+    type Input = bool;
+    type Output = Vec<bool>;
+
+    /// Returns the Keccak hash of the given input as bits.
+    #[inline]
+    fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Ensure the output size is supported.
+        ensure!(VARIANT > 0 && VARIANT <= Self::RATE, "Invalid Keccak output size of {VARIANT} bits");
+
+        // Absorb the padded input into the state.
+        let mut state = vec![false; PERMUTATION_WIDTH];
+        for block in Self::pad(input).chunks(Self::RATE) {
+            state.iter_mut().zip(block).for_each(|(bit, input_bit)| *bit ^= input_bit);
+            Self::permute(&mut state);
+        }
+        // Squeeze the output from the state.
+        state.truncate(VARIANT);
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns the hash of the given bytes, as a hex string.
+    fn hash_bytes<const TYPE: u8>(bytes: &[u8]) -> Result<String> {
+        let output = Keccak::<TYPE, 256>::new().hash(&bytes.to_bits_le())?;
+        Ok(hex::encode(Vec::<u8>::from_bits_le(&output)?))
+    }
+
+    #[test]
+    fn test_keccak256() -> Result<()> {
+        const KECCAK: u8 = KeccakType::Keccak as u8;
+        assert_eq!(hash_bytes::<KECCAK>(b"")?, "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+        assert_eq!(hash_bytes::<KECCAK>(b"abc")?, "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45");
+        assert_eq!(
+            hash_bytes::<KECCAK>(b"The quick brown fox jumps over the lazy dog")?,
+            "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sha3_256() -> Result<()> {
+        const SHA3: u8 = KeccakType::Sha3 as u8;
+        assert_eq!(hash_bytes::<SHA3>(b"")?, "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        assert_eq!(hash_bytes::<SHA3>(b"abc")?, "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+        assert_eq!(
+            hash_bytes::<SHA3>(b"The quick brown fox jumps over the lazy dog")?,
+            "69070dda01975c8c120c3aada1b282394e7f032fa9cf32f4cb2259a0897dfc04"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple_blocks() -> Result<()> {
+        // A 200-byte input spans two blocks of the 136-byte rate.
+        let input = [0xa3u8; 200];
+        assert_eq!(
+            hash_bytes::<{ KeccakType::Sha3 as u8 }>(&input)?,
+            "79f38adec5c20307a98ef76e8324afbfd46cfd81b22e3973c65fa1bd9de31787"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_size() -> Result<()> {
+        let mut rng = TestRng::default();
+        for i in 0..ITERATIONS {
+            let input = (0..i * 100).map(|_| Uniform::rand(&mut rng)).collect::<Vec<bool>>();
+            assert_eq!(Keccak256::new().hash(&input)?.len(), 256);
+            assert_eq!(Sha3_256::new().hash(&input)?.len(), 256);
+            // Ensure the hash is deterministic, and the two paddings differ.
+            assert_eq!(Keccak256::new().hash(&input)?, Keccak256::new().hash(&input)?);
+            assert_ne!(Keccak256::new().hash(&input)?, Sha3_256::new().hash(&input)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod hash;
+
+use snarkvm_console_types::prelude::*;
+
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used by Ethereum.
+pub type Keccak256 = Keccak<{ KeccakType::Keccak as u8 }, 256>;
+/// Sha3_256 is the SHA-3 hash function with a 256-bit output, as standardized in FIPS 202.
+pub type Sha3_256 = Keccak<{ KeccakType::Sha3 as u8 }, 256>;
+
+/// The number of bits in the Keccak-f[1600] permutation state.
+pub const PERMUTATION_WIDTH: usize = 1600;
+/// The number of bits in a lane of the Keccak-f[1600] permutation state.
+pub const LANE_WIDTH: usize = 64;
+/// The number of rounds in the Keccak-f[1600] permutation.
+pub const NUM_ROUNDS: usize = 24;
+
+/// The round constants of the Keccak-f[1600] permutation.
+pub const ROUND_CONSTANTS: [u64; NUM_ROUNDS] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the Keccak-f[1600] permutation, indexed by `[x][y]`.
+pub const ROTATION_OFFSETS: [[usize; 5]; 5] =
+    [[0, 36, 3, 41, 18], [1, 44, 10, 45, 2], [62, 6, 43, 15, 61], [28, 55, 25, 21, 56], [27, 20, 39, 8, 14]];
+
+/// The padding rule of a Keccak instance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KeccakType {
+    /// The original Keccak padding, as used by Ethereum.
+    Keccak,
+    /// The SHA-3 padding, which appends the domain separator bits `01` before padding.
+    Sha3,
+}
+
+/// Keccak is a sponge-based hash function over the Keccak-f[1600] permutation.
+/// This implementation operates on bits, where each byte is expected in little-endian bit order.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Keccak<const TYPE: u8, const VARIANT: usize>;
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// The number of bits absorbed into the state per permutation.
+    pub const RATE: usize = PERMUTATION_WIDTH - 2 * VARIANT;
+
+    /// Initializes a new instance of Keccak.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the padded input, where the length of the padded input is a multiple of the rate.
+    fn pad(input: &[bool]) -> Vec<bool> {
+        let mut padded = input.to_vec();
+        // Append the domain separator bits for SHA-3.
+        if TYPE == KeccakType::Sha3 as u8 {
+            padded.extend([false, true]);
+        }
+        // Apply the `pad10*1` rule.
+        padded.push(true);
+        while (padded.len() + 1) % Self::RATE != 0 {
+            padded.push(false);
+        }
+        padded.push(true);
+        padded
+    }
+
+    /// Applies the Keccak-f[1600] permutation to the given state.
+    fn permute(state: &mut [bool]) {
+        debug_assert_eq!(state.len(), PERMUTATION_WIDTH);
+        for round_constant in ROUND_CONSTANTS {
+            Self::round(state, round_constant);
+        }
+    }
+
+    /// Applies a round of the Keccak-f[1600] permutation to the given state.
+    fn round(a: &mut [bool], round_constant: u64) {
+        // Returns the index of the given bit in the state.
+        let index = |x: usize, y: usize, z: usize| LANE_WIDTH * ((x % 5) + 5 * (y % 5)) + (z % LANE_WIDTH);
+
+        // Step θ: XOR each bit with the parities of two neighboring columns.
+        let c: Vec<bool> = (0..5 * LANE_WIDTH)
+            .map(|i| (0..5).fold(false, |parity, y| parity ^ a[index(i / LANE_WIDTH, y, i % LANE_WIDTH)]))
+            .collect();
+        let d: Vec<bool> = (0..5 * LANE_WIDTH)
+            .map(|i| {
+                let (x, z) = (i / LANE_WIDTH, i % LANE_WIDTH);
+                c[((x + 4) % 5) * LANE_WIDTH + z] ^ c[((x + 1) % 5) * LANE_WIDTH + (z + LANE_WIDTH - 1) % LANE_WIDTH]
+            })
+            .collect();
+        for x in 0..5 {
+            for y in 0..5 {
+                for z in 0..LANE_WIDTH {
+                    a[index(x, y, z)] ^= d[x * LANE_WIDTH + z];
+                }
+            }
+        }
+
+        // Steps ρ and π: Rotate each lane, and move it to its new position.
+        let mut b = vec![false; PERMUTATION_WIDTH];
+        for x in 0..5 {
+            for y in 0..5 {
+                let offset = ROTATION_OFFSETS[x][y];
+                for z in 0..LANE_WIDTH {
+                    b[index(y, 2 * x + 3 * y, z + offset)] = a[index(x, y, z)];
+                }
+            }
+        }
+
+        // Step χ: Combine each bit with the bits of the two subsequent lanes in its row.
+        for x in 0..5 {
+            for y in 0..5 {
+                for z in 0..LANE_WIDTH {
+                    a[index(x, y, z)] = b[index(x, y, z)] ^ (!b[index(x + 1, y, z)] & b[index(x + 2, y, z)]);
+                }
+            }
+        }
+
+        // Step ι: XOR the round constant into the first lane.
+        for z in 0..LANE_WIDTH {
+            a[index(0, 0, z)] ^= (round_constant >> z) & 1 == 1;
+        }
+    }
+}
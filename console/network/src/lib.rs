@@ -217,6 +217,12 @@ pub trait Network:
     /// Returns the Pedersen hash for a given (up to) 128-bit input.
     fn hash_ped128(input: &[bool]) -> Result<Field<Self>>;
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>>;
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>>;
 
@@ -17,11 +17,13 @@
 use super::*;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    Keccak256,
     Pedersen128,
     Pedersen64,
     Poseidon2,
     Poseidon4,
     Poseidon8,
+    Sha3_256,
     BHP1024,
     BHP256,
     BHP512,
@@ -293,6 +295,16 @@ impl Network for Testnet3 {
         PEDERSEN_128.hash(input)
     }
 
+    /// Returns the Keccak hash with a 256-bit output.
+    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+        Keccak256::new().hash(input)
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output.
+    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+        Sha3_256::new().hash(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2.
     fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
         POSEIDON_2.hash(input)
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for Access<N> {
+    /// Reads the access from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let variant = u8::read_le(&mut reader)?;
+        match variant {
+            0 => Ok(Self::Member(Identifier::read_le(&mut reader)?)),
+            1 => Ok(Self::Index(U32::read_le(&mut reader)?)),
+            2.. => Err(error(format!("Failed to deserialize access variant {variant}"))),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for Access<N> {
+    /// Writes the access to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Member(identifier) => {
+                u8::write_le(&0u8, &mut writer)?;
+                identifier.write_le(&mut writer)
+            }
+            Self::Index(index) => {
+                u8::write_le(&1u8, &mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["[0u32]", "[31u32]", ".owner", ".foo_bar"] {
+            let expected = Access::<CurrentNetwork>::from_str(string)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Access::read_le(&expected_bytes[..])?);
+            assert!(Access::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
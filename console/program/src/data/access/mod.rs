@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::Identifier;
+use snarkvm_console_network::Network;
+use snarkvm_console_types::{prelude::*, U32};
+
+/// A helper type for accessing an entry in a register, struct, array, or record.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Access<N: Network> {
+    /// The access is a member of a struct or record.
+    Member(Identifier<N>),
+    /// The access is an index into an array.
+    Index(U32<N>),
+}
+
+impl<N: Network> From<Identifier<N>> for Access<N> {
+    /// Initializes a new member access from an identifier.
+    fn from(identifier: Identifier<N>) -> Self {
+        Self::Member(identifier)
+    }
+}
+
+impl<N: Network> From<U32<N>> for Access<N> {
+    /// Initializes a new index access from a `U32`.
+    fn from(index: U32<N>) -> Self {
+        Self::Index(index)
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Literal<N> {
+    /// Casts the literal to the given literal type, preserving its value.
+    ///
+    /// The cast fails if the value is not representable in the given literal type:
+    ///   - A boolean, an integer, a field, or a scalar is cast by its integer value, where a boolean is `0` or `1`.
+    ///     A negative signed integer can only be cast to a signed integer.
+    ///   - A group or an address is cast as its **x-coordinate**, unless it is cast to a group or an address.
+    ///     Casting a field to a group or an address fails if the field is not a valid x-coordinate.
+    ///   - A string or a signature cannot be cast, except a signature to itself.
+    pub fn cast(&self, to_type: LiteralType) -> Result<Self> {
+        match self {
+            Self::Address(address) => match to_type {
+                LiteralType::Address => Ok(self.clone()),
+                LiteralType::Group => Ok(Self::Group(**address)),
+                _ => cast_field(address.to_field()?, to_type),
+            },
+            Self::Boolean(boolean) => cast_bits(&[**boolean], false, to_type),
+            Self::Field(field) => cast_field(*field, to_type),
+            Self::Group(group) => match to_type {
+                LiteralType::Address => Ok(Self::Address(Address::new(*group))),
+                LiteralType::Group => Ok(self.clone()),
+                _ => cast_field(group.to_x_coordinate(), to_type),
+            },
+            Self::I8(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I16(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I32(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I64(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::I128(integer) => cast_bits(&integer.to_bits_le(), true, to_type),
+            Self::U8(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U16(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U32(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U64(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::U128(integer) => cast_bits(&integer.to_bits_le(), false, to_type),
+            Self::Scalar(scalar) => cast_field(scalar.to_field()?, to_type),
+            Self::String(..) => bail!("Cannot cast a string to '{to_type}'"),
+            Self::Signature(..) => match to_type {
+                LiteralType::Signature => Ok(self.clone()),
+                _ => bail!("Cannot cast a signature to '{to_type}'"),
+            },
+        }
+    }
+}
+
+/// Casts the given field element to the given literal type.
+fn cast_field<N: Network>(field: Field<N>, to_type: LiteralType) -> Result<Literal<N>> {
+    match to_type {
+        LiteralType::Address => Ok(Literal::Address(Address::from_field(&field)?)),
+        LiteralType::Field => Ok(Literal::Field(field)),
+        LiteralType::Group => Ok(Literal::Group(Group::from_x_coordinate(field)?)),
+        LiteralType::Scalar => Ok(Literal::Scalar(Scalar::from_bits_le(&field.to_bits_le())?)),
+        LiteralType::String => bail!("Cannot cast a field to a string"),
+        LiteralType::Signature => bail!("Cannot cast a field to a signature"),
+        // Note: The field element is cast to a boolean or an integer by its (non-negative) integer value.
+        _ => cast_bits(&field.to_bits_le(), false, to_type),
+    }
+}
+
+/// Casts the given little-endian bits of an integer to the given literal type.
+/// If `is_signed` is `true`, the bits are interpreted in two's complement.
+fn cast_bits<N: Network>(bits_le: &[bool], is_signed: bool, to_type: LiteralType) -> Result<Literal<N>> {
+    // Retrieve the sign bit.
+    let sign = is_signed && bits_le.last().copied().unwrap_or(false);
+    // Note: The sign bit of a signed integer is excluded, as it trivially matches itself.
+    let num_magnitude_bits = bits_le.len() - is_signed as usize;
+
+    // Resizes the bits to the given integer size, ensuring the value is representable.
+    let resize = |size: usize, to_signed: bool| -> Result<Vec<bool>> {
+        // Ensure a negative value is not cast to an unsigned type.
+        ensure!(to_signed || !sign, "Cannot cast a negative value to '{to_type}'");
+        // Ensure the bits above the size of the integer (excluding its sign bit) equal the sign bit.
+        let start = size - to_signed as usize;
+        ensure!(
+            bits_le[..num_magnitude_bits].iter().skip(start).all(|bit| *bit == sign),
+            "Cannot cast a value that is out of range for '{to_type}'"
+        );
+        // Sign-extend or truncate the bits to the given size.
+        Ok((0..size).map(|i| bits_le.get(i).copied().unwrap_or(sign)).collect())
+    };
+
+    match to_type {
+        LiteralType::Boolean => Ok(Literal::Boolean(Boolean::new(resize(1, false)?[0]))),
+        LiteralType::I8 => Ok(Literal::I8(I8::from_bits_le(&resize(8, true)?)?)),
+        LiteralType::I16 => Ok(Literal::I16(I16::from_bits_le(&resize(16, true)?)?)),
+        LiteralType::I32 => Ok(Literal::I32(I32::from_bits_le(&resize(32, true)?)?)),
+        LiteralType::I64 => Ok(Literal::I64(I64::from_bits_le(&resize(64, true)?)?)),
+        LiteralType::I128 => Ok(Literal::I128(I128::from_bits_le(&resize(128, true)?)?)),
+        LiteralType::U8 => Ok(Literal::U8(U8::from_bits_le(&resize(8, false)?)?)),
+        LiteralType::U16 => Ok(Literal::U16(U16::from_bits_le(&resize(16, false)?)?)),
+        LiteralType::U32 => Ok(Literal::U32(U32::from_bits_le(&resize(32, false)?)?)),
+        LiteralType::U64 => Ok(Literal::U64(U64::from_bits_le(&resize(64, false)?)?)),
+        LiteralType::U128 => Ok(Literal::U128(U128::from_bits_le(&resize(128, false)?)?)),
+        LiteralType::String => bail!("Cannot cast an integer to a string"),
+        LiteralType::Signature => bail!("Cannot cast an integer to a signature"),
+        LiteralType::Address | LiteralType::Field | LiteralType::Group | LiteralType::Scalar => {
+            // Ensure the value is not negative.
+            ensure!(!sign, "Cannot cast a negative value to '{to_type}'");
+            match to_type {
+                LiteralType::Scalar => Ok(Literal::Scalar(Scalar::from_bits_le(bits_le)?)),
+                _ => cast_field(Field::from_bits_le(bits_le)?, to_type),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Casts the given literal string to the given literal type.
+    fn cast(literal: &str, to_type: LiteralType) -> Result<Literal<CurrentNetwork>> {
+        Literal::<CurrentNetwork>::from_str(literal)?.cast(to_type)
+    }
+
+    /// Checks that the given literal string casts to the expected literal string.
+    fn check_cast(literal: &str, to_type: LiteralType, expected: &str) {
+        assert_eq!(Literal::from_str(expected).unwrap(), cast(literal, to_type).unwrap(), "{literal} as {to_type}");
+    }
+
+    #[test]
+    fn test_cast_integers() {
+        // Ensure integers are widened, preserving their sign.
+        check_cast("5u8", LiteralType::U128, "5u128");
+        check_cast("-5i8", LiteralType::I128, "-5i128");
+        check_cast("127i8", LiteralType::U8, "127u8");
+        check_cast("255u8", LiteralType::I16, "255i16");
+        // Ensure integers are narrowed, if they are in range.
+        check_cast("255u64", LiteralType::U8, "255u8");
+        check_cast("-128i64", LiteralType::I8, "-128i8");
+        check_cast("127u128", LiteralType::I8, "127i8");
+        assert!(cast("256u64", LiteralType::U8).is_err());
+        assert!(cast("-129i64", LiteralType::I8).is_err());
+        assert!(cast("128u8", LiteralType::I8).is_err());
+        assert!(cast(&format!("{}u128", u128::MAX), LiteralType::I128).is_err());
+        // Ensure negative values are not cast to unsigned types.
+        assert!(cast("-1i8", LiteralType::U8).is_err());
+        assert!(cast("-1i128", LiteralType::U128).is_err());
+    }
+
+    #[test]
+    fn test_cast_boolean() {
+        check_cast("true", LiteralType::U8, "1u8");
+        check_cast("false", LiteralType::I64, "0i64");
+        check_cast("true", LiteralType::Field, "1field");
+        check_cast("true", LiteralType::Scalar, "1scalar");
+        check_cast("1u32", LiteralType::Boolean, "true");
+        check_cast("0field", LiteralType::Boolean, "false");
+        assert!(cast("2u8", LiteralType::Boolean).is_err());
+        assert!(cast("-1i8", LiteralType::Boolean).is_err());
+    }
+
+    #[test]
+    fn test_cast_field_and_scalar() {
+        check_cast("12345u64", LiteralType::Field, "12345field");
+        check_cast("12345field", LiteralType::U16, "12345u16");
+        check_cast("12345field", LiteralType::Scalar, "12345scalar");
+        check_cast("12345scalar", LiteralType::I32, "12345i32");
+        assert!(cast("-1i64", LiteralType::Field).is_err());
+        assert!(cast("-1field", LiteralType::U128).is_err());
+        // Ensure a field that exceeds the scalar modulus is not cast to a scalar.
+        assert!(cast("-1field", LiteralType::Scalar).is_err());
+        // Ensure the maximum scalar is cast to a field and back.
+        let scalar = Literal::Scalar(-Scalar::<CurrentNetwork>::one());
+        assert_eq!(scalar, scalar.cast(LiteralType::Field).unwrap().cast(LiteralType::Scalar).unwrap());
+    }
+
+    #[test]
+    fn test_cast_group_and_address() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..100 {
+            // Sample a random group element.
+            let group = Group::<CurrentNetwork>::rand(&mut rng);
+            let x_coordinate = Literal::Field(group.to_x_coordinate());
+
+            // Ensure a group is cast to its x-coordinate, and recovered from it.
+            assert_eq!(x_coordinate, Literal::Group(group).cast(LiteralType::Field).unwrap());
+            assert_eq!(Literal::Group(group), x_coordinate.cast(LiteralType::Group).unwrap());
+            // Ensure an address is cast to its x-coordinate, and recovered from it.
+            let address = Literal::Address(Address::new(group));
+            assert_eq!(x_coordinate, address.cast(LiteralType::Field).unwrap());
+            assert_eq!(address, x_coordinate.cast(LiteralType::Address).unwrap());
+            // Ensure a group and an address are cast to each other.
+            assert_eq!(address, Literal::Group(group).cast(LiteralType::Address).unwrap());
+            assert_eq!(Literal::Group(group), address.cast(LiteralType::Group).unwrap());
+        }
+
+        // Ensure an invalid x-coordinate is not cast to a group or an address.
+        assert!(cast("1field", LiteralType::Group).is_err());
+        assert!(cast("1u8", LiteralType::Address).is_err());
+    }
+
+    #[test]
+    fn test_cast_identity() {
+        let mut rng = TestRng::default();
+
+        for literal_type in
+            [LiteralType::Address, LiteralType::Field, LiteralType::I64, LiteralType::U128, LiteralType::Signature]
+        {
+            let literal = Literal::<CurrentNetwork>::sample(literal_type, &mut rng);
+            assert_eq!(literal, literal.cast(literal_type).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_cast_string() {
+        assert!(cast("\"hello\"", LiteralType::Field).is_err());
+        assert!(cast("1field", LiteralType::String).is_err());
+    }
+
+    #[test]
+    fn test_cast_signature() {
+        let mut rng = TestRng::default();
+
+        let signature = Literal::<CurrentNetwork>::sample(LiteralType::Signature, &mut rng);
+        assert!(signature.cast(LiteralType::Field).is_err());
+        assert!(signature.cast(LiteralType::Address).is_err());
+        assert!(cast("1field", LiteralType::Signature).is_err());
+        assert!(cast("1u8", LiteralType::Signature).is_err());
+    }
+}
@@ -44,6 +44,7 @@ impl<N: Network> core::hash::Hash for Literal<N> {
             Self::U128(a) => a.hash(state),
             Self::Scalar(a) => a.hash(state),
             Self::String(a) => a.hash(state),
+            Self::Signature(a) => a.hash(state),
         }
     }
 }
@@ -70,6 +71,7 @@ impl<N: Network> Equal for Literal<N> {
             (Self::U128(a), Self::U128(b)) => a.is_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_equal(b),
             (Self::String(a), Self::String(b)) => a.is_equal(b),
+            (Self::Signature(a), Self::Signature(b)) => Boolean::new(a == b),
             _ => Boolean::new(false),
         }
     }
@@ -93,6 +95,7 @@ impl<N: Network> Equal for Literal<N> {
             (Self::U128(a), Self::U128(b)) => a.is_not_equal(b),
             (Self::Scalar(a), Self::Scalar(b)) => a.is_not_equal(b),
             (Self::String(a), Self::String(b)) => a.is_not_equal(b),
+            (Self::Signature(a), Self::Signature(b)) => Boolean::new(a != b),
             _ => Boolean::new(true),
         }
     }
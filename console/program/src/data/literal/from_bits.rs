@@ -49,7 +49,8 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            16.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
+            16 => Literal::Signature(Box::new(Signature::from_bits_le(literal)?)),
+            17.. => bail!("Failed to initialize literal variant {} from bits (LE)", variant),
         };
         Ok(literal)
     }
@@ -86,7 +87,8 @@ impl<N: Network> Literal<N> {
                     false => bail!("String literal exceeds maximum length of {} bytes.", N::MAX_STRING_BYTES),
                 }
             }
-            16.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
+            16 => Literal::Signature(Box::new(Signature::from_bits_be(literal)?)),
+            17.. => bail!("Failed to initialize literal variant {} from bits (BE)", variant),
         };
         Ok(literal)
     }
@@ -149,6 +151,8 @@ mod tests {
             // Sample a random string. Take 1/4th to ensure we fit for all code points.
             let string = rng.next_string(CurrentNetwork::MAX_STRING_BYTES / 4, false);
             check_serialization(Literal::<CurrentNetwork>::String(StringType::new(&string)))?;
+            // Signature
+            check_serialization(Literal::<CurrentNetwork>::sample(LiteralType::Signature, rng))?;
         }
         Ok(())
     }
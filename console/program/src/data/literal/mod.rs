@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod cast;
 mod equal;
 mod from_bits;
 mod parse;
@@ -25,7 +26,7 @@ mod to_bits;
 mod to_type;
 mod variant;
 
-use crate::LiteralType;
+use crate::{LiteralType, Signature};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::{prelude::*, Boolean};
 
@@ -64,4 +65,6 @@ pub enum Literal<N: Network> {
     Scalar(Scalar<N>),
     /// The string type.
     String(StringType<N>),
+    /// The Aleo signature type.
+    Signature(Box<Signature<N>>),
 }
@@ -44,7 +44,27 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Return the struct.
                 Self::Struct(members, Default::default())
             }
-            2.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
+            2 => {
+                // Read the number of elements in the array.
+                let num_elements = u32::read_le(&mut reader)?;
+                // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+                if num_elements as usize > N::MAX_DATA_ENTRIES {
+                    return Err(error(format!("Plaintext array exceeds size ({num_elements})")));
+                }
+                // Read the elements.
+                let mut elements = Vec::with_capacity(num_elements as usize);
+                for _ in 0..num_elements {
+                    // Read the plaintext value (in 2 steps to prevent infinite recursion).
+                    let num_bytes = u16::read_le(&mut reader)?;
+                    // Read the plaintext bytes.
+                    let bytes = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                    // Recover the plaintext value.
+                    elements.push(Plaintext::read_le(&mut bytes.as_slice())?);
+                }
+                // Return the array.
+                Self::Array(elements, Default::default())
+            }
+            3.. => return Err(error(format!("Failed to decode plaintext variant {index}"))),
         };
         Ok(plaintext)
     }
@@ -82,6 +102,27 @@ impl<N: Network> ToBytes for Plaintext<N> {
                 }
                 Ok(())
             }
+            Self::Array(array, ..) => {
+                2u8.write_le(&mut writer)?;
+
+                // Write the number of elements in the array.
+                u32::try_from(array.len())
+                    .or_halt_with::<N>("Plaintext array length exceeds u32::MAX.")
+                    .write_le(&mut writer)?;
+
+                // Write each element.
+                for element in array {
+                    // Write the element (performed in 2 steps to prevent infinite recursion).
+                    let bytes = element.to_bytes_le().map_err(|e| error(e.to_string()))?;
+                    // Write the number of bytes.
+                    u16::try_from(bytes.len())
+                        .or_halt_with::<N>("Plaintext element exceeds u16::MAX bytes.")
+                        .write_le(&mut writer)?;
+                    // Write the bytes.
+                    bytes.write_le(&mut writer)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -190,6 +231,14 @@ mod tests {
             "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, gates: 5u64, token_amount: 100u64 }",
         )?;
 
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
+        assert!(Plaintext::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Lastly check the array manually.
+        let expected = Plaintext::<CurrentNetwork>::from_str("[ { amount: 5u64 }, { amount: 10u64 } ]")?;
+
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Plaintext::read_le(&expected_bytes[..])?);
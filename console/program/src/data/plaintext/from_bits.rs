@@ -74,6 +74,33 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            let num_elements = u32::from_bits_le(&bits_le[counter..counter + 32])?;
+            counter += 32;
+
+            // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+            ensure!(num_elements as usize <= N::MAX_DATA_ENTRIES, "Plaintext array exceeds size ({num_elements})");
+
+            let mut elements = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = u16::from_bits_le(&bits_le[counter..counter + 16])?;
+                counter += 16;
+
+                let element = Plaintext::from_bits_le(&bits_le[counter..counter + element_size as usize])?;
+                counter += element_size as usize;
+
+                elements.push(element);
+            }
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_le.to_vec()) {
+                // Return the array.
+                Ok(_) => Ok(Self::Array(elements, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant.");
@@ -137,6 +164,33 @@ impl<N: Network> FromBits for Plaintext<N> {
                 Err(_) => bail!("Failed to store the plaintext bits in the cache."),
             }
         }
+        // Array
+        else if variant == [true, false] {
+            let num_elements = u32::from_bits_be(&bits_be[counter..counter + 32])?;
+            counter += 32;
+
+            // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+            ensure!(num_elements as usize <= N::MAX_DATA_ENTRIES, "Plaintext array exceeds size ({num_elements})");
+
+            let mut elements = Vec::with_capacity(num_elements as usize);
+            for _ in 0..num_elements {
+                let element_size = u16::from_bits_be(&bits_be[counter..counter + 16])?;
+                counter += 16;
+
+                let element = Plaintext::from_bits_be(&bits_be[counter..counter + element_size as usize])?;
+                counter += element_size as usize;
+
+                elements.push(element);
+            }
+
+            // Store the plaintext bits in the cache.
+            let cache = OnceCell::new();
+            match cache.set(bits_be.to_vec()) {
+                // Return the array.
+                Ok(_) => Ok(Self::Array(elements, cache)),
+                Err(_) => bail!("Failed to store the plaintext bits in the cache."),
+            }
+        }
         // Unknown variant.
         else {
             bail!("Unknown plaintext variant.");
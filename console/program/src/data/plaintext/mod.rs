@@ -27,7 +27,7 @@ mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Ciphertext, Identifier, Literal};
+use crate::{Access, Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -40,6 +40,8 @@ pub enum Plaintext<N: Network> {
     Literal(Literal<N>, OnceCell<Vec<bool>>),
     /// A struct.
     Struct(IndexMap<Identifier<N>, Plaintext<N>>, OnceCell<Vec<bool>>),
+    /// An array.
+    Array(Vec<Plaintext<N>>, OnceCell<Vec<bool>>),
 }
 
 impl<N: Network> From<Literal<N>> for Plaintext<N> {
@@ -153,6 +155,21 @@ mod tests {
             OnceCell::new(),
         );
         assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value = Plaintext::<CurrentNetwork>::Array(
+            vec![
+                Plaintext::<CurrentNetwork>::from_str("{ a: true, b: 1u8 }")?,
+                Plaintext::<CurrentNetwork>::from_str("{ a: false, b: 2u8 }")?,
+            ],
+            OnceCell::new(),
+        );
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value = Plaintext::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8]")?;
+        assert_eq!(value.to_bits_le(), Plaintext::<CurrentNetwork>::from_bits_le(&value.to_bits_le())?.to_bits_le());
+
+        let value = Plaintext::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8]")?;
+        assert_eq!(value.to_bits_be(), Plaintext::<CurrentNetwork>::from_bits_be(&value.to_bits_be())?.to_bits_be());
         Ok(())
     }
 }
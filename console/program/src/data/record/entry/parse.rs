@@ -55,6 +55,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
                 parse_literal,
                 // Parse a struct.
                 parse_struct,
+                // Parse an array.
+                parse_array,
             ))(string)?;
             // Return the identifier, plaintext, and visibility.
             Ok((string, (identifier, plaintext, mode)))
@@ -96,6 +98,44 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             Ok((string, (Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default()), mode)))
         }
 
+        /// Parses an entry as an array: `[plaintext_0.visibility, ..., plaintext_n.visibility]`.
+        /// Observe the `visibility` is the same for all elements of the plaintext value.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+            /// Parses a sanitized element: `entry`.
+            fn parse_element<N: Network>(string: &str) -> ParserResult<(Plaintext<N>, Mode)> {
+                // Parse the whitespace and comments from the string.
+                let (string, _) = Sanitizer::parse(string)?;
+                // Parse the plaintext and visibility from the string.
+                alt((parse_literal, parse_struct, parse_array))(string)
+            }
+
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, (elements, mode)) = map_res(separated_list1(tag(","), parse_element), |elements: Vec<_>| {
+                // Ensure the elements all have the same visibility.
+                let mode = elements.iter().map(|(_, mode)| mode).dedup().collect::<Vec<_>>();
+                let mode = match mode.len() == 1 {
+                    true => *mode[0],
+                    false => return Err(error("Elements of array in entry have different visibilities")),
+                };
+                // Ensure the number of elements is within `N::MAX_DATA_ENTRIES`.
+                match elements.len() <= N::MAX_DATA_ENTRIES {
+                    // Return the elements and the visibility.
+                    true => Ok((elements.into_iter().map(|(p, _)| p).collect::<Vec<_>>(), mode)),
+                    false => Err(error(format!("Found an array that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext and visibility.
+            Ok((string, (Plaintext::Array(elements, Default::default()), mode)))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the entry (order matters).
@@ -104,6 +144,8 @@ impl<N: Network> Parser for Entry<N, Plaintext<N>> {
             parse_literal,
             // Parse a struct.
             parse_struct,
+            // Parse an array.
+            parse_array,
         ))(string)?;
 
         // Return the entry.
@@ -181,7 +223,7 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal}.{visibility},", "", indent = (depth + 1) * INDENT),
                         },
-                        Plaintext::Struct(..) => {
+                        Plaintext::Struct(..) | Plaintext::Array(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -201,6 +243,44 @@ impl<N: Network> Entry<N, Plaintext<N>> {
                     }
                 })
             }
+            // Prints the array, i.e. [ 10i64.private, 198i64.private ]
+            Plaintext::Array(array, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                array.iter().enumerate().try_for_each(|(i, element)| {
+                    match element {
+                        #[rustfmt::skip]
+                        Plaintext::Literal(literal, ..) => match i == array.len() - 1 {
+                            true => {
+                                // Print the last element without a comma.
+                                write!(f, "\n{:indent$}{literal}.{visibility}", "", indent = (depth + 1) * INDENT)?;
+                                // Print the closing bracket.
+                                write!(f, "\n{:indent$}]", "", indent = depth * INDENT)
+                            }
+                            // Print the element with a comma.
+                            false => write!(f, "\n{:indent$}{literal}.{visibility},", "", indent = (depth + 1) * INDENT),
+                        },
+                        Plaintext::Struct(..) | Plaintext::Array(..) => {
+                            // Print the indentation.
+                            write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                            // Print the element.
+                            match self {
+                                Self::Constant(..) => Self::Constant(element.clone()).fmt_internal(f, depth + 1)?,
+                                Self::Public(..) => Self::Public(element.clone()).fmt_internal(f, depth + 1)?,
+                                Self::Private(..) => Self::Private(element.clone()).fmt_internal(f, depth + 1)?,
+                            }
+                            // Print the closing bracket.
+                            match i == array.len() - 1 {
+                                // Print the last element without a comma.
+                                true => write!(f, "\n{:indent$}]", "", indent = depth * INDENT),
+                                // Print the element with a comma.
+                                false => write!(f, ","),
+                            }
+                        }
+                    }
+                })
+            }
         }
     }
 }
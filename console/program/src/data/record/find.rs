@@ -18,18 +18,23 @@ use super::*;
 
 impl<N: Network> Record<N, Plaintext<N>> {
     /// Returns the entry from the given path.
-    pub fn find(&self, path: &[Identifier<N>]) -> Result<Entry<N, Plaintext<N>>> {
+    pub fn find(&self, path: &[Access<N>]) -> Result<Entry<N, Plaintext<N>>> {
         // If the path is of length one, check if the path is requesting the `owner` or `gates`.
         if path.len() == 1 {
-            if path[0] == Identifier::from_str("owner")? {
+            if path[0] == Access::Member(Identifier::from_str("owner")?) {
                 return Ok(self.owner.to_entry());
-            } else if path[0] == Identifier::from_str("gates")? {
+            } else if path[0] == Access::Member(Identifier::from_str("gates")?) {
                 return Ok(self.gates.to_entry());
             }
         }
 
         // Ensure the path is not empty.
         if let Some((first, rest)) = path.split_first() {
+            // Ensure the first access is a member.
+            let first = match first {
+                Access::Member(identifier) => identifier,
+                Access::Index(index) => bail!("Attempted to index into a record with '{index}'"),
+            };
             // Retrieve the top-level entry.
             match self.data.get(first) {
                 Some(entry) => match rest.is_empty() {
@@ -180,10 +180,10 @@ impl<N: Network> Record<N, Plaintext<N>> {
                 Entry::Constant(Plaintext::Literal(..))
                 | Entry::Public(Plaintext::Literal(..))
                 | Entry::Private(Plaintext::Literal(..)) => write!(f, "{entry}")?,
-                // If the entry is a struct, print the entry with indentation.
-                Entry::Constant(Plaintext::Struct(..))
-                | Entry::Public(Plaintext::Struct(..))
-                | Entry::Private(Plaintext::Struct(..)) => entry.fmt_internal(f, depth + 1)?,
+                // If the entry is a struct or an array, print the entry with indentation.
+                Entry::Constant(Plaintext::Struct(..) | Plaintext::Array(..))
+                | Entry::Public(Plaintext::Struct(..) | Plaintext::Array(..))
+                | Entry::Private(Plaintext::Struct(..) | Plaintext::Array(..)) => entry.fmt_internal(f, depth + 1)?,
             }
             // Print the comma.
             write!(f, ",")?;
@@ -264,6 +264,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_array_entry() -> Result<()> {
+        let expected = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public,
+  gates: 99u64.private,
+  foo: [
+    5u8.public,
+    6u8.public
+  ],
+  bar: [
+    {
+      baz: 7u8.constant
+    },
+    {
+      baz: 8u8.constant
+    }
+  ],
+  _nonce: 0group.public
+}";
+        let (remainder, candidate) = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(expected)?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Array elements must have the same visibility.
+        let given = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, gates: 99u64.private, foo: [5u8.public, 6u8.private], _nonce: 0group.public }";
+        assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(given).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() -> Result<()> {
         // Missing owner.
@@ -33,7 +33,25 @@ impl<N: Network> FromBytes for Register<N> {
                 }
                 Ok(Self::Member(locator, identifiers))
             }
-            2.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
+            2 => {
+                // Read the number of accesses.
+                let num_accesses = u16::read_le(&mut reader)?;
+                // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+                if num_accesses as usize > N::MAX_DATA_DEPTH {
+                    return Err(error(format!("Failed to deserialize register: too many accesses ({num_accesses})")));
+                }
+                // Read the accesses.
+                let mut accesses = Vec::with_capacity(num_accesses as usize);
+                for _ in 0..num_accesses {
+                    accesses.push(Access::read_le(&mut reader)?);
+                }
+                // Ensure the accesses contain an index, as a register of only members is encoded as variant 1.
+                if !accesses.iter().any(|access| matches!(access, Access::Index(..))) {
+                    return Err(error("Failed to deserialize register: the accesses do not contain an index"));
+                }
+                Ok(Self::Access(locator, accesses))
+            }
+            3.. => Err(error(format!("Failed to deserialize register variant {variant}"))),
         }
     }
 }
@@ -59,6 +77,68 @@ impl<N: Network> ToBytes for Register<N> {
                     .write_le(&mut writer)?;
                 identifiers.write_le(&mut writer)
             }
+            Self::Access(locator, accesses) => {
+                // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+                if accesses.len() > N::MAX_DATA_DEPTH {
+                    return Err(error("Failed to serialize register: too many accesses"));
+                }
+                // Ensure the accesses contain an index, as a register of only members is encoded as variant 1.
+                if !accesses.iter().any(|access| matches!(access, Access::Index(..))) {
+                    return Err(error("Failed to serialize register: the accesses do not contain an index"));
+                }
+
+                u8::write_le(&2u8, &mut writer)?;
+                variable_length_integer(locator).write_le(&mut writer)?;
+                u16::try_from(accesses.len())
+                    .or_halt_with::<N>("Register path length exceeds u16::MAX")
+                    .write_le(&mut writer)?;
+                accesses.write_le(&mut writer)
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["r0", "r1.owner", "r2.owner.data", "r3[0u32]", "r4.owner[1u32].data"] {
+            let expected = Register::<CurrentNetwork>::from_str(string)?;
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, Register::read_le(&expected_bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_member_encoding() -> Result<()> {
+        // A register of only members is encoded as `[1u8][locator][u16 count][identifiers]`.
+        let register = Register::<CurrentNetwork>::from_str("r5.owner.data")?;
+        let identifiers = vec![Identifier::<CurrentNetwork>::from_str("owner")?, Identifier::from_str("data")?];
+        let mut expected_bytes = vec![1u8];
+        variable_length_integer(&5).write_le(&mut expected_bytes)?;
+        2u16.write_le(&mut expected_bytes)?;
+        identifiers.write_le(&mut expected_bytes)?;
+        assert_eq!(expected_bytes, register.to_bytes_le()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_access_without_index_fails() -> Result<()> {
+        // A register of only members must not be encoded as variant 2.
+        let register = Register::<CurrentNetwork>::Access(0, vec![Access::from_str(".owner")?]);
+        assert!(register.to_bytes_le().is_err());
+
+        let mut bytes = vec![2u8];
+        variable_length_integer(&0).write_le(&mut bytes)?;
+        1u16.write_le(&mut bytes)?;
+        Access::<CurrentNetwork>::from_str(".owner")?.write_le(&mut bytes)?;
+        assert!(Register::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        Ok(())
+    }
+}
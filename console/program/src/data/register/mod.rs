@@ -18,7 +18,7 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::Identifier;
+use crate::{Access, Identifier};
 use snarkvm_console_network::prelude::*;
 
 /// A register contains the location data to a value in memory.
@@ -28,6 +28,8 @@ pub enum Register<N: Network> {
     Locator(u64),
     /// A register member contains its locator and identifier(s) in memory.
     Member(u64, Vec<Identifier<N>>),
+    /// A register access contains its locator and access(es) in memory, including at least one array index.
+    Access(u64, Vec<Access<N>>),
 }
 
 impl<N: Network> Register<N> {
@@ -37,19 +39,30 @@ impl<N: Network> Register<N> {
         match self {
             Self::Locator(locator) => *locator,
             Self::Member(locator, _) => *locator,
+            Self::Access(locator, _) => *locator,
+        }
+    }
+
+    /// Returns the access path of the register, which is empty if the register is a locator.
+    #[inline]
+    pub fn accesses(&self) -> Vec<Access<N>> {
+        match self {
+            Self::Locator(..) => vec![],
+            Self::Member(_, identifiers) => identifiers.iter().map(|identifier| Access::Member(*identifier)).collect(),
+            Self::Access(_, accesses) => accesses.clone(),
         }
     }
 }
 
 impl<N: Network> Ord for Register<N> {
-    /// Ordering is determined by the register locator (any member identifiers are ignored).
+    /// Ordering is determined by the register locator (any accesses are ignored).
     fn cmp(&self, other: &Self) -> Ordering {
         self.locator().cmp(&other.locator())
     }
 }
 
 impl<N: Network> PartialOrd for Register<N> {
-    /// Ordering is determined by the register locator (any member identifiers are ignored).
+    /// Ordering is determined by the register locator (any accesses are ignored).
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
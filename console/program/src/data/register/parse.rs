@@ -18,7 +18,7 @@ use super::*;
 
 impl<N: Network> Parser for Register<N> {
     /// Parses a string into a register.
-    /// The register is of the form `r{locator}` or `r{locator}.{identifier}`.
+    /// The register is of the form `r{locator}` or `r{locator}` followed by accesses, i.e. `r0.owner` or `r0[3u32]`.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse the register character from the string.
@@ -26,20 +26,32 @@ impl<N: Network> Parser for Register<N> {
         // Parse the locator from the string.
         let (string, locator) =
             map_res(recognize(many1(one_of("0123456789"))), |locator: &str| locator.parse::<u64>())(string)?;
-        // Parse the identifier from the string, if it is a register member.
-        let (string, identifiers): (&str, Vec<Identifier<N>>) =
-            map_res(many0(pair(tag("."), Identifier::parse)), |identifiers| {
-                // Ensure the number of identifiers is within `N::MAX_DATA_DEPTH`.
-                if identifiers.len() <= N::MAX_DATA_DEPTH {
-                    Ok(identifiers.iter().cloned().map(|(_, identifier)| identifier).collect())
-                } else {
-                    Err(error(format!("Register \'r{locator}\' has too many identifiers ({})", identifiers.len())))
-                }
-            })(string)?;
+        // Parse the accesses from the string, if it is a register access.
+        let (string, accesses): (&str, Vec<Access<N>>) = map_res(many0(Access::parse), |accesses: Vec<_>| {
+            // Ensure the number of accesses is within `N::MAX_DATA_DEPTH`.
+            match accesses.len() <= N::MAX_DATA_DEPTH {
+                true => Ok(accesses),
+                false => Err(error(format!("Register \'r{locator}\' has too many accesses ({})", accesses.len()))),
+            }
+        })(string)?;
         // Return the register.
-        Ok((string, match identifiers.len() {
+        Ok((string, match accesses.len() {
             0 => Self::Locator(locator),
-            _ => Self::Member(locator, identifiers),
+            _ => match accesses.iter().all(|access| matches!(access, Access::Member(..))) {
+                // If every access is a member, then return a register member.
+                true => Self::Member(
+                    locator,
+                    accesses
+                        .into_iter()
+                        .filter_map(|access| match access {
+                            Access::Member(identifier) => Some(identifier),
+                            Access::Index(..) => None,
+                        })
+                        .collect(),
+                ),
+                // Otherwise, return a register access.
+                false => Self::Access(locator, accesses),
+            },
         }))
     }
 }
@@ -83,6 +95,14 @@ impl<N: Network> Display for Register<N> {
                 }
                 Ok(())
             }
+            // Prints the register access, i.e. r0[3u32] or r0.owner[3u32]
+            Self::Access(locator, accesses) => {
+                write!(f, "r{locator}")?;
+                for access in accesses {
+                    write!(f, "{access}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -90,7 +110,9 @@ impl<N: Network> Display for Register<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Identifier;
     use snarkvm_console_network::Testnet3;
+    use snarkvm_console_types::U32;
 
     type CurrentNetwork = Testnet3;
 
@@ -208,6 +230,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_register_parse_index() -> Result<()> {
+        let expected = Register::<CurrentNetwork>::Access(0, vec![
+            Access::Index(U32::new(3)),
+            Access::Member(Identifier::from_str("owner")?),
+            Access::Index(U32::new(0)),
+        ]);
+        assert_eq!(("", expected.clone()), Register::parse("r0[3u32].owner[0u32]").unwrap());
+        assert_eq!("r0[3u32].owner[0u32]", expected.to_string());
+
+        // An index must be a `u32`.
+        assert_eq!(("[3u8]", Register::<CurrentNetwork>::Locator(0)), Register::parse("r0[3u8]").unwrap());
+        assert_eq!(("[3]", Register::<CurrentNetwork>::Locator(0)), Register::parse("r0[3]").unwrap());
+        Ok(())
+    }
+
     #[test]
     fn test_register_parser_fails() {
         assert!(Register::<CurrentNetwork>::parse("").is_err());
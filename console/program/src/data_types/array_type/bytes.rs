@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for ArrayType<N> {
+    /// Reads an array type from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the element type.
+        // Note: The element type is read in place (rather than as a plaintext type), as it cannot be an array.
+        let variant = u8::read_le(&mut reader)?;
+        let element_type = match variant {
+            0 => PlaintextType::Literal(LiteralType::read_le(&mut reader)?),
+            1 => PlaintextType::Struct(Identifier::read_le(&mut reader)?),
+            2.. => return Err(error(format!("Failed to deserialize element type variant {variant}"))),
+        };
+        // Read the length.
+        let length = U32::read_le(&mut reader)?;
+        // Return the array type.
+        Self::new(element_type, length).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl<N: Network> ToBytes for ArrayType<N> {
+    /// Writes the array type to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the element type.
+        // Note: The element type is written in place (rather than as a plaintext type), as it cannot be an array.
+        match self.element_type {
+            ElementType::Literal(literal_type) => {
+                u8::write_le(&0u8, &mut writer)?;
+                literal_type.write_le(&mut writer)?;
+            }
+            ElementType::Struct(identifier) => {
+                u8::write_le(&1u8, &mut writer)?;
+                identifier.write_le(&mut writer)?;
+            }
+        }
+        // Write the length.
+        self.length.write_le(&mut writer)
+    }
+}
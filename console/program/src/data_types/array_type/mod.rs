@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::{Identifier, LiteralType, PlaintextType};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::U32;
+
+/// An array type contains its element type and a static length.
+/// The format of the type is `[<element_type>; <length>]`, i.e. `[u8; 32u32]`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The element type of the array.
+    element_type: ElementType<N>,
+    /// The number of elements in the array.
+    length: U32<N>,
+}
+
+/// The element type of an array, which is a literal type or a struct type.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum ElementType<N: Network> {
+    Literal(LiteralType),
+    Struct(Identifier<N>),
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type from the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: U32<N>) -> Result<Self> {
+        // Ensure the length is nonzero.
+        ensure!(*length != 0, "The array must have at least one element");
+        // Ensure the length is within `N::MAX_DATA_ENTRIES`.
+        ensure!(
+            *length as usize <= N::MAX_DATA_ENTRIES,
+            "The array length cannot exceed {} elements, found {length}",
+            N::MAX_DATA_ENTRIES
+        );
+        // Ensure the element type is not an array.
+        let element_type = match element_type {
+            PlaintextType::Literal(literal_type) => ElementType::Literal(literal_type),
+            PlaintextType::Struct(struct_name) => ElementType::Struct(struct_name),
+            PlaintextType::Array(..) => bail!("The element type of an array cannot be an array"),
+        };
+        Ok(Self { element_type, length })
+    }
+
+    /// Returns the element type of the array.
+    #[inline]
+    pub const fn element_type(&self) -> PlaintextType<N> {
+        match self.element_type {
+            ElementType::Literal(literal_type) => PlaintextType::Literal(literal_type),
+            ElementType::Struct(struct_name) => PlaintextType::Struct(struct_name),
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    #[inline]
+    pub const fn length(&self) -> &U32<N> {
+        &self.length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let array_type = ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("u8")?, U32::new(32))?;
+        assert_eq!(array_type.element_type(), PlaintextType::Literal(LiteralType::U8));
+        assert_eq!(**array_type.length(), 32);
+
+        let array_type = ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("token")?, U32::new(1))?;
+        assert_eq!(array_type.element_type(), PlaintextType::Struct(Identifier::from_str("token")?));
+        assert_eq!(**array_type.length(), 1);
+
+        // The length must be nonzero and within `MAX_DATA_ENTRIES`.
+        assert!(ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("u8")?, U32::new(0)).is_err());
+        let max_length = u32::try_from(CurrentNetwork::MAX_DATA_ENTRIES)?;
+        assert!(ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("u8")?, U32::new(max_length)).is_ok());
+        assert!(ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("u8")?, U32::new(max_length + 1)).is_err());
+
+        // The element type must not be an array.
+        assert!(ArrayType::<CurrentNetwork>::new(PlaintextType::from_str("[u8; 2u32]")?, U32::new(2)).is_err());
+        Ok(())
+    }
+}
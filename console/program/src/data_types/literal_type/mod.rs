@@ -58,6 +58,8 @@ pub enum LiteralType {
     Scalar,
     /// The string type.
     String,
+    /// The Aleo signature type.
+    Signature,
 }
 
 impl LiteralType {
@@ -80,6 +82,7 @@ impl LiteralType {
             Self::U128 => "u128",
             Self::Scalar => "scalar",
             Self::String => "string",
+            Self::Signature => "signature",
         }
     }
 }
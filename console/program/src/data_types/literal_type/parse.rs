@@ -38,6 +38,7 @@ impl Parser for LiteralType {
             map(tag("u128"), |_| Self::U128),
             map(tag("scalar"), |_| Self::Scalar),
             map(tag("string"), |_| Self::String),
+            map(tag("signature"), |_| Self::Signature),
         ))(string)
     }
 }
@@ -43,7 +43,7 @@ mod tests {
     /// Add test cases here to be checked for serialization.
     const TEST_CASES: &[&str] = &[
         "address", "boolean", "field", "group", "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128",
-        "scalar", "string",
+        "scalar", "string", "signature",
     ];
 
     fn check_serde_json<
@@ -22,6 +22,7 @@ impl<N: Network> Parser for PlaintextType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the plaintext type (order matters).
         alt((
+            map(ArrayType::parse, |type_| Self::Array(type_)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
         ))(string)
@@ -58,8 +59,10 @@ impl<N: Network> Display for PlaintextType<N> {
         match self {
             // Prints the literal, i.e. field
             Self::Literal(literal) => Display::fmt(literal, f),
-            // Prints the struct, i.e. signature
+            // Prints the struct, i.e. message
             Self::Struct(struct_) => Display::fmt(struct_, f),
+            // Prints the array, i.e. [u8; 32u32]
+            Self::Array(array) => Display::fmt(array, f),
         }
     }
 }
@@ -79,7 +82,15 @@ mod tests {
         );
         assert_eq!(
             PlaintextType::parse("signature"),
-            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?)))
+            Ok(("", PlaintextType::<CurrentNetwork>::Literal(LiteralType::Signature)))
+        );
+        assert_eq!(
+            PlaintextType::parse("message"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("message")?)))
+        );
+        assert_eq!(
+            PlaintextType::parse("[u8; 32u32]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[u8; 32u32]")?)))
         );
         Ok(())
     }
@@ -149,9 +160,10 @@ mod tests {
     #[test]
     fn test_display() -> Result<()> {
         assert_eq!(PlaintextType::<CurrentNetwork>::Literal(LiteralType::Field).to_string(), "field");
+        assert_eq!(PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("message")?).to_string(), "message");
         assert_eq!(
-            PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?).to_string(),
-            "signature"
+            PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[u8; 32u32]")?).to_string(),
+            "[u8; 32u32]"
         );
         Ok(())
     }
@@ -69,6 +69,10 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Array
+        "[u8; 32u32]",
+        "[field; 1u32]",
+        "[signature; 4u32]",
     ];
 
     fn check_serde_json<
@@ -105,6 +105,23 @@ impl<N: Network> Metadata<N> {
             }
         }
     }
+
+    /// Returns the expected `(coinbase target, proof target)` of the block at the given timestamp,
+    /// which follows the block with this metadata. Block checks use this to verify the next targets.
+    pub fn next_targets(&self, timestamp: i64) -> Result<(u64, u64)> {
+        // Retarget the coinbase target from the last coinbase.
+        let coinbase_target = crate::consensus::coinbase_target(
+            self.last_coinbase_target,
+            self.last_coinbase_timestamp,
+            timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        // Compute the proof target from the coinbase target.
+        let proof_target = crate::consensus::proof_target(coinbase_target, N::GENESIS_PROOF_TARGET);
+        Ok((coinbase_target, proof_target))
+    }
 }
 
 impl<N: Network> Metadata<N> {
@@ -148,3 +165,42 @@ impl<N: Network> Metadata<N> {
         self.timestamp
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_next_targets() {
+        let genesis = Metadata::<CurrentNetwork>::genesis().unwrap();
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+
+        // Ensure a block at the anchor time keeps the genesis targets.
+        let expected = (CurrentNetwork::GENESIS_COINBASE_TARGET, CurrentNetwork::GENESIS_PROOF_TARGET);
+        assert_eq!(genesis.next_targets(CurrentNetwork::GENESIS_TIMESTAMP + anchor_time).unwrap(), expected);
+
+        // Ensure an early block raises the targets, and a late block keeps the (minimum) genesis targets.
+        let (coinbase_target, proof_target) = genesis.next_targets(CurrentNetwork::GENESIS_TIMESTAMP + 1).unwrap();
+        assert!(coinbase_target > CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert!(proof_target >= CurrentNetwork::GENESIS_PROOF_TARGET);
+        assert_eq!(genesis.next_targets(CurrentNetwork::GENESIS_TIMESTAMP + 10 * anchor_time).unwrap(), expected);
+
+        // Ensure the next targets are valid for the next block.
+        let timestamp = CurrentNetwork::GENESIS_TIMESTAMP + 1;
+        let (last_coinbase_target, last_coinbase_timestamp) = (coinbase_target, timestamp);
+        let metadata = Metadata::<CurrentNetwork>::new(
+            CurrentNetwork::ID,
+            1,
+            1,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        );
+        assert!(metadata.is_ok());
+    }
+}
@@ -96,6 +96,24 @@ impl<N: Network> Transaction<N> {
         Self::from_deployment(deployment, fee)
     }
 
+    /// Initializes a new deployment transaction that upgrades an existing program,
+    /// signed by the upgrade authority of the program.
+    pub fn upgrade<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        (credits, fee_in_gates): (Record<N, Plaintext<N>>, u64),
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Compute the upgrade.
+        let deployment = vm.upgrade(program, private_key, rng)?;
+        // Compute the fee.
+        let (_, fee, _) = vm.execute_fee(private_key, credits, fee_in_gates, query, rng)?;
+        // Initialize the transaction.
+        Self::from_deployment(deployment, fee)
+    }
+
     /// Initializes a new execution transaction from an authorization, and an optional fee.
     pub fn execute_authorization<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
         vm: &VM<N, C>,
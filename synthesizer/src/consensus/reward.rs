@@ -0,0 +1,227 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::coinbase_puzzle::CoinbaseSolution;
+use console::{network::prelude::*, types::Address};
+
+use indexmap::IndexMap;
+
+/// The number of seconds in a year.
+const SECONDS_PER_YEAR: u32 = 365 * 24 * 60 * 60;
+
+/// Returns the number of blocks produced in the given number of years, at the given anchor time (in seconds).
+pub const fn num_blocks_in_years(years: u32, anchor_time: u16) -> u32 {
+    match anchor_time {
+        0 => 0,
+        anchor_time => years.saturating_mul(SECONDS_PER_YEAR) / anchor_time as u32,
+    }
+}
+
+/// Returns the block reward, which issues 5% of the starting supply per year to the block producers:
+///     R_block = floor((0.05 * S) / H_Y1)
+pub const fn block_reward(starting_supply: u64, anchor_time: u16) -> u64 {
+    match num_blocks_in_years(1, anchor_time) {
+        0 => 0,
+        num_blocks => starting_supply / 20 / num_blocks as u64,
+    }
+}
+
+/// Returns the anchor reward at the given block height, which is the reward for a coinbase that meets the target.
+/// The anchor reward decreases linearly to zero over the first 10 years, and sums to the starting supply:
+///     R_anchor = floor((2 * S * (H_Y10 - H + 1)) / (H_Y10 * (H_Y10 + 1))), for 0 < H <= H_Y10.
+pub fn anchor_reward(height: u32, starting_supply: u64, anchor_time: u16) -> u64 {
+    // Compute the number of blocks in 10 years.
+    let num_blocks = num_blocks_in_years(10, anchor_time);
+    // Note: The genesis block does not have a coinbase, and there is no anchor reward after 10 years.
+    if height == 0 || height > num_blocks {
+        return 0;
+    }
+    // Compute the number of blocks remaining, including the given block.
+    let num_remaining = u128::from(num_blocks - height + 1);
+    let num_blocks = u128::from(num_blocks);
+    // Compute the anchor reward.
+    // Note: This cannot overflow, and the anchor reward is at most `2 * S / (H_Y10 + 1)`.
+    (2 * u128::from(starting_supply) * num_remaining / (num_blocks * (num_blocks + 1))) as u64
+}
+
+/// Returns the coinbase reward at the given block height, for a coinbase with the given cumulative proof target.
+/// The coinbase reward is the anchor reward, scaled down if the cumulative proof target is below the coinbase target:
+///     R_coinbase = floor(R_anchor * min(P, C) / C)
+pub fn coinbase_reward(
+    height: u32,
+    starting_supply: u64,
+    anchor_time: u16,
+    cumulative_proof_target: u128,
+    coinbase_target: u64,
+) -> Result<u64> {
+    ensure!(coinbase_target > 0, "The coinbase target must be nonzero");
+    // Compute the anchor reward.
+    let anchor_reward = u128::from(anchor_reward(height, starting_supply, anchor_time));
+    // Compute the cumulative proof target, capped at the coinbase target.
+    let cumulative_proof_target = cumulative_proof_target.min(u128::from(coinbase_target));
+    // Compute the coinbase reward.
+    let reward = anchor_reward.saturating_mul(cumulative_proof_target) / u128::from(coinbase_target);
+    Ok(u64::try_from(reward)?)
+}
+
+/// Returns the reward of each prover in the given coinbase solution, for the given coinbase reward.
+/// The coinbase reward is split proportionally to the target of each partial solution:
+///     R_prover = floor(R_coinbase * T_solution / sum(T_solution)), summed over the solutions of the prover.
+/// Note: Each reward is rounded down, and the remainder of the coinbase reward is not minted.
+pub fn prover_rewards<N: Network>(
+    coinbase_solution: &CoinbaseSolution<N>,
+    coinbase_reward: u64,
+) -> Result<IndexMap<Address<N>, u64>> {
+    // Retrieve the address and target of each partial solution.
+    let solutions = coinbase_solution
+        .partial_solutions()
+        .iter()
+        .map(|solution| Ok((solution.address(), solution.to_target()?)))
+        .collect::<Result<Vec<_>>>()?;
+    // Split the coinbase reward.
+    split_reward(&solutions, coinbase_reward)
+}
+
+/// Returns the reward of each address, by splitting the given reward proportionally to the given targets.
+/// The rewards are ordered by the first occurrence of each address.
+fn split_reward<N: Network>(solutions: &[(Address<N>, u64)], reward: u64) -> Result<IndexMap<Address<N>, u64>> {
+    // Compute the cumulative target.
+    let cumulative_target = solutions.iter().map(|(_, target)| u128::from(*target)).sum::<u128>();
+    ensure!(cumulative_target > 0, "Cannot split a reward over a cumulative target of zero");
+
+    let mut rewards = IndexMap::<Address<N>, u64>::with_capacity(solutions.len());
+    for (address, target) in solutions {
+        // Compute the reward of the solution.
+        // Note: This cannot overflow, as the reward and target are each at most 64 bits.
+        let solution_reward = u64::try_from(u128::from(reward) * u128::from(*target) / cumulative_target)?;
+        // Add the reward to the address.
+        let entry = rewards.entry(*address).or_default();
+        *entry = entry.checked_add(solution_reward).ok_or_else(|| anyhow!("Prover reward overflowed"))?;
+    }
+    Ok(rewards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// The number of blocks in 10 years, at the anchor time of the current network.
+    const NUM_BLOCKS_IN_10_YEARS: u32 = num_blocks_in_years(10, CurrentNetwork::ANCHOR_TIME);
+
+    /// Returns the anchor reward of the current network at the given height.
+    fn sample_anchor_reward(height: u32) -> u64 {
+        anchor_reward(height, CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::ANCHOR_TIME)
+    }
+
+    #[test]
+    fn test_num_blocks_in_years() {
+        assert_eq!(num_blocks_in_years(1, CurrentNetwork::ANCHOR_TIME), 1_261_440);
+        assert_eq!(NUM_BLOCKS_IN_10_YEARS, 12_614_400);
+        assert_eq!(num_blocks_in_years(1, 0), 0);
+    }
+
+    #[test]
+    fn test_block_reward() {
+        let reward = block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::ANCHOR_TIME);
+        assert_eq!(reward, 43_600_963);
+
+        // Ensure the block rewards for a year are at most 5% of the starting supply.
+        let annual_issuance = reward * u64::from(num_blocks_in_years(1, CurrentNetwork::ANCHOR_TIME));
+        assert!(annual_issuance <= CurrentNetwork::STARTING_SUPPLY / 20);
+        assert!(annual_issuance > CurrentNetwork::STARTING_SUPPLY / 20 - 1_261_440);
+    }
+
+    #[test]
+    fn test_anchor_reward() {
+        // Ensure the anchor reward starts at the first block, and ends after 10 years.
+        assert_eq!(sample_anchor_reward(0), 0);
+        assert_eq!(sample_anchor_reward(1), 174_403_842);
+        assert_eq!(sample_anchor_reward(NUM_BLOCKS_IN_10_YEARS), 13);
+        assert_eq!(sample_anchor_reward(NUM_BLOCKS_IN_10_YEARS + 1), 0);
+        assert_eq!(sample_anchor_reward(u32::MAX), 0);
+
+        // Ensure the anchor rewards are decreasing, and sum to (at most) the starting supply.
+        let mut previous = u64::MAX;
+        let mut total = 0u64;
+        for height in 1..=NUM_BLOCKS_IN_10_YEARS {
+            let reward = sample_anchor_reward(height);
+            assert!(reward <= previous);
+            previous = reward;
+            total += reward;
+        }
+        assert!(total <= CurrentNetwork::STARTING_SUPPLY);
+        // Note: Each anchor reward is floored, which loses less than 1 gate per block.
+        assert!(total > CurrentNetwork::STARTING_SUPPLY - u64::from(NUM_BLOCKS_IN_10_YEARS));
+    }
+
+    #[test]
+    fn test_coinbase_reward() {
+        let coinbase_target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+        let anchor_reward = sample_anchor_reward(1);
+        let reward_for = |cumulative_proof_target| {
+            coinbase_reward(
+                1,
+                CurrentNetwork::STARTING_SUPPLY,
+                CurrentNetwork::ANCHOR_TIME,
+                cumulative_proof_target,
+                coinbase_target,
+            )
+            .unwrap()
+        };
+
+        // Ensure a coinbase that meets the coinbase target is paid the anchor reward.
+        assert_eq!(reward_for(u128::from(coinbase_target)), anchor_reward);
+        assert_eq!(reward_for(u128::MAX), anchor_reward);
+        // Ensure a coinbase below the coinbase target is paid proportionally.
+        let half_target = coinbase_target / 2;
+        assert_eq!(reward_for(u128::from(half_target)), anchor_reward * half_target / coinbase_target);
+        assert_eq!(reward_for(0), 0);
+
+        // Ensure a zero coinbase target is rejected.
+        assert!(coinbase_reward(1, CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::ANCHOR_TIME, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_split_reward() {
+        let rng = &mut TestRng::default();
+        let reward = sample_anchor_reward(1);
+
+        // Sample the prover addresses.
+        let address = |rng: &mut TestRng| Address::<CurrentNetwork>::new(Uniform::rand(rng));
+        let (alice, bob, carol) = (address(rng), address(rng), address(rng));
+
+        // Ensure a single prover receives the entire reward.
+        let rewards = split_reward(&[(alice, 42)], reward).unwrap();
+        assert_eq!(rewards, IndexMap::from([(alice, reward)]));
+
+        // Ensure the reward is split proportionally to the targets, and aggregated by prover.
+        let rewards = split_reward(&[(alice, 1), (bob, 2), (alice, 1), (carol, 4)], 800).unwrap();
+        assert_eq!(rewards, IndexMap::from([(alice, 200), (bob, 200), (carol, 400)]));
+
+        // Ensure the rewards are rounded down, and never exceed the reward.
+        let solutions = (0..100).map(|_| (address(rng), rng.gen_range(1..u64::MAX))).collect::<Vec<_>>();
+        let rewards = split_reward(&solutions, reward).unwrap();
+        let total = rewards.values().sum::<u64>();
+        assert!(total <= reward);
+        assert!(total > reward - solutions.len() as u64);
+
+        // Ensure a split without solutions is rejected.
+        assert!(split_reward::<CurrentNetwork>(&[], reward).is_err());
+    }
+}
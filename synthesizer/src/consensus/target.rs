@@ -0,0 +1,204 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use console::network::prelude::*;
+
+/// The number of fractional bits used in the fixed-point arithmetic of the retargeting function.
+const RBITS: u32 = 16;
+/// The fixed-point representation of `1`.
+const RADIX: u128 = 1 << RBITS;
+
+/// The number of bits the coinbase target is shifted by to compute the proof target,
+/// such that a coinbase requires at most `2^PROOF_TARGET_SHIFT` partial solutions at the proof target.
+const PROOF_TARGET_SHIFT: u32 = 7;
+
+/// Returns the coinbase target for a block at the given timestamp, retargeted from the last coinbase.
+///
+/// The coinbase target follows an anchor-based schedule, where one coinbase is expected per anchor time.
+/// For every half-life that the block is late (or early) against the anchor time, the target halves (or doubles):
+///     T = T_last * 2^(-(t - t_last - A) / tau), where tau = (NUM_BLOCKS_PER_EPOCH / 2) * A.
+///
+/// The coinbase target is floored at the given genesis coinbase target.
+pub fn coinbase_target(
+    last_coinbase_target: u64,
+    last_coinbase_timestamp: i64,
+    timestamp: i64,
+    anchor_time: u16,
+    num_blocks_per_epoch: u32,
+    genesis_coinbase_target: u64,
+) -> Result<u64> {
+    // Compute the half-life, in seconds.
+    let half_life = num_blocks_per_epoch.saturating_div(2).saturating_mul(u32::from(anchor_time));
+    ensure!(half_life > 0, "The retargeting half-life must be nonzero");
+
+    // Compute the time elapsed since the last coinbase, in seconds.
+    // Note: The elapsed time is at least 1 second, to guard against a repeated timestamp.
+    let elapsed = timestamp.saturating_sub(last_coinbase_timestamp).max(1);
+    // Compute the drift against the anchor time, which is negative if the coinbase is early.
+    let drift = elapsed.saturating_sub(i64::from(anchor_time));
+
+    // Retarget inversely to the drift, as a late coinbase must become easier to produce.
+    let target = retarget(last_coinbase_target, drift.saturating_neg(), half_life)?;
+    // Floor the coinbase target at the genesis coinbase target.
+    Ok(target.max(genesis_coinbase_target))
+}
+
+/// Returns the proof target for the given coinbase target, floored at the given genesis proof target.
+pub fn proof_target(coinbase_target: u64, genesis_proof_target: u64) -> u64 {
+    (coinbase_target >> PROOF_TARGET_SHIFT).saturating_add(1).max(genesis_proof_target)
+}
+
+/// Returns `target * 2^(exponent / half_life)`, computed in fixed-point arithmetic,
+/// and bounded to the range `[1, u64::MAX]`.
+fn retarget(target: u64, exponent: i64, half_life: u32) -> Result<u64> {
+    // If the exponent is zero, the target is unchanged.
+    if exponent == 0 {
+        return Ok(target);
+    }
+
+    // Compute the fixed-point exponent, and decompose it into its integral and fractional parts.
+    let exponent = (i128::from(exponent) * RADIX as i128).div_euclid(i128::from(half_life));
+    let integral = exponent >> RBITS;
+    let fractional = (exponent - (integral << RBITS)) as u128;
+    ensure!(fractional < RADIX, "The fractional part of the exponent is out of range");
+
+    // Approximate `RADIX * 2^(fractional / RADIX)` with a cubic polynomial, as follows:
+    //     2^x ~= 1 + 0.695502049 * x + 0.2262698 * x^2 + 0.0782318 * x^3, for x in [0, 1).
+    let multiplier = RADIX
+        + ((195_766_423_245_049 * fractional
+            + 971_821_376 * fractional.pow(2)
+            + 5_127 * fractional.pow(3)
+            + (1 << (RBITS * 3 - 1)))
+            >> (RBITS * 3));
+
+    // Scale the target by the fractional part.
+    // Note: This cannot overflow, as the target is at most 64 bits and the multiplier is at most 17 bits.
+    let target = u128::from(target) * multiplier;
+    // Scale the target by the integral part, removing the fixed-point scaling.
+    let shift = integral - i128::from(RBITS);
+    let target = match shift.is_negative() {
+        true => u32::try_from(shift.unsigned_abs()).ok().and_then(|shift| target.checked_shr(shift)).unwrap_or(0),
+        false => match shift < i128::from(target.leading_zeros()) {
+            true => target << shift,
+            false => u128::MAX,
+        },
+    };
+    // Bound the target to the range `[1, u64::MAX]`.
+    Ok(target.clamp(1, u128::from(u64::MAX)) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the retargeting half-life of the current network, in seconds.
+    fn half_life() -> i64 {
+        i64::from(CurrentNetwork::NUM_BLOCKS_PER_EPOCH / 2) * i64::from(CurrentNetwork::ANCHOR_TIME)
+    }
+
+    /// Returns the coinbase target of the current network, for the given target and time since the last coinbase.
+    fn sample_coinbase_target(last_coinbase_target: u64, elapsed: i64) -> u64 {
+        let last_coinbase_timestamp = CurrentNetwork::GENESIS_TIMESTAMP;
+        coinbase_target(
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            last_coinbase_timestamp + elapsed,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_coinbase_target() {
+        let anchor_time = i64::from(CurrentNetwork::ANCHOR_TIME);
+        let target = 1u64 << 40;
+
+        // Ensure a coinbase at the anchor time keeps the target.
+        assert_eq!(sample_coinbase_target(target, anchor_time), target);
+        // Ensure a coinbase one half-life late halves the target.
+        assert_eq!(sample_coinbase_target(target, anchor_time + half_life()), target / 2);
+        // Ensure a coinbase two half-lives late quarters the target.
+        assert_eq!(sample_coinbase_target(target, anchor_time + 2 * half_life()), target / 4);
+
+        // Ensure an early coinbase increases the target, and a late coinbase decreases it.
+        assert!(sample_coinbase_target(target, 1) > target);
+        assert!(sample_coinbase_target(target, anchor_time + 1) < target);
+        // Ensure a repeated (or earlier) timestamp is treated as a coinbase after 1 second.
+        assert_eq!(sample_coinbase_target(target, 0), sample_coinbase_target(target, 1));
+        assert_eq!(sample_coinbase_target(target, -anchor_time), sample_coinbase_target(target, 1));
+
+        // Ensure the target is monotonically decreasing in the elapsed time.
+        let mut previous = u64::MAX;
+        for elapsed in (1..10 * half_life()).step_by(7) {
+            let candidate = sample_coinbase_target(target, elapsed);
+            assert!(candidate <= previous, "The target increased at {elapsed} seconds");
+            previous = candidate;
+        }
+    }
+
+    #[test]
+    fn test_coinbase_target_bounds() {
+        // Ensure the coinbase target is floored at the genesis coinbase target.
+        let genesis_target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+        assert_eq!(sample_coinbase_target(genesis_target, 100 * half_life()), genesis_target);
+        assert_eq!(sample_coinbase_target(genesis_target, 1_000_000 * half_life()), genesis_target);
+
+        // Ensure the coinbase target saturates at the maximum target.
+        assert_eq!(sample_coinbase_target(u64::MAX, 1), u64::MAX);
+    }
+
+    #[test]
+    fn test_retarget() {
+        let half_life = 3200;
+
+        // Ensure the target doubles (or halves) for every half-life.
+        for target in [1u64, 1023, 1 << 20, 1 << 40] {
+            assert_eq!(retarget(target, 0, half_life).unwrap(), target);
+            assert_eq!(retarget(target, half_life as i64, half_life).unwrap(), target * 2);
+            assert_eq!(retarget(target, 3 * half_life as i64, half_life).unwrap(), target * 8);
+            assert_eq!(retarget(target, -(half_life as i64), half_life).unwrap(), (target / 2).max(1));
+        }
+
+        // Ensure the fractional approximation is within 0.1% of `2^(1/2)`.
+        let target = 1u64 << 40;
+        let expected = (target as f64) * 2f64.sqrt();
+        let candidate = retarget(target, half_life as i64 / 2, half_life).unwrap() as f64;
+        assert!((candidate - expected).abs() / expected < 0.001);
+
+        // Ensure the target is bounded.
+        assert_eq!(retarget(u64::MAX, i64::MAX, half_life).unwrap(), u64::MAX);
+        assert_eq!(retarget(1, i64::MIN, half_life).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_proof_target() {
+        // Ensure the genesis targets are consistent.
+        let genesis_proof_target = CurrentNetwork::GENESIS_PROOF_TARGET;
+        assert_eq!(proof_target(CurrentNetwork::GENESIS_COINBASE_TARGET, genesis_proof_target), genesis_proof_target);
+
+        // Ensure the proof target is always less than the coinbase target.
+        for coinbase_target in [CurrentNetwork::GENESIS_COINBASE_TARGET, 1 << 20, 1 << 40, u64::MAX] {
+            assert!(proof_target(coinbase_target, genesis_proof_target) < coinbase_target);
+        }
+        // Ensure the proof target is floored at the genesis proof target.
+        assert_eq!(proof_target(0, genesis_proof_target), genesis_proof_target);
+    }
+}
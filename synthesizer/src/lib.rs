@@ -31,6 +31,9 @@ pub use block::*;
 pub mod coinbase_puzzle;
 pub use coinbase_puzzle::*;
 
+pub mod consensus;
+pub use consensus::*;
+
 pub mod process;
 pub use process::*;
 
@@ -39,6 +39,40 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys the given program as the given edition of an existing program,
+    /// signed by the upgrade authority of the existing program.
+    #[inline]
+    pub fn upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        edition: u16,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("Process::upgrade");
+
+        // Ensure the upgrade is authorized and preserves the interface of the existing program.
+        self.check_upgrade(program, &Address::try_from(private_key)?)?;
+        lap!(timer, "Check the upgrade");
+
+        // Compute the stack, in place of the existing program.
+        let stack = Stack::new(&self.without_program(program.id()), program)?;
+        lap!(timer, "Compute the stack");
+
+        // Compute the verifying keys and certificates.
+        let deployment = stack.deploy::<A, R>(rng)?;
+        lap!(timer, "Construct the deployment");
+
+        // Sign the upgrade.
+        let (program, verifying_keys) = (deployment.program().clone(), deployment.verifying_keys().clone());
+        let upgrade = Deployment::upgrade(edition, program, verifying_keys, private_key, rng);
+        lap!(timer, "Sign the upgrade");
+
+        finish!(timer);
+
+        upgrade
+    }
+
     /// Verifies the given deployment is ordered.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -49,10 +83,20 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::verify_deployment");
         // Retrieve the program ID.
         let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = match deployment.upgrade_authority() {
+            // If the deployment is an upgrade, compute the stack in place of the existing program.
+            Some(upgrade_authority) => {
+                // Ensure the upgrade is authorized and preserves the interface of the existing program.
+                self.check_upgrade(deployment.program(), &upgrade_authority)?;
+                Stack::new(&self.without_program(program_id), deployment.program())?
+            }
+            None => {
+                // Ensure the program does not already exist in the process.
+                ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+                Stack::new(self, deployment.program())?
+            }
+        };
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -72,6 +116,11 @@ impl<N: Network> Process<N> {
         store: &ProgramStore<N, P>,
         deployment: &Deployment<N>,
     ) -> Result<()> {
+        // If the deployment is an upgrade, replace the existing program.
+        if deployment.is_upgrade() {
+            return self.finalize_upgrade(store, deployment);
+        }
+
         let timer = timer!("Process::finalize_deployment");
 
         // TODO (howardwu): Make this function atomic.
@@ -95,6 +144,10 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Initialize the program mappings");
 
+        // Record the edition of the program.
+        store.insert_edition(program_id, deployment.edition(), deployment.to_checksum()?)?;
+        lap!(timer, "Record the edition");
+
         // Add the stack to the process.
         self.stacks.insert(*deployment.program_id(), stack);
 
@@ -103,6 +156,66 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
+    /// Finalizes the upgrade, by replacing the stack of the existing program and recording the new edition.
+    /// The mappings of the existing program are preserved, and the stacks are swapped atomically.
+    /// This method assumes the given deployment **is valid**.
+    #[inline]
+    fn finalize_upgrade<P: ProgramStorage<N>>(
+        &mut self,
+        store: &ProgramStore<N, P>,
+        deployment: &Deployment<N>,
+    ) -> Result<()> {
+        let timer = timer!("Process::finalize_upgrade");
+
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure the upgrade is authorized and preserves the interface of the existing program.
+        match deployment.upgrade_authority() {
+            Some(upgrade_authority) => self.check_upgrade(deployment.program(), &upgrade_authority)?,
+            None => bail!("The upgrade of '{program_id}' is missing the upgrade signature"),
+        }
+        // Ensure the upgrade is the next edition of the program.
+        let edition = store.get_edition(program_id)?.unwrap_or(N::EDITION);
+        ensure!(
+            edition.checked_add(1) == Some(deployment.edition()),
+            "The upgrade of '{program_id}' must be edition '{}', found '{}'",
+            edition.saturating_add(1),
+            deployment.edition()
+        );
+        lap!(timer, "Check the upgrade");
+
+        // Compute the program stack, in place of the existing program.
+        let stack = Stack::new(&self.without_program(program_id), deployment.program())?;
+        lap!(timer, "Compute the stack");
+
+        // Insert the verifying keys.
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            stack.insert_verifying_key(function_name, verifying_key.clone())?;
+        }
+        lap!(timer, "Insert the verifying keys");
+
+        // Replace the stack of the existing program, and refresh the stacks that import it.
+        // Note: The stacks are replaced in a copy of the process, so that a failure leaves the process unchanged.
+        let mut process = self.clone();
+        match process.stacks.get_mut(program_id) {
+            Some(existing_stack) => *existing_stack = stack,
+            None => bail!("Program '{program_id}' does not exist"),
+        }
+        process.refresh_external_stacks(program_id)?;
+        lap!(timer, "Replace the stacks");
+
+        // Record the edition of the program.
+        store.insert_edition(program_id, deployment.edition(), deployment.to_checksum()?)?;
+        lap!(timer, "Record the edition");
+
+        // Swap in the upgraded stacks.
+        *self = process;
+
+        finish!(timer);
+
+        Ok(())
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -126,6 +239,61 @@ impl<N: Network> Process<N> {
 
         Ok(())
     }
+
+    /// Checks that the given program is a valid upgrade of the existing program, authorized by the given address.
+    #[inline]
+    fn check_upgrade(&self, program: &Program<N>, authority: &Address<N>) -> Result<()> {
+        // Retrieve the existing program.
+        let program_id = program.id();
+        let existing_program = self.get_program(program_id)?;
+        // Ensure the existing program is upgradable by the given authority.
+        match existing_program.upgrade_authority() {
+            Some(upgrade_authority) => ensure!(
+                upgrade_authority == authority,
+                "The upgrade of '{program_id}' is not authorized by its upgrade authority"
+            ),
+            None => bail!("Program '{program_id}' does not declare an upgrade authority, and cannot be upgraded"),
+        }
+        // Ensure the upgrade preserves the interface of the existing program.
+        program.check_upgrade_of(existing_program)
+    }
+
+    /// Returns a copy of the process without the given program, to compute the stack of its upgrade.
+    #[inline]
+    fn without_program(&self, program_id: &ProgramID<N>) -> Self {
+        let mut process = self.clone();
+        process.stacks.shift_remove(program_id);
+        process
+    }
+
+    /// Refreshes the external stacks of each program that imports the given program, directly or transitively.
+    /// Note: This relies on the stacks being ordered such that each program comes after its imports.
+    #[inline]
+    fn refresh_external_stacks(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Track the program IDs whose stacks have been replaced.
+        let mut refreshed = vec![*program_id];
+        for index in 0..self.stacks.len() {
+            // Retrieve the stack.
+            let (stack_program_id, stack) = match self.stacks.get_index(index) {
+                Some((stack_program_id, stack)) => (*stack_program_id, stack),
+                None => bail!("Failed to retrieve the stack at index {index}"),
+            };
+            // Determine the imports whose stacks have been replaced.
+            let imports: Vec<_> =
+                stack.program().imports().keys().filter(|import| refreshed.contains(import)).copied().collect();
+            if imports.is_empty() {
+                continue;
+            }
+            // Replace the external stacks of the imports.
+            let mut stack = stack.clone();
+            for import in imports {
+                stack.replace_external_stack(self.get_stack(import)?.clone())?;
+            }
+            self.stacks.insert(stack_program_id, stack);
+            refreshed.push(stack_program_id);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -135,6 +303,96 @@ mod tests {
 
     type CurrentAleo = circuit::network::AleoV0;
 
+    #[test]
+    fn test_finalize_upgrade() {
+        use crate::store::ProgramMemory;
+        use console::network::Testnet3;
+
+        let rng = &mut TestRng::default();
+
+        // Sample the upgrade authority, and an unauthorized account.
+        let private_key = PrivateKey::<Testnet3>::new(rng).unwrap();
+        let authority = Address::try_from(&private_key).unwrap();
+        let unauthorized_private_key = PrivateKey::<Testnet3>::new(rng).unwrap();
+
+        // Declare an upgradable program, with a function body to be replaced.
+        let program_string = |operation: &str| {
+            format!(
+                r"
+program upgradable.aleo;
+
+upgrade_authority {authority};
+
+mapping counts:
+    key owner as address.public;
+    value count as u64.public;
+
+function compute:
+    input r0 as u64.private;
+    {operation} r0 r0 into r1;
+    output r1 as u64.private;"
+            )
+        };
+        let program = Program::<Testnet3>::from_str(&program_string("add")).unwrap();
+        let upgraded_program = Program::<Testnet3>::from_str(&program_string("mul")).unwrap();
+        // Declare a program that imports the upgradable program.
+        let importer = Program::<Testnet3>::from_str(
+            r"
+import upgradable.aleo;
+
+program importer.aleo;
+
+function forward:
+    input r0 as u64.private;
+    output r0 as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize a new process and program store.
+        let mut process = Process::load().unwrap();
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Deploy the programs.
+        for program in [&program, &importer] {
+            let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+            process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+            process.finalize_deployment(&store, &deployment).unwrap();
+        }
+        assert_eq!(store.get_edition(program.id()).unwrap(), Some(0));
+
+        // Ensure an upgrade that is not signed by the upgrade authority fails.
+        assert!(process.upgrade::<CurrentAleo, _>(&upgraded_program, 1, &unauthorized_private_key, rng).is_err());
+        // Ensure an upgrade that changes the interface fails.
+        let invalid_program = Program::<Testnet3>::from_str(&program_string("add").replace("u64", "u32")).unwrap();
+        assert!(process.upgrade::<CurrentAleo, _>(&invalid_program, 1, &private_key, rng).is_err());
+        // Ensure a program without an upgrade authority cannot be upgraded.
+        assert!(process.upgrade::<CurrentAleo, _>(&importer, 1, &private_key, rng).is_err());
+
+        // Upgrade the program.
+        let upgrade = process.upgrade::<CurrentAleo, _>(&upgraded_program, 1, &private_key, rng).unwrap();
+        assert!(upgrade.is_upgrade());
+        assert_eq!(upgrade.upgrade_authority(), Some(authority));
+        // Ensure the upgrade round-trips through bytes.
+        assert_eq!(upgrade, Deployment::from_bytes_le(&upgrade.to_bytes_le().unwrap()).unwrap());
+        // Verify and finalize the upgrade.
+        process.verify_deployment::<CurrentAleo, _>(&upgrade, rng).unwrap();
+        process.finalize_deployment(&store, &upgrade).unwrap();
+
+        // Ensure the stack, and the external stack in the importing program, were replaced.
+        assert_eq!(process.get_program(program.id()).unwrap(), &upgraded_program);
+        let importer_stack = process.get_stack(importer.id()).unwrap();
+        assert_eq!(importer_stack.get_external_program(program.id()).unwrap(), &upgraded_program);
+        // Ensure the mappings were preserved, and the edition history was recorded.
+        assert!(store.contains_mapping(program.id(), &Identifier::from_str("counts").unwrap()).unwrap());
+        assert_eq!(store.get_edition(program.id()).unwrap(), Some(1));
+        let history = store.get_edition_history(program.id()).unwrap().unwrap();
+        assert_eq!(history.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(history[&1], upgrade.to_checksum().unwrap());
+
+        // Ensure the same upgrade cannot be finalized again.
+        assert!(process.finalize_deployment(&store, &upgrade).is_err());
+    }
+
     #[test]
     fn test_finalize_deployment() {
         let rng = &mut TestRng::default();
@@ -15,8 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::finalize::{Branch, Command, Finalize};
 use console::program::Literal;
 
+use std::panic::AssertUnwindSafe;
+
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
     #[inline]
@@ -64,6 +67,49 @@ impl<N: Network> Process<N> {
     pub fn verify_execution<const VERIFY_INCLUSION: bool>(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Ensure the execution is well-formed.
+        self.verify_execution_layout::<VERIFY_INCLUSION>(execution)?;
+        lap!(timer, "Verify the execution layout");
+
+        // Replicate the execution stack for verification.
+        let mut queue = execution.clone();
+
+        // Verify each transition.
+        while let Ok(transition) = queue.pop() {
+            self.verify_transition(&transition, &queue)?;
+            lap!(timer, "Verify transition for {}", transition.function_name());
+        }
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given execution, and returns the outcome of verifying each transition.
+    /// Unlike `verify_execution`, every transition is verified, even if a prior transition is invalid.
+    /// Note: The execution is valid iff every transition outcome is `Ok`.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_transitions<const VERIFY_INCLUSION: bool>(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<IndexMap<N::TransitionID, Result<()>>> {
+        // Ensure the execution is well-formed.
+        self.verify_execution_layout::<VERIFY_INCLUSION>(execution)?;
+
+        // Replicate the execution stack for verification.
+        let mut queue = execution.clone();
+
+        // Verify each transition, in the order of the execution.
+        let mut outcomes = Vec::with_capacity(execution.len());
+        while let Ok(transition) = queue.pop() {
+            outcomes.push((*transition.id(), self.verify_transition(&transition, &queue)));
+        }
+        Ok(outcomes.into_iter().rev().collect())
+    }
+
+    /// Verifies the number of transitions in the given execution, and its inclusion proof (if requested).
+    #[inline]
+    fn verify_execution_layout<const VERIFY_INCLUSION: bool>(&self, execution: &Execution<N>) -> Result<()> {
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -81,190 +127,239 @@ impl<N: Network> Process<N> {
                 execution.len()
             );
         }
-        lap!(timer, "Verify the number of transitions");
 
         // Ensure the inclusion proof is valid.
         if VERIFY_INCLUSION {
             Inclusion::verify_execution(execution)?;
-            lap!(timer, "Verify the inclusion proof");
         }
+        Ok(())
+    }
 
-        // Replicate the execution stack for verification.
-        let mut queue = execution.clone();
-
-        // Verify each transition.
-        while let Ok(transition) = queue.pop() {
-            #[cfg(debug_assertions)]
-            println!("Verifying transition for {}/{}...", transition.program_id(), transition.function_name());
-
-            // Ensure the transition ID is correct.
-            ensure!(**transition.id() == transition.to_root()?, "The transition ID is incorrect");
-            // Ensure the number of inputs is within the allowed range.
-            ensure!(transition.inputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of inputs");
-            // Ensure the number of outputs is within the allowed range.
-            ensure!(transition.outputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of outputs");
-
-            // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-            let function_id = N::hash_bhp1024(
-                &(
-                    U16::<N>::new(N::ID),
-                    transition.program_id().name(),
-                    transition.program_id().network(),
-                    transition.function_name(),
-                )
-                    .to_bits_le(),
-            )?;
-
-            // Ensure each input is valid.
-            if transition
-                .inputs()
-                .iter()
-                .enumerate()
-                .any(|(index, input)| !input.verify(function_id, transition.tcm(), index))
-            {
-                bail!("Failed to verify a transition input")
-            }
-            lap!(timer, "Verify the inputs");
-
-            // Ensure each output is valid.
-            let num_inputs = transition.inputs().len();
-            if transition
-                .outputs()
-                .iter()
-                .enumerate()
-                .any(|(index, output)| !output.verify(function_id, transition.tcm(), num_inputs + index))
-            {
-                bail!("Failed to verify a transition output")
-            }
-            lap!(timer, "Verify the outputs");
-
-            // Ensure the fee is correct.
-            match Program::is_coinbase(transition.program_id(), transition.function_name()) {
-                true => ensure!(transition.fee() < &0, "The fee must be negative in a coinbase transition"),
-                false => ensure!(transition.fee() >= &0, "The fee must be zero or positive"),
-            }
-
-            // Compute the x- and y-coordinate of `tpk`.
-            let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
+    /// Verifies the given transition, where `queue` contains the transitions that precede it in the execution.
+    #[inline]
+    fn verify_transition(&self, transition: &Transition<N>, queue: &Execution<N>) -> Result<()> {
+        let timer = timer!("Process::verify_transition");
+
+        #[cfg(debug_assertions)]
+        println!("Verifying transition for {}/{}...", transition.program_id(), transition.function_name());
+
+        // Ensure the transition ID is correct.
+        ensure!(**transition.id() == transition.to_root()?, "The transition ID is incorrect");
+        // Ensure the number of inputs is within the allowed range.
+        ensure!(transition.inputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of inputs");
+        // Ensure the number of outputs is within the allowed range.
+        ensure!(transition.outputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of outputs");
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(
+                U16::<N>::new(N::ID),
+                transition.program_id().name(),
+                transition.program_id().network(),
+                transition.function_name(),
+            )
+                .to_bits_le(),
+        )?;
+
+        // Ensure each input is valid.
+        if transition
+            .inputs()
+            .iter()
+            .enumerate()
+            .any(|(index, input)| !input.verify(function_id, transition.tcm(), index))
+        {
+            bail!("Failed to verify a transition input")
+        }
+        lap!(timer, "Verify the inputs");
+
+        // Ensure each output is valid.
+        let num_inputs = transition.inputs().len();
+        if transition
+            .outputs()
+            .iter()
+            .enumerate()
+            .any(|(index, output)| !output.verify(function_id, transition.tcm(), num_inputs + index))
+        {
+            bail!("Failed to verify a transition output")
+        }
+        lap!(timer, "Verify the outputs");
 
-            // [Inputs] Construct the verifier inputs to verify the proof.
-            let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **transition.tcm()];
-            // [Inputs] Extend the verifier inputs with the input IDs.
-            inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
+        // Ensure the fee is correct.
+        match Program::is_coinbase(transition.program_id(), transition.function_name()) {
+            true => ensure!(transition.fee() < &0, "The fee must be negative in a coinbase transition"),
+            false => ensure!(transition.fee() >= &0, "The fee must be zero or positive"),
+        }
 
-            // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
-            // Retrieve the function from the stack.
-            let function = stack.get_function(transition.function_name())?;
-            // Determine the number of function calls in this function.
-            let mut num_function_calls = 0;
-            for instruction in function.instructions() {
-                if let Instruction::Call(call) = instruction {
-                    // Determine if this is a function call.
-                    if call.is_function_call(stack)? {
-                        num_function_calls += 1;
-                    }
+        // Compute the x- and y-coordinate of `tpk`.
+        let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
+
+        // [Inputs] Construct the verifier inputs to verify the proof.
+        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **transition.tcm()];
+        // [Inputs] Extend the verifier inputs with the input IDs.
+        inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
+
+        // Retrieve the stack.
+        let stack = self.get_stack(transition.program_id())?;
+        // Retrieve the function from the stack.
+        let function = stack.get_function(transition.function_name())?;
+        // Determine the number of function calls in this function.
+        let mut num_function_calls = 0;
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Determine if this is a function call.
+                if call.is_function_call(stack)? {
+                    num_function_calls += 1;
                 }
             }
-            // If there are function calls, append their inputs and outputs.
-            if num_function_calls > 0 {
-                // This loop takes the last `num_function_call` transitions, and reverses them
-                // to order them in the order they were defined in the function.
-                for transition in queue.transitions().rev().take(num_function_calls).rev() {
-                    // [Inputs] Extend the verifier inputs with the input IDs of the external call.
-                    inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
-                    // [Inputs] Extend the verifier inputs with the output IDs of the external call.
-                    inputs.extend(transition.output_ids().map(|id| **id));
-                }
+        }
+        // If there are function calls, append their inputs and outputs.
+        if num_function_calls > 0 {
+            // This loop takes the last `num_function_call` transitions, and reverses them
+            // to order them in the order they were defined in the function.
+            for transition in queue.transitions().rev().take(num_function_calls).rev() {
+                // [Inputs] Extend the verifier inputs with the input IDs of the external call.
+                inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
+                // [Inputs] Extend the verifier inputs with the output IDs of the external call.
+                inputs.extend(transition.output_ids().map(|id| **id));
             }
+        }
 
-            // [Inputs] Extend the verifier inputs with the output IDs.
-            inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
-
-            // Ensure the transition contains finalize inputs, if the function has a finalize scope.
-            if let Some((command, logic)) = function.finalize() {
-                // Ensure the transition contains finalize inputs.
-                match transition.finalize() {
-                    Some(finalize) => {
-                        // Retrieve the number of operands.
-                        let num_operands = command.operands().len();
-                        // Retrieve the number of inputs.
-                        let num_inputs = logic.inputs().len();
-
-                        // Ensure the number of inputs for finalize is within the allowed range.
-                        ensure!(finalize.len() <= N::MAX_INPUTS, "Transition exceeds maximum inputs for finalize");
-                        // Ensure the number of inputs for finalize matches in the finalize command.
-                        ensure!(finalize.len() == num_operands, "The number of inputs for finalize is incorrect");
-                        // Ensure the number of inputs for finalize matches in the finalize logic.
-                        ensure!(finalize.len() == num_inputs, "The number of inputs for finalize is incorrect");
-
-                        // Convert the finalize inputs into concatenated bits.
-                        let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
-                        // Compute the checksum of the finalize inputs.
-                        let checksum = N::hash_bhp1024(&finalize_bits)?;
-
-                        // [Inputs] Extend the verifier inputs with the inputs for finalize.
-                        inputs.push(*checksum);
-                    }
-                    None => bail!("The transition is missing inputs for 'finalize'"),
+        // [Inputs] Extend the verifier inputs with the output IDs.
+        inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
+
+        // Ensure the transition contains finalize inputs, if the function has a finalize scope.
+        if let Some((command, logic)) = function.finalize() {
+            // Ensure the transition contains finalize inputs.
+            match transition.finalize() {
+                Some(finalize) => {
+                    // Retrieve the number of operands.
+                    let num_operands = command.operands().len();
+                    // Retrieve the number of inputs.
+                    let num_inputs = logic.inputs().len();
+
+                    // Ensure the number of inputs for finalize is within the allowed range.
+                    ensure!(finalize.len() <= N::MAX_INPUTS, "Transition exceeds maximum inputs for finalize");
+                    // Ensure the number of inputs for finalize matches in the finalize command.
+                    ensure!(finalize.len() == num_operands, "The number of inputs for finalize is incorrect");
+                    // Ensure the number of inputs for finalize matches in the finalize logic.
+                    ensure!(finalize.len() == num_inputs, "The number of inputs for finalize is incorrect");
+
+                    // Convert the finalize inputs into concatenated bits.
+                    let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
+                    // Compute the checksum of the finalize inputs.
+                    let checksum = N::hash_bhp1024(&finalize_bits)?;
+
+                    // [Inputs] Extend the verifier inputs with the inputs for finalize.
+                    inputs.push(*checksum);
                 }
+                None => bail!("The transition is missing inputs for 'finalize'"),
             }
+        }
 
-            // [Inputs] Extend the verifier inputs with the fee.
-            inputs.push(*I64::<N>::new(*transition.fee()).to_field()?);
-            lap!(timer, "Construct the verifier inputs");
+        // [Inputs] Extend the verifier inputs with the fee.
+        inputs.push(*I64::<N>::new(*transition.fee()).to_field()?);
+        lap!(timer, "Construct the verifier inputs");
 
-            #[cfg(debug_assertions)]
-            println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
+        #[cfg(debug_assertions)]
+        println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-            // Retrieve the verifying key.
-            let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
-            // Ensure the transition proof is valid.
-            ensure!(
-                verifying_key.verify(function.name(), &inputs, transition.proof()),
-                "Transition is invalid - failed to verify transition proof"
-            );
+        // Retrieve the verifying key.
+        let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
+        // Ensure the transition proof is valid.
+        ensure!(
+            verifying_key.verify(function.name(), &inputs, transition.proof()),
+            "Transition is invalid - failed to verify transition proof"
+        );
 
-            lap!(timer, "Verify transition proof for {}", function.name());
-        }
+        lap!(timer, "Verify transition proof for {}", function.name());
 
         finish!(timer);
         Ok(())
     }
 
-    /// Finalizes the execution.
+    /// Finalizes the execution, and returns the finalize outputs of each finalized transition.
     /// This method assumes the given execution **is valid**.
     #[inline]
     pub fn finalize_execution<P: ProgramStorage<N>>(
         &self,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
-    ) -> Result<()> {
+    ) -> Result<IndexMap<N::TransitionID, Vec<Value<N>>>> {
+        self.finalize_execution_with_mappings(store, execution).map(|(finalize_outputs, _)| finalize_outputs)
+    }
+
+    /// Finalizes the execution, and returns the finalize outputs of each finalized transition,
+    /// along with the `(program ID, mapping name, transition ID)` of each mapping touched by a finalize scope.
+    /// Note: A mapping is only touched by a finalize scope if one of its evaluated commands reads or writes it.
+    /// This method assumes the given execution **is valid**.
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn finalize_execution_with_mappings<P: ProgramStorage<N>>(
+        &self,
+        store: &ProgramStore<N, P>,
+        execution: &Execution<N>,
+    ) -> Result<(IndexMap<N::TransitionID, Vec<Value<N>>>, Vec<(ProgramID<N>, Identifier<N>, N::TransitionID)>)> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
-        // Ensure the number of transitions matches the program function.
-        {
-            // Retrieve the transition (without popping it).
+        // Retrieve the call graph of the main function.
+        let call_graph = {
+            // Retrieve the main transition (without popping it).
             let transition = execution.peek()?;
             // Retrieve the stack.
             let stack = self.get_stack(transition.program_id())?;
-            // Ensure the number of calls matches the number of transitions.
-            let number_of_calls = stack.get_number_of_calls(transition.function_name())?;
+            // Retrieve the call graph.
+            stack.get_call_graph(transition.function_name())?
+        };
+        // Ensure the number of calls matches the number of transitions.
+        ensure!(
+            call_graph.len() == execution.len(),
+            "The number of transitions in the execution is incorrect. Expected {}, but found {}",
+            call_graph.len(),
+            execution.len()
+        );
+        lap!(timer, "Verify the number of transitions");
+
+        // Ensure each transition matches its call in the call graph, and contains the expected finalize inputs.
+        for (transition, (program_id, function_name)) in execution.transitions().zip_eq(&call_graph) {
+            // Ensure the transition corresponds to the call.
             ensure!(
-                number_of_calls == execution.len(),
-                "The number of transitions in the execution is incorrect. Expected {number_of_calls}, but found {}",
-                execution.len()
+                transition.program_id() == program_id && transition.function_name() == function_name,
+                "Expected a transition for '{program_id}/{function_name}', but found '{}/{}'",
+                transition.program_id(),
+                transition.function_name()
             );
+            // Retrieve the function.
+            let function = self.get_stack(program_id)?.get_function(function_name)?;
+            // Ensure the finalize inputs line up with the finalize scope of the function.
+            match (function.finalize(), transition.finalize()) {
+                (Some((_, finalize)), Some(inputs)) => ensure!(
+                    finalize.inputs().len() == inputs.len(),
+                    "The transition for '{program_id}/{function_name}' contains {} inputs for 'finalize', but expected {}",
+                    inputs.len(),
+                    finalize.inputs().len()
+                ),
+                (Some(..), None) => {
+                    bail!("The transition for '{program_id}/{function_name}' is missing inputs for 'finalize'")
+                }
+                (None, Some(..)) => {
+                    bail!("The transition for '{program_id}/{function_name}' contains unexpected inputs for 'finalize'")
+                }
+                (None, None) => (),
+            }
         }
-        lap!(timer, "Verify the number of transitions");
+        lap!(timer, "Verify the call graph");
 
-        // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
-        //  Currently this loop assumes a linearly execution stack.
-        // Finalize each transition, starting from the last one.
-        for transition in execution.transitions().rev() {
+        // Initialize a map for the finalize outputs of each transition.
+        let mut finalize_outputs = IndexMap::new();
+        // Initialize a list for the mappings touched by each transition.
+        let mut mapping_transitions = Vec::new();
+
+        // Finalize each transition, in the order of the call graph.
+        // Note: The calls of a function are finalized before the function itself.
+        for transition in execution.transitions() {
             #[cfg(debug_assertions)]
             println!("Finalizing transition for {}/{}...", transition.program_id(), transition.function_name());
 
@@ -291,20 +386,45 @@ impl<N: Network> Process<N> {
                     registers.store(stack, register, input.clone())
                 })?;
 
+                // Initialize a set for the mappings touched by the finalize scope.
+                let mut mapping_names = IndexSet::new();
+                // Initialize a counter for the command index.
+                let mut counter = 0;
                 // Evaluate the commands.
-                for command in finalize.commands() {
+                while counter < finalize.commands().len() {
+                    // Retrieve the command.
+                    let command = &finalize.commands()[counter];
+                    // Evaluate the command, and retrieve the index of the next command.
+                    // Note: An operation that overflows or underflows halts, which is returned as an error.
+                    let result = std::panic::catch_unwind(AssertUnwindSafe(|| match command {
+                        Command::BranchEq(branch_eq) => {
+                            Self::branch_to(counter, finalize, stack, &registers, branch_eq)
+                        }
+                        Command::BranchNeq(branch_neq) => {
+                            Self::branch_to(counter, finalize, stack, &registers, branch_neq)
+                        }
+                        _ => command.evaluate_finalize(stack, store, &mut registers).map(|_| counter + 1),
+                    }))
+                    .unwrap_or_else(|error| match error.downcast_ref::<String>() {
+                        Some(message) => Err(anyhow!("{message}")),
+                        None => Err(anyhow!("The command halted")),
+                    });
                     // If the evaluation fails, bail and return the error.
-                    if let Err(error) = command.evaluate_finalize(stack, store, &mut registers) {
-                        bail!("'finalize' failed to evaluate command ({command}): {error}");
+                    counter = match result {
+                        Ok(next) => next,
+                        Err(error) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
+                    };
+                    // Record the mapping touched by the command, if any.
+                    if let Some(mapping_name) = command.mapping_name() {
+                        mapping_names.insert(*mapping_name);
                     }
                 }
 
                 // Retrieve the output operands.
                 let output_operands = finalize.outputs().iter().map(|output| output.operand());
 
-                // TODO (howardwu): Save the outputs in ProgramStore.
                 // Load the outputs.
-                let _outputs = output_operands
+                let outputs = output_operands
                     .map(|operand| {
                         // Load the outputs.
                         match operand {
@@ -322,11 +442,47 @@ impl<N: Network> Process<N> {
                     })
                     .collect::<Result<Vec<_>>>()?;
 
+                // Save the outputs in storage.
+                store.insert_finalize_outputs(transition.id(), outputs.clone())?;
+                // Record the outputs for the transition.
+                finalize_outputs.insert(*transition.id(), outputs);
+                // Record the mappings touched by the transition.
+                mapping_transitions.extend(
+                    mapping_names
+                        .into_iter()
+                        .map(|mapping_name| (*transition.program_id(), mapping_name, *transition.id())),
+                );
+
                 lap!(timer, "Finalize transition for {function_name}");
             }
         }
         finish!(timer);
 
-        Ok(())
+        Ok((finalize_outputs, mapping_transitions))
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Evaluates the given branch, and returns the index of the next command to evaluate.
+    #[inline]
+    fn branch_to<const VARIANT: u8>(
+        counter: usize,
+        finalize: &Finalize<N>,
+        stack: &Stack<N>,
+        registers: &FinalizeRegisters<N>,
+        branch: &Branch<N, VARIANT>,
+    ) -> Result<usize> {
+        // Retrieve the index of the position.
+        let position = match finalize.positions().get(branch.position()) {
+            Some(position) => *position,
+            None => bail!("Position '{}' does not exist.", branch.position()),
+        };
+        // Ensure the branch jumps forward.
+        ensure!(position > counter, "Forbidden operation: Cannot branch backwards to position '{}'", branch.position());
+        // If the condition is met, jump to the position. Otherwise, proceed to the next command.
+        match branch.evaluate_finalize(stack, registers)? {
+            true => Ok(position),
+            false => Ok(counter + 1),
+        }
     }
 }
@@ -87,7 +87,7 @@ impl<N: Network> Process<N> {
     /// Verifies the given fee is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
-    pub fn verify_fee(&self, fee: &Fee<N>) -> Result<()> {
+    pub fn verify_fee<const VERIFY_INCLUSION: bool>(&self, fee: &Fee<N>) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
         #[cfg(debug_assertions)]
@@ -137,8 +137,10 @@ impl<N: Network> Process<N> {
         ensure!(fee.fee() >= &0, "The fee must be zero or positive");
 
         // Ensure the inclusion proof is valid.
-        Inclusion::verify_fee(fee)?;
-        lap!(timer, "Verify the inclusion proof");
+        if VERIFY_INCLUSION {
+            Inclusion::verify_fee(fee)?;
+            lap!(timer, "Verify the inclusion proof");
+        }
 
         // Compute the x- and y-coordinate of `tpk`.
         let (tpk_x, tpk_y) = fee.tpk().to_xy_coordinates();
@@ -30,14 +30,14 @@ use crate::{
     store::{ProgramStorage, ProgramStore},
 };
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey},
     network::prelude::*,
     program::{Identifier, Plaintext, ProgramID, Record, Request, Response, Value},
     types::{I64, U16, U64},
 };
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -884,6 +884,122 @@ function hello_world:
         );
     }
 
+    #[test]
+    fn test_process_cast_literal() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program cast.aleo;
+
+  function narrow:
+    input r0 as u64.private;
+    cast r0 into r1 as u8;
+    output r1 as u8.private;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("narrow").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Ensure a value in range is cast.
+        let r0 = Value::<CurrentNetwork>::from_str("255u64").unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(Value::from_str("255u8").unwrap(), candidate[0]);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(Value::from_str("255u8").unwrap(), candidate[0]);
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Ensure a value that overflows the destination type fails.
+        let r0 = Value::<CurrentNetwork>::from_str("256u64").unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+        assert!(process.evaluate::<CurrentAleo>(authorization.replicate()).is_err());
+        assert!(process.execute::<CurrentAleo, _>(authorization, rng).is_err());
+    }
+
+    #[test]
+    fn test_process_array_index() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program array.aleo;
+
+  function reverse:
+    input r0 as [u8; 4u32].private;
+    add r0[1u32] r0[3u32] into r1;
+    cast r0[3u32] r0[2u32] r0[1u32] r0[0u32] into r2 as [u8; 4u32];
+    output r1 as u8.private;
+    output r2 as [u8; 4u32].private;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("reverse").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+
+        // Declare the input and the expected output values.
+        let r0 = Value::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8, 4u8]").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("6u8").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("[4u8, 3u8, 2u8, 1u8]").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+            .unwrap();
+
+        // Compute the output values.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(&[r1.clone(), r2.clone()], response.outputs());
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(&[r1, r2], response.outputs());
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Ensure an input with the wrong number of elements is rejected.
+        let r0 = Value::<CurrentNetwork>::from_str("[1u8, 2u8, 3u8]").unwrap();
+        assert!(
+            process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0].iter(), rng)
+                .is_err()
+        );
+
+        // Ensure an index that is out of bounds is rejected.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program array.aleo;
+
+  function out_of_bounds:
+    input r0 as [u8; 4u32].private;
+    add r0[0u32] r0[4u32] into r1;
+    output r1 as u8.private;",
+        )
+        .unwrap();
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+    }
+
     #[test]
     fn test_process_execute_call_closure() {
         // Initialize a new program.
@@ -1269,7 +1385,7 @@ finalize compute:
     }
 
     #[test]
-    fn test_process_execute_and_finalize_increment_decrement() {
+    fn test_process_execute_and_finalize_get_set() {
         // Initialize a new program.
         let (string, program) = Program::<CurrentNetwork>::parse(
             r"
@@ -1289,8 +1405,11 @@ function compute:
 finalize compute:
     input r0 as address.public;
     input r1 as u64.public;
-    increment account[r0] by r1;
-    decrement account[r0] by r1;
+    get.or_init account[r0] 0u64 into r2;
+    set r1 into account[r0];
+    get account[r0] into r3;
+    add r2 r3 into r4;
+    set r4 into account[r0];
 ",
         )
         .unwrap();
@@ -1326,7 +1445,6 @@ finalize compute:
 
         // Initialize a new caller account.
         let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-        let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
         let caller = Address::try_from(&caller_private_key).unwrap();
 
         // Declare the input value.
@@ -1340,14 +1458,6 @@ finalize compute:
             .unwrap();
         assert_eq!(authorization.len(), 1);
 
-        // Compute the output value.
-        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-        let candidate = response.outputs();
-        assert_eq!(0, candidate.len());
-
-        // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-        assert_eq!(authorization.len(), 1);
-
         // Execute the request.
         let (response, execution, _inclusion, _metrics) =
             process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
@@ -1360,49 +1470,48 @@ finalize compute:
         // Now, finalize the execution.
         process.finalize_execution(&store, &execution).unwrap();
 
-        // Check that the account balance is now 0.
-        let candidate =
-            store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
-        assert_eq!(candidate, Value::from_str("0u64").unwrap());
+        // Check that `get.or_init` read the initialized 0, and `get` read back the 8 that was `set`.
+        let key = Plaintext::from(Literal::Address(caller));
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("8u64").unwrap());
+
+        // Set the account balance directly in storage.
+        store.update_key_value(program_id, &mapping_name, key.clone(), Value::from_str("10u64").unwrap()).unwrap();
+
+        // Finalize the execution again.
+        process.finalize_execution(&store, &execution).unwrap();
+
+        // Check that `get.or_init` read the existing 10, and `get` read back the 8 that was `set`.
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("18u64").unwrap());
     }
 
     #[test]
-    fn test_process_execute_mint_public() {
+    fn test_process_execute_and_finalize_with_instructions() {
         // Initialize a new program.
         let (string, program) = Program::<CurrentNetwork>::parse(
             r"
-program token.aleo;
+program testing.aleo;
 
-// On-chain storage of an `account` map, with `owner` as the key,
-// and `amount` as the value.
 mapping account:
-    // The token owner.
     key owner as address.public;
-    // The token amount.
     value amount as u64.public;
 
-// The function `mint_public` issues the specified token amount
-// for the token receiver publicly on the network.
-function mint_public:
-    // Input the token receiver.
+function compute:
     input r0 as address.public;
-    // Input the token amount.
     input r1 as u64.public;
-    // Mint the tokens publicly.
-    finalize r0 r1;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
 
-// The finalize scope of `mint_public` increments the
-// `account` of the token receiver by the specified amount.
-finalize mint_public:
-    // Input the token receiver.
+finalize compute:
     input r0 as address.public;
-    // Input the token amount.
     input r1 as u64.public;
-
-    // Increments `account[r0]` by `r1`.
-    // If `account[r0]` does not exist, it will be created.
-    // If `account[r0] + r1` overflows, `mint_public` is reverted.
-    increment account[r0] by r1;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    lt r3 10u64 into r4;
+    ternary r4 r3 10u64 into r5;
+    set r5 into account[r0];
 ",
         )
         .unwrap();
@@ -1413,7 +1522,7 @@ finalize mint_public:
         // Declare the mapping.
         let mapping_name = Identifier::from_str("account").unwrap();
         // Declare the function name.
-        let function_name = Identifier::from_str("mint_public").unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
 
         // Initialize the RNG.
         let rng = &mut TestRng::default();
@@ -1436,34 +1545,21 @@ finalize mint_public:
         // Finalize the deployment.
         process.finalize_deployment(&store, &deployment).unwrap();
 
-        // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
-        //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
-        //  the correctness of the synthesizer.
-        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
-
         // Initialize a new caller account.
         let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-        let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
         let caller = Address::try_from(&caller_private_key).unwrap();
 
         // Declare the input value.
         let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
         let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
 
         // Authorize the function call.
         let authorization = process
-            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
             .unwrap();
         assert_eq!(authorization.len(), 1);
 
-        // Compute the output value.
-        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-        let candidate = response.outputs();
-        assert_eq!(0, candidate.len());
-
-        // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-        assert_eq!(authorization.len(), 1);
-
         // Execute the request.
         let (response, execution, _inclusion, _metrics) =
             process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
@@ -1476,137 +1572,732 @@ finalize mint_public:
         // Now, finalize the execution.
         process.finalize_execution(&store, &execution).unwrap();
 
-        // Check the account balance.
-        let candidate =
-            store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
-        assert_eq!(candidate, Value::from_str("3u64").unwrap());
+        // Check that the account balance is now 8.
+        let key = Plaintext::from(Literal::Address(caller));
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("8u64").unwrap());
+
+        // Finalize the execution again.
+        process.finalize_execution(&store, &execution).unwrap();
+
+        // Check that the account balance is now capped at 10.
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("10u64").unwrap());
     }
 
     #[test]
-    fn test_process_execute_call_mint_public() {
+    fn test_process_finalize_with_forbidden_instructions() {
+        // Initialize a program that uses the caller in finalize.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as address.public;
+    is.eq r0 self.caller into r1;
+",
+        )
+        .unwrap();
+
+        // Ensure the program cannot be added to the process.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_outputs() {
         // Initialize a new program.
-        let (string, program0) = Program::<CurrentNetwork>::parse(
+        let (string, program) = Program::<CurrentNetwork>::parse(
             r"
-program token.aleo;
+program testing.aleo;
 
-// On-chain storage of an `account` map, with `owner` as the key,
-// and `amount` as the value.
 mapping account:
-    // The token owner.
     key owner as address.public;
-    // The token amount.
     value amount as u64.public;
 
-// The function `mint_public` issues the specified token amount
-// for the token receiver publicly on the network.
-function mint_public:
-    // Input the token receiver.
+function compute:
     input r0 as address.public;
-    // Input the token amount.
     input r1 as u64.public;
-    // Mint the tokens publicly.
     finalize r0 r1;
 
-// The finalize scope of `mint_public` increments the
-// `account` of the token receiver by the specified amount.
-finalize mint_public:
-    // Input the token receiver.
+finalize compute:
     input r0 as address.public;
-    // Input the token amount.
     input r1 as u64.public;
-
-    // Increments `account[r0]` by `r1`.
-    // If `account[r0]` does not exist, it will be created.
-    // If `account[r0] + r1` overflows, `mint_public` is reverted.
-    increment account[r0] by r1;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    output r3 as u64.public;
+    output r0 as address.public;
 ",
         )
         .unwrap();
         assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
-        // Declare the mapping.
-        let mapping_name = Identifier::from_str("account").unwrap();
         // Declare the function name.
-        let function_name = Identifier::from_str("mint_public").unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
 
         // Initialize the RNG.
         let rng = &mut TestRng::default();
 
         // Construct the process.
-        let process = super::test_helpers::sample_process(&program0);
-        // Check that the circuit key can be synthesized.
-        process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
-
-        // Reset the process.
         let mut process = Process::load().unwrap();
 
         // Initialize a new program store.
         let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
 
         // Add the program to the process.
-        let deployment = process.deploy::<CurrentAleo, _>(&program0, rng).unwrap();
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
         // Check that the deployment verifies.
         process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
         // Finalize the deployment.
         process.finalize_deployment(&store, &deployment).unwrap();
 
-        // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
-        //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
-        //  the correctness of the synthesizer.
-        process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
-
-        // Initialize another program.
-        let (string, program1) = Program::<CurrentNetwork>::parse(
-            r"
-import token.aleo;
-
-program public_wallet.aleo;
-
-function mint:
-    input r0 as address.public;
-    input r1 as u64.public;
-    call token.aleo/mint_public r0 r1;",
-        )
-        .unwrap();
-        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
-
-        // Add the program to the process.
-        process.add_program(&program1).unwrap();
-
-        // Initialize the RNG.
-        let rng = &mut TestRng::default();
-
-        // Initialize caller.
+        // Initialize a new caller account.
         let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
         let caller = Address::try_from(&caller_private_key).unwrap();
 
-        // Declare the function name.
-        let function_name = Identifier::from_str("mint").unwrap();
-
         // Declare the input value.
         let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
-        let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
 
         // Authorize the function call.
         let authorization = process
-            .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0.clone(), r1].iter(), rng)
             .unwrap();
-        assert_eq!(authorization.len(), 2);
-
-        // Compute the output value.
-        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
-        let candidate = response.outputs();
-        assert_eq!(0, candidate.len());
-
-        // Check again to make sure we didn't modify the authorization after calling `evaluate`.
-        assert_eq!(authorization.len(), 2);
+        assert_eq!(authorization.len(), 1);
 
         // Execute the request.
-        let (response, execution, _inclusion, _metrics) =
+        let (_response, execution, _inclusion, _metrics) =
             process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
-        let candidate = response.outputs();
-        assert_eq!(0, candidate.len());
-
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Ensure the finalize outputs are not in storage yet.
+        let transition_id = *execution.peek().unwrap().id();
+        assert!(store.get_finalize_outputs(&transition_id).unwrap().is_none());
+
+        // Now, finalize the execution.
+        let finalize_outputs = process.finalize_execution(&store, &execution).unwrap();
+
+        // Ensure the finalize outputs are returned, and saved in storage.
+        let expected = vec![Value::from_str("3u64").unwrap(), r0];
+        assert_eq!(finalize_outputs.len(), 1);
+        assert_eq!(finalize_outputs.get(&transition_id), Some(&expected));
+        assert_eq!(store.get_finalize_outputs(&transition_id).unwrap(), Some(expected));
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_with_branches() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    branch.neq r1 0u64 to deposit;
+    set 0u64 into account[r0];
+    branch.eq true true to exit;
+    position deposit;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+    position exit;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+        // Declare the mapping key.
+        let key = Plaintext::from(Literal::Address(caller));
+
+        // Executes and finalizes `compute` with the given amount.
+        let mut execute_and_finalize = |amount: &str| {
+            // Declare the input value.
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str(amount).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+
+            // Execute the request.
+            let (_response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+            // Finalize the execution.
+            process.finalize_execution(&store, &execution).unwrap();
+        };
+
+        // Deposit twice, and check that the account balance is now 10.
+        execute_and_finalize("5u64");
+        execute_and_finalize("5u64");
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("10u64").unwrap());
+
+        // Reset the account, and check that the account balance is now 0.
+        execute_and_finalize("0u64");
+        let candidate = store.get_value(program_id, &mapping_name, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("0u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_finalize_execution_with_mappings() {
+        // Initialize a new program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping deposits:
+    key owner as address.public;
+    value amount as u64.public;
+
+mapping resets:
+    key owner as address.public;
+    value count as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to reset;
+    increment deposits[r0] by r1;
+    branch.eq true true to exit;
+    position reset;
+    increment resets[r0] by 1u64;
+    position exit;
+",
+        )
+        .unwrap();
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Executes and finalizes `compute` with the given amount, and checks the touched mapping.
+        let mut execute_and_finalize = |amount: &str, expected_mapping: &str| {
+            // Declare the input value.
+            let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+            let r1 = Value::<CurrentNetwork>::from_str(amount).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+
+            // Execute the request.
+            let (_response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+            // Finalize the execution.
+            let (_, mapping_transitions) = process.finalize_execution_with_mappings(&store, &execution).unwrap();
+
+            // Ensure only the mapping of the evaluated branch is touched.
+            let transition = execution.peek().unwrap();
+            let expected_mapping = Identifier::from_str(expected_mapping).unwrap();
+            assert_eq!(mapping_transitions, vec![(*program.id(), expected_mapping, *transition.id())]);
+        };
+
+        execute_and_finalize("5u64", "deposits");
+        execute_and_finalize("0u64", "resets");
+    }
+
+    #[test]
+    fn test_process_finalize_with_undefined_position() {
+        // Initialize a program that branches to an undefined position in finalize.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to exit;
+",
+        )
+        .unwrap();
+
+        // Ensure the program cannot be added to the process.
+        let mut process = Process::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+    }
+
+    #[test]
+    fn test_process_finalize_with_unassigned_register() {
+        // Initialize a program that reads a register which is only assigned when the branch is not taken.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to exit;
+    add r1 r1 into r2;
+    position exit;
+    set r2 into account[r0];
+",
+        )
+        .unwrap();
+
+        // Ensure the program cannot be added to the process.
+        let mut process = Process::load().unwrap();
+        let error = process.add_program(&program).unwrap_err();
+        assert!(error.to_string().contains("is not assigned on every path"), "{error}");
+
+        // Initialize a program that assigns the register on both paths.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    branch.eq r1 0u64 to exit;
+    add r1 r2 into r3;
+    set r3 into account[r0];
+    position exit;
+    set r2 into account[r0];
+",
+        )
+        .unwrap();
+
+        // Ensure the program can be added to the process.
+        process.add_program(&program).unwrap();
+    }
+
+    #[test]
+    fn test_process_execute_and_finalize_increment_decrement() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment account[r0] by r1;
+    decrement account[r0] by r1;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        // Check that the circuit key can be synthesized.
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+        // Reset the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("5u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        process.finalize_execution(&store, &execution).unwrap();
+
+        // Check that the account balance is now 0.
+        let candidate =
+            store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("0u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_mint_public() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program token.aleo;
+
+// On-chain storage of an `account` map, with `owner` as the key,
+// and `amount` as the value.
+mapping account:
+    // The token owner.
+    key owner as address.public;
+    // The token amount.
+    value amount as u64.public;
+
+// The function `mint_public` issues the specified token amount
+// for the token receiver publicly on the network.
+function mint_public:
+    // Input the token receiver.
+    input r0 as address.public;
+    // Input the token amount.
+    input r1 as u64.public;
+    // Mint the tokens publicly.
+    finalize r0 r1;
+
+// The finalize scope of `mint_public` increments the
+// `account` of the token receiver by the specified amount.
+finalize mint_public:
+    // Input the token receiver.
+    input r0 as address.public;
+    // Input the token amount.
+    input r1 as u64.public;
+
+    // Increments `account[r0]` by `r1`.
+    // If `account[r0]` does not exist, it will be created.
+    // If `account[r0] + r1` overflows, `mint_public` is reverted.
+    increment account[r0] by r1;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("mint_public").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program);
+        // Check that the circuit key can be synthesized.
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+        // Reset the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
+        //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
+        //  the correctness of the synthesizer.
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let _caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+        assert_eq!(authorization.len(), 1);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        process.finalize_execution(&store, &execution).unwrap();
+
+        // Check the account balance.
+        let candidate =
+            store.get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("3u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_execute_call_mint_public() {
+        // Initialize a new program.
+        let (string, program0) = Program::<CurrentNetwork>::parse(
+            r"
+program token.aleo;
+
+// On-chain storage of an `account` map, with `owner` as the key,
+// and `amount` as the value.
+mapping account:
+    // The token owner.
+    key owner as address.public;
+    // The token amount.
+    value amount as u64.public;
+
+// The function `mint_public` issues the specified token amount
+// for the token receiver publicly on the network.
+function mint_public:
+    // Input the token receiver.
+    input r0 as address.public;
+    // Input the token amount.
+    input r1 as u64.public;
+    // Mint the tokens publicly.
+    finalize r0 r1;
+
+// The finalize scope of `mint_public` increments the
+// `account` of the token receiver by the specified amount.
+finalize mint_public:
+    // Input the token receiver.
+    input r0 as address.public;
+    // Input the token amount.
+    input r1 as u64.public;
+
+    // Increments `account[r0]` by `r1`.
+    // If `account[r0]` does not exist, it will be created.
+    // If `account[r0] + r1` overflows, `mint_public` is reverted.
+    increment account[r0] by r1;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("mint_public").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let process = super::test_helpers::sample_process(&program0);
+        // Check that the circuit key can be synthesized.
+        process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
+
+        // Reset the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program0, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // TODO (howardwu): Remove this. I call this to synthesize the proving key independent of the assignment from 'execute'.
+        //  In general, we should update all tests to utilize a presynthesized proving key, before execution, to test
+        //  the correctness of the synthesizer.
+        process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
+
+        // Initialize another program.
+        let (string, program1) = Program::<CurrentNetwork>::parse(
+            r"
+import token.aleo;
+
+program public_wallet.aleo;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    call token.aleo/mint_public r0 r1;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Add the program to the process.
+        process.add_program(&program1).unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Initialize caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("mint").unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("100u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program1.id(), function_name, [r0, r1].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 2);
+
+        // Compute the output value.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
+        // Check again to make sure we didn't modify the authorization after calling `evaluate`.
+        assert_eq!(authorization.len(), 2);
+
+        // Execute the request.
+        let (response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(0, candidate.len());
+
         // Verify the execution.
         process.verify_execution::<true>(&execution).unwrap();
 
@@ -1618,4 +2309,226 @@ function mint:
             store.get_value(program0.id(), &mapping_name, &Plaintext::from(Literal::Address(caller))).unwrap().unwrap();
         assert_eq!(candidate, Value::from_str("100u64").unwrap());
     }
+
+    #[test]
+    fn test_process_execute_and_finalize_multiple_imports() {
+        // Initialize the imported programs.
+        let program_a = Program::<CurrentNetwork>::from_str(
+            r"
+program counter_a.aleo;
+
+mapping count:
+    key owner as address.public;
+    value amount as u64.public;
+
+function bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment count[r0] by r1;
+    output r1 as u64.public;
+",
+        )
+        .unwrap();
+        let program_b = Program::<CurrentNetwork>::from_str(
+            r"
+program counter_b.aleo;
+
+mapping count:
+    key owner as address.public;
+    value amount as u64.public;
+
+function bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize bump:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init count[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into count[r0];
+    output r3 as u64.public;
+",
+        )
+        .unwrap();
+
+        // Initialize the main program, which calls both imported programs.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import counter_a.aleo;
+import counter_b.aleo;
+
+program dispatch.aleo;
+
+mapping total:
+    key owner as address.public;
+    value amount as u64.public;
+
+function run:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    call counter_a.aleo/bump r0 r1;
+    call counter_b.aleo/bump r0 r2;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize run:
+    input r0 as address.public;
+    input r1 as u64.public;
+    increment total[r0] by r1;
+",
+        )
+        .unwrap();
+
+        // Declare the mapping names.
+        let count = Identifier::from_str("count").unwrap();
+        let total = Identifier::from_str("total").unwrap();
+        // Declare the function names.
+        let bump = Identifier::from_str("bump").unwrap();
+        let run = Identifier::from_str("run").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Deploy each program, in import order.
+        for program in [&program_a, &program_b, &program] {
+            // Add the program to the process.
+            let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+            // Check that the deployment verifies.
+            process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+            // Finalize the deployment.
+            process.finalize_deployment(&store, &deployment).unwrap();
+        }
+
+        // Ensure the call graph visits the imported functions before the main function.
+        let call_graph = process.get_stack(program.id()).unwrap().get_call_graph(&run).unwrap();
+        assert_eq!(call_graph, vec![(*program_a.id(), bump), (*program_b.id(), bump), (*program.id(), run)]);
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input value.
+        let r0 = Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap();
+        let r1 = Value::<CurrentNetwork>::from_str("3u64").unwrap();
+        let r2 = Value::<CurrentNetwork>::from_str("4u64").unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), run, [r0, r1, r2].iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 3);
+
+        // Execute the request.
+        let (_response, execution, _inclusion, _metrics) =
+            process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(execution.len(), 3);
+
+        // Verify the execution.
+        process.verify_execution::<true>(&execution).unwrap();
+
+        // Now, finalize the execution.
+        let finalize_outputs = process.finalize_execution(&store, &execution).unwrap();
+
+        // Ensure every transition was finalized, in the order of the call graph.
+        let transition_ids = execution.transitions().map(|transition| *transition.id()).collect::<Vec<_>>();
+        assert_eq!(finalize_outputs.keys().copied().collect::<Vec<_>>(), transition_ids);
+        assert_eq!(finalize_outputs[&transition_ids[0]], vec![Value::from_str("3u64").unwrap()]);
+        assert_eq!(finalize_outputs[&transition_ids[1]], vec![Value::from_str("4u64").unwrap()]);
+        assert!(finalize_outputs[&transition_ids[2]].is_empty());
+
+        // Check the mappings of each program.
+        let key = Plaintext::from(Literal::Address(caller));
+        let candidate = store.get_value(program_a.id(), &count, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("3u64").unwrap());
+        let candidate = store.get_value(program_b.id(), &count, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("4u64").unwrap());
+        let candidate = store.get_value(program.id(), &total, &key).unwrap().unwrap();
+        assert_eq!(candidate, Value::from_str("7u64").unwrap());
+    }
+
+    #[test]
+    fn test_process_call_graph_with_nested_imports() {
+        // Initialize a program with a finalize scope.
+        let program_c = Program::<CurrentNetwork>::from_str(
+            r"
+program leaf.aleo;
+
+mapping count:
+    key owner as address.public;
+    value amount as u64.public;
+
+function bump:
+    input r0 as address.public;
+    finalize r0;
+
+finalize bump:
+    input r0 as address.public;
+    increment count[r0] by 1u64;
+",
+        )
+        .unwrap();
+        // Initialize a program that calls `leaf.aleo`.
+        let program_b = Program::<CurrentNetwork>::from_str(
+            r"
+import leaf.aleo;
+
+program middle.aleo;
+
+function relay:
+    input r0 as address.public;
+    call leaf.aleo/bump r0;
+",
+        )
+        .unwrap();
+        // Initialize a program that calls both `middle.aleo` and `leaf.aleo`.
+        let program_a = Program::<CurrentNetwork>::from_str(
+            r"
+import leaf.aleo;
+import middle.aleo;
+
+program root.aleo;
+
+function run:
+    input r0 as address.public;
+    call middle.aleo/relay r0;
+    call leaf.aleo/bump r0;
+",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+        for program in [&program_c, &program_b, &program_a] {
+            process.add_program(program).unwrap();
+        }
+
+        // Declare the function names.
+        let bump = Identifier::from_str("bump").unwrap();
+        let relay = Identifier::from_str("relay").unwrap();
+        let run = Identifier::from_str("run").unwrap();
+
+        // Ensure the call graph lists every call, with each function after its own calls.
+        let stack = process.get_stack(program_a.id()).unwrap();
+        let call_graph = stack.get_call_graph(&run).unwrap();
+        assert_eq!(
+            call_graph,
+            vec![(*program_c.id(), bump), (*program_b.id(), relay), (*program_c.id(), bump), (*program_a.id(), run)]
+        );
+        // Ensure the call graph is consistent with the number of calls.
+        assert_eq!(call_graph.len(), stack.get_number_of_calls(&run).unwrap());
+    }
 }
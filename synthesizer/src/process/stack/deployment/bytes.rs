@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Deployment<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid deployment version"));
         }
 
@@ -46,8 +46,14 @@ impl<N: Network> FromBytes for Deployment<N> {
             verifying_keys.push((identifier, (verifying_key, certificate)));
         }
 
+        // Read the upgrade signature, if the deployment is an upgrade.
+        let signature = match version {
+            1 => Some(Signature::read_le(&mut reader)?),
+            _ => None,
+        };
+
         // Return the deployment.
-        Self::new(edition, program, verifying_keys).map_err(|err| error(format!("{err}")))
+        Self::from(edition, program, verifying_keys, signature).map_err(|err| error(format!("{err}")))
     }
 }
 
@@ -55,7 +61,11 @@ impl<N: Network> ToBytes for Deployment<N> {
     /// Writes the deployment to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        // Note: Version 1 is only used for upgrades, to preserve the encoding of all other deployments.
+        match self.signature {
+            Some(_) => 1u16.write_le(&mut writer)?,
+            None => 0u16.write_le(&mut writer)?,
+        }
         // Write the edition.
         self.edition.write_le(&mut writer)?;
         // Write the program.
@@ -71,6 +81,10 @@ impl<N: Network> ToBytes for Deployment<N> {
             // Write the certificate.
             certificate.write_le(&mut writer)?;
         }
+        // Write the upgrade signature, if the deployment is an upgrade.
+        if let Some(signature) = &self.signature {
+            signature.write_le(&mut writer)?;
+        }
         Ok(())
     }
 }
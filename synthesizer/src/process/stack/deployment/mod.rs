@@ -20,8 +20,10 @@ mod string;
 
 use crate::{Certificate, Program, VerifyingKey};
 use console::{
+    account::{Address, PrivateKey, Signature},
     network::prelude::*,
     program::{Identifier, ProgramID},
+    types::Field,
 };
 
 #[derive(Clone, PartialEq, Eq)]
@@ -32,6 +34,8 @@ pub struct Deployment<N: Network> {
     program: Program<N>,
     /// The mapping of function names to their verifying key and certificate.
     verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
+    /// The signature of the upgrade authority, if the deployment upgrades an existing program.
+    signature: Option<Signature<N>>,
 }
 
 impl<N: Network> Deployment<N> {
@@ -40,9 +44,36 @@ impl<N: Network> Deployment<N> {
         edition: u16,
         program: Program<N>,
         verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
+    ) -> Result<Self> {
+        Self::from(edition, program, verifying_keys, None)
+    }
+
+    /// Initializes a new deployment that upgrades an existing program to the given edition,
+    /// signed by the upgrade authority of the existing program.
+    pub fn upgrade<R: Rng + CryptoRng>(
+        edition: u16,
+        program: Program<N>,
+        verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Compute the checksum of the deployment.
+        let checksum = Self::compute_checksum(edition, &program, &verifying_keys)?;
+        // Sign the checksum with the upgrade authority.
+        let signature = Signature::sign(private_key, &[checksum], rng)?;
+        // Construct the deployment.
+        Self::from(edition, program, verifying_keys, Some(signature))
+    }
+
+    /// Initializes a deployment from its components, including the upgrade signature (if any).
+    pub fn from(
+        edition: u16,
+        program: Program<N>,
+        verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
+        signature: Option<Signature<N>>,
     ) -> Result<Self> {
         // Construct the deployment.
-        let deployment = Self { edition, program, verifying_keys };
+        let deployment = Self { edition, program, verifying_keys, signature };
         // Ensure the deployment is ordered.
         deployment.check_is_ordered()?;
         // Return the deployment.
@@ -53,13 +84,23 @@ impl<N: Network> Deployment<N> {
     pub fn check_is_ordered(&self) -> Result<()> {
         let program_id = self.program.id();
 
-        // Ensure the edition matches.
+        // Ensure the edition is valid.
         ensure!(
-            self.edition == N::EDITION,
-            "Deployed the wrong edition (expected '{}', found '{}').",
+            self.edition >= N::EDITION,
+            "Deployed the wrong edition (expected at least '{}', found '{}').",
             N::EDITION,
             self.edition
         );
+        // Ensure only an upgrade is signed, and that the upgrade signature is valid.
+        match (self.is_upgrade(), &self.signature) {
+            (false, None) => (),
+            (false, Some(_)) => bail!("The initial deployment of '{program_id}' must not contain an upgrade signature"),
+            (true, None) => bail!("The upgrade of '{program_id}' is missing the upgrade signature"),
+            (true, Some(signature)) => ensure!(
+                signature.verify(&signature.to_address(), &[self.to_checksum()?]),
+                "The upgrade signature for '{program_id}' is invalid"
+            ),
+        }
         // Ensure the program network-level domain (NLD) is correct.
         ensure!(program_id.is_aleo(), "Program '{program_id}' has an incorrect network-level domain (NLD)");
         // Ensure the program contains functions.
@@ -117,6 +158,45 @@ impl<N: Network> Deployment<N> {
     pub const fn verifying_keys(&self) -> &Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))> {
         &self.verifying_keys
     }
+
+    /// Returns the upgrade signature, if the deployment upgrades an existing program.
+    pub const fn signature(&self) -> Option<&Signature<N>> {
+        self.signature.as_ref()
+    }
+
+    /// Returns `true` if the deployment upgrades an existing program.
+    pub const fn is_upgrade(&self) -> bool {
+        self.edition > N::EDITION
+    }
+
+    /// Returns the address of the upgrade authority that signed the deployment, if it is an upgrade.
+    pub fn upgrade_authority(&self) -> Option<Address<N>> {
+        self.signature.as_ref().map(|signature| signature.to_address())
+    }
+
+    /// Returns the checksum of the deployment, which is signed by the upgrade authority.
+    pub fn to_checksum(&self) -> Result<Field<N>> {
+        Self::compute_checksum(self.edition, &self.program, &self.verifying_keys)
+    }
+
+    /// Returns the checksum as `Hash( edition || program || verifying keys )`.
+    fn compute_checksum(
+        edition: u16,
+        program: &Program<N>,
+        verifying_keys: &[(Identifier<N>, (VerifyingKey<N>, Certificate<N>))],
+    ) -> Result<Field<N>> {
+        // Write the edition and program.
+        let mut preimage = edition.to_bytes_le()?;
+        program.write_le(&mut preimage)?;
+        // Write the verifying keys and certificates.
+        for (function_name, (verifying_key, certificate)) in verifying_keys {
+            function_name.write_le(&mut preimage)?;
+            verifying_key.write_le(&mut preimage)?;
+            certificate.write_le(&mut preimage)?;
+        }
+        // Compute the checksum.
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
 }
 
 #[cfg(test)]
@@ -23,10 +23,14 @@ impl<N: Network> Serialize for Deployment<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut deployment = serializer.serialize_struct("Deployment", 3)?;
+                let num_fields = 3 + self.signature.is_some() as usize;
+                let mut deployment = serializer.serialize_struct("Deployment", num_fields)?;
                 deployment.serialize_field("edition", &self.edition)?;
                 deployment.serialize_field("program", &self.program)?;
                 deployment.serialize_field("verifying_keys", &self.verifying_keys)?;
+                if let Some(signature) = &self.signature {
+                    deployment.serialize_field("signature", signature)?;
+                }
                 deployment.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -43,13 +47,18 @@ impl<'de, N: Network> Deserialize<'de> for Deployment<N> {
                 let mut deployment = serde_json::Value::deserialize(deserializer)?;
 
                 // Recover the deployment.
-                let deployment = Self::new(
+                let deployment = Self::from(
                     // Retrieve the edition.
                     DeserializeExt::take_from_value::<D>(&mut deployment, "edition")?,
                     // Retrieve the program.
                     DeserializeExt::take_from_value::<D>(&mut deployment, "program")?,
                     // Retrieve the verifying keys.
                     DeserializeExt::take_from_value::<D>(&mut deployment, "verifying_keys")?,
+                    // Retrieve the upgrade signature, if it exists.
+                    serde_json::from_value(
+                        deployment.get_mut("signature").unwrap_or(&mut serde_json::Value::Null).take(),
+                    )
+                    .map_err(de::Error::custom)?,
                 )
                 .map_err(de::Error::custom)?;
 
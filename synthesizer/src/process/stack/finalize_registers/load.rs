@@ -16,43 +16,14 @@
 
 use super::*;
 
-impl<N: Network> FinalizeRegisters<N> {
-    /// Loads the literal of a given operand from the registers.
-    ///
-    /// # Errors
-    /// This method will halt if the given operand is not a literal.
-    /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
-    #[inline]
-    pub fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
-            Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
-            Value::Record(..) => bail!("Operand must be a literal"),
-        }
-    }
-
-    /// Loads the plaintext of a given operand from the registers.
-    ///
-    /// # Errors
-    /// This method will halt if the given operand is not a plaintext.
-    /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
-    #[inline]
-    pub fn load_plaintext(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Plaintext<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(plaintext) => Ok(plaintext),
-            Value::Record(..) => bail!("Operand must be a plaintext"),
-        }
-    }
-
+impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
     /// Loads the value of a given operand from the registers.
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
-    pub fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
+    fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
         // Retrieve the register.
         let register = match operand {
             // If the operand is a literal, return the literal.
@@ -76,12 +47,15 @@ impl<N: Network> FinalizeRegisters<N> {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => stack_value.clone(),
             // If the register is a register member, then load the specific stack value.
-            Register::Member(_, ref path) => {
+            Register::Member(..) | Register::Access(..) => {
+                // Retrieve the access path.
+                let path = register.accesses();
+
                 match stack_value {
                     // Retrieve the plaintext member from the path.
-                    Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
+                    Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(&path)?),
                     // Retrieve the record entry from the path.
-                    Value::Record(record) => match record.find(path)? {
+                    Value::Record(record) => match record.find(&path)? {
                         Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
                             Value::Plaintext(plaintext)
                         }
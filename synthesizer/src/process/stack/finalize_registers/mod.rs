@@ -17,10 +17,11 @@
 mod load;
 mod store;
 
-use crate::{FinalizeTypes, Operand, Stack};
+use crate::{Call, FinalizeTypes, Operand, RegistersCall, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
+    types::{Address, Field},
 };
 
 use indexmap::IndexMap;
@@ -40,3 +41,25 @@ impl<N: Network> FinalizeRegisters<N> {
         Self { finalize_types, registers: IndexMap::new() }
     }
 }
+
+impl<N: Network> RegistersCaller<N> for FinalizeRegisters<N> {
+    /// Returns the transition caller.
+    #[inline]
+    fn caller(&self) -> Result<Address<N>> {
+        bail!("Forbidden operation: Cannot use the caller in 'finalize'")
+    }
+
+    /// Returns the transition view key.
+    #[inline]
+    fn tvk(&self) -> Result<Field<N>> {
+        bail!("Forbidden operation: Cannot use the transition view key in 'finalize'")
+    }
+}
+
+impl<N: Network> RegistersCall<N> for FinalizeRegisters<N> {
+    /// Evaluates the given `call` instruction, and assigns its outputs to the registers.
+    #[inline]
+    fn evaluate_call(&mut self, _stack: &Stack<N>, _call: &Call<N>) -> Result<()> {
+        bail!("Forbidden operation: Cannot invoke a 'call' in 'finalize'")
+    }
+}
@@ -16,18 +16,7 @@
 
 use super::*;
 
-impl<N: Network> FinalizeRegisters<N> {
-    /// Assigns the given literal to the given register, assuming the register is not already assigned.
-    ///
-    /// # Errors
-    /// This method will halt if the given register is a register member.
-    /// This method will halt if the given register is an input register.
-    /// This method will halt if the register is already used.
-    #[inline]
-    pub fn store_literal(&mut self, stack: &Stack<N>, register: &Register<N>, literal: Literal<N>) -> Result<()> {
-        self.store(stack, register, Value::Plaintext(Plaintext::from(literal)))
-    }
-
+impl<N: Network> RegistersStore<N> for FinalizeRegisters<N> {
     /// Assigns the given value to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
@@ -35,12 +24,14 @@ impl<N: Network> FinalizeRegisters<N> {
     /// This method will halt if the given register is an input register.
     /// This method will halt if the register is already used.
     #[inline]
-    pub fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()> {
+    fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()> {
         match register {
             Register::Locator(locator) => {
                 // Ensure the register assignments are monotonically increasing.
-                let expected_locator = self.registers.len() as u64;
-                ensure!(expected_locator == *locator, "Out-of-order write operation at '{register}'");
+                // Note: Registers may be skipped, if their commands were jumped over by a branch.
+                if let Some((last_locator, _)) = self.registers.last() {
+                    ensure!(*locator > *last_locator, "Out-of-order write operation at '{register}'");
+                }
                 // Ensure the register does not already exist.
                 ensure!(!self.registers.contains_key(locator), "Cannot write to occupied register '{register}'");
 
@@ -61,7 +52,7 @@ impl<N: Network> FinalizeRegisters<N> {
                 }
             }
             // Ensure the register is not a register member.
-            Register::Member(..) => bail!("Cannot store to a register member: '{register}'"),
+            Register::Member(..) | Register::Access(..) => bail!("Cannot store to a register member: '{register}'"),
         }
     }
 }
@@ -31,9 +31,9 @@ impl<N: Network> FinalizeTypes<N> {
         }
 
         // Step 2. Check the commands are well-formed.
-        for command in finalize.commands() {
+        for (index, command) in finalize.commands().iter().enumerate() {
             // Check the command opcode, operands, and destinations.
-            finalize_types.check_command(stack, finalize.name(), command)?;
+            finalize_types.check_command(stack, finalize, index, command)?;
         }
 
         // Step 3. Check the outputs are well-formed.
@@ -42,6 +42,9 @@ impl<N: Network> FinalizeTypes<N> {
             finalize_types.check_output(stack, output.operand(), &RegisterType::from(*output.finalize_type()))?;
         }
 
+        // Step 4. Check every register is assigned on every path to the commands and outputs that read it.
+        finalize_types.check_definite_assignment(stack, finalize)?;
+
         Ok(finalize_types)
     }
 }
@@ -68,7 +71,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Member(..) | Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -91,7 +94,7 @@ impl<N: Network> FinalizeTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Member(..) | Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -109,6 +112,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -158,6 +169,14 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -185,12 +204,128 @@ impl<N: Network> FinalizeTypes<N> {
 
     /// Ensures the given command is well-formed.
     #[inline]
-    fn check_command(&mut self, stack: &Stack<N>, finalize_name: &Identifier<N>, command: &Command<N>) -> Result<()> {
+    fn check_command(
+        &mut self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        index: usize,
+        command: &Command<N>,
+    ) -> Result<()> {
+        // Retrieve the finalize name.
+        let finalize_name = finalize.name();
         match command {
             Command::Decrement(decrement) => self.check_decrement(stack, finalize_name, decrement)?,
+            Command::Get(get) => self.check_get(stack, finalize_name, get)?,
+            Command::GetOrInit(get_or_init) => self.check_get_or_init(stack, finalize_name, get_or_init)?,
             Command::Instruction(instruction) => self.check_instruction(stack, finalize_name, instruction)?,
             Command::Increment(increment) => self.check_increment(stack, finalize_name, increment)?,
+            Command::Set(set) => self.check_set(stack, finalize_name, set)?,
+            Command::BranchEq(branch_eq) => self.check_branch(stack, finalize, index, branch_eq)?,
+            Command::BranchNeq(branch_neq) => self.check_branch(stack, finalize, index, branch_neq)?,
+            // Note that the position name is checked for uniqueness when it is added to finalize.
+            Command::Position(_) => (),
+        }
+        Ok(())
+    }
+
+    /// Ensures every register that is read by a command or output is assigned on every path to it.
+    /// Note: As branches only jump forward, the commands are visited in order, and the registers
+    /// assigned at each command are the intersection of the registers assigned on each path to it.
+    fn check_definite_assignment(&self, stack: &Stack<N>, finalize: &Finalize<N>) -> Result<()> {
+        // Ensures the registers read by the given operands are in the given set of assigned registers.
+        let check_operands = |operands: &[Operand<N>], assigned: &IndexSet<u64>| {
+            for operand in operands {
+                if let Operand::Register(register) = operand {
+                    ensure!(
+                        assigned.contains(&register.locator()),
+                        "Register '{register}' in '{}/{}' is not assigned on every path that reads it.",
+                        stack.program_id(),
+                        finalize.name()
+                    );
+                }
+            }
+            Ok(())
+        };
+        // Intersects the registers assigned on a path to the given index with the given registers.
+        let merge = |paths: &mut [Option<IndexSet<u64>>], index: usize, assigned: &IndexSet<u64>| {
+            paths[index] = Some(match paths[index].take() {
+                Some(previous) => previous.intersection(assigned).copied().collect(),
+                None => assigned.clone(),
+            });
+        };
+
+        // Initialize the registers assigned on every path to each command, and to the outputs.
+        let commands = finalize.commands();
+        let mut paths = vec![None; commands.len() + 1];
+        paths[0] = Some(self.inputs.keys().copied().collect());
+
+        for (index, command) in commands.iter().enumerate() {
+            // Note: Every command can be reached from the previous command, as branches are conditional.
+            let mut assigned = paths[index].take().unwrap_or_default();
+            // Ensure the operands are assigned.
+            check_operands(&command.operands(), &assigned)?;
+            // If the command is a branch, merge the assigned registers into the branch target.
+            if let Some(position) = command.branch_to() {
+                match finalize.positions().get(position) {
+                    Some(target) => merge(&mut paths, *target, &assigned),
+                    None => {
+                        bail!("Position '{position}' in '{}/{}' is not defined.", stack.program_id(), finalize.name())
+                    }
+                }
+            }
+            // Add the destinations, and merge the assigned registers into the next command.
+            assigned.extend(command.destinations().iter().map(|register| register.locator()));
+            merge(&mut paths, index + 1, &assigned);
+        }
+
+        // Ensure the output operands are assigned.
+        let assigned = paths[commands.len()].take().unwrap_or_default();
+        let operands = finalize.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+        check_operands(&operands, &assigned)
+    }
+
+    /// Ensures the given branch command is well-formed.
+    #[inline]
+    fn check_branch<const VARIANT: u8>(
+        &self,
+        stack: &Stack<N>,
+        finalize: &Finalize<N>,
+        index: usize,
+        branch: &Branch<N, VARIANT>,
+    ) -> Result<()> {
+        // Ensure the position is defined in finalize.
+        let position = match finalize.positions().get(branch.position()) {
+            Some(position) => *position,
+            None => bail!(
+                "Position '{}' in '{}/{}' is not defined.",
+                branch.position(),
+                stack.program_id(),
+                finalize.name()
+            ),
+        };
+        // Ensure the branch only jumps forward, to prevent loops in finalize.
+        ensure!(
+            position > index,
+            "Forbidden operation: '{branch}' in '{}/{}' must jump forward.",
+            stack.program_id(),
+            finalize.name()
+        );
+
+        // Ensure the branch does not reference the caller, as it is not available in 'finalize'.
+        if branch.operands().iter().any(|operand| matches!(operand, Operand::Caller)) {
+            bail!("Forbidden operation: Cannot use 'self.caller' in '{}/{}'", stack.program_id(), finalize.name())
         }
+
+        // Retrieve the register types of the operands.
+        let first_type = self.get_type_from_operand(stack, branch.first())?;
+        let second_type = self.get_type_from_operand(stack, branch.second())?;
+        // Ensure the operand types match.
+        if first_type != second_type {
+            bail!(
+                "Command '{branch}' expects operands of the same type. Found operands of type '{first_type}' and '{second_type}'"
+            )
+        }
+
         Ok(())
     }
 
@@ -219,7 +354,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::String | LiteralType::Signature => {
                         bail!("Decrement cannot decrement by a(n) '{literal_type}' (found at '{decrement}')")
                     }
                     // These literal types are valid for the 'decrement' command.
@@ -241,6 +376,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Decrement cannot decrement by an 'struct' (found at '{decrement}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Decrement cannot decrement by an 'array' (found at '{decrement}')")
+            }
             RegisterType::Record(..) => bail!("Decrement cannot decrement by a 'record' (found at '{decrement}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Decrement cannot decrement by an 'external record' (found at '{decrement}')")
@@ -250,6 +388,84 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given get command is well-formed.
+    #[inline]
+    fn check_get(&mut self, stack: &Stack<N>, finalize_name: &Identifier<N>, get: &Get<N>) -> Result<()> {
+        // Ensure the declared mapping in get is defined in the program.
+        if !stack.program().contains_mapping(get.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", get.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(get.mapping_name()).unwrap();
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, get.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!("Key type in `get` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
+        }
+
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(matches!(get.destination(), Register::Locator(..)), "Destination '{get}' must be a locator.");
+        // Insert the destination register, with the mapping value type.
+        self.add_destination(get.destination().clone(), RegisterType::from(*mapping.value().finalize_type()))?;
+
+        Ok(())
+    }
+
+    /// Ensures the given get.or_init command is well-formed.
+    #[inline]
+    fn check_get_or_init(
+        &mut self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        get_or_init: &GetOrInit<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in get.or_init is defined in the program.
+        if !stack.program().contains_mapping(get_or_init.mapping_name()) {
+            bail!(
+                "Mapping '{}' in '{}/{finalize_name}' is not defined.",
+                get_or_init.mapping_name(),
+                stack.program_id()
+            )
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(get_or_init.mapping_name()).unwrap();
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, get_or_init.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!(
+                "Key type in `get.or_init` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+            )
+        }
+
+        // Retrieve the register type of the default value.
+        let default_type = self.get_type_from_operand(stack, get_or_init.default())?;
+        // Ensure the default value type matches the mapping value type.
+        let mapping_value_type = RegisterType::from(*mapping.value().finalize_type());
+        if default_type != mapping_value_type {
+            bail!(
+                "Default value type in `get.or_init` '{default_type}' does not match the value type in the mapping '{mapping_value_type}'."
+            )
+        }
+
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(
+            matches!(get_or_init.destination(), Register::Locator(..)),
+            "Destination '{get_or_init}' must be a locator."
+        );
+        // Insert the destination register, with the mapping value type.
+        self.add_destination(get_or_init.destination().clone(), mapping_value_type)?;
+
+        Ok(())
+    }
+
     /// Ensures the given increment command is well-formed.
     #[inline]
     fn check_increment(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, increment: &Increment<N>) -> Result<()> {
@@ -275,7 +491,7 @@ impl<N: Network> FinalizeTypes<N> {
         match value_type {
             RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
                 match literal_type {
-                    LiteralType::Address | LiteralType::Boolean | LiteralType::String => {
+                    LiteralType::Address | LiteralType::Boolean | LiteralType::String | LiteralType::Signature => {
                         bail!("Increment cannot increment by a(n) '{literal_type}' (found at '{increment}')")
                     }
                     // These literal types are valid for the 'increment' command.
@@ -297,6 +513,9 @@ impl<N: Network> FinalizeTypes<N> {
             RegisterType::Plaintext(PlaintextType::Struct(..)) => {
                 bail!("Increment cannot increment by an 'struct' (found at '{increment}')")
             }
+            RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                bail!("Increment cannot increment by an 'array' (found at '{increment}')")
+            }
             RegisterType::Record(..) => bail!("Increment cannot increment by a 'record' (found at '{increment}')"),
             RegisterType::ExternalRecord(..) => {
                 bail!("Increment cannot increment by an 'external record' (found at '{increment}')")
@@ -306,6 +525,38 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given set command is well-formed.
+    #[inline]
+    fn check_set(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, set: &Set<N>) -> Result<()> {
+        // Ensure the declared mapping in set is defined in the program.
+        if !stack.program().contains_mapping(set.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", set.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(set.mapping_name()).unwrap();
+
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, set.key())?;
+        // Ensure the key type matches the mapping key type.
+        let mapping_key_type = RegisterType::from(*mapping.key().finalize_type());
+        if key_type != mapping_key_type {
+            bail!("Key type in `set` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
+        }
+
+        // Retrieve the register type of the value.
+        let value_type = self.get_type_from_operand(stack, set.value())?;
+        // Ensure the value type matches the mapping value type.
+        let mapping_value_type = RegisterType::from(*mapping.value().finalize_type());
+        if value_type != mapping_value_type {
+            bail!(
+                "Value type in `set` '{value_type}' does not match the value type in the mapping '{mapping_value_type}'."
+            )
+        }
+
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -317,6 +568,11 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the opcode is well-formed.
         self.check_instruction_opcode(stack, finalize_name, instruction)?;
 
+        // Ensure the instruction does not reference the caller, as it is not available in 'finalize'.
+        if instruction.operands().iter().any(|operand| matches!(operand, Operand::Caller)) {
+            bail!("Forbidden operation: Cannot use 'self.caller' in '{}/{finalize_name}'", stack.program_id())
+        }
+
         // Initialize a vector to store the register types of the operands.
         let mut operand_types = Vec::with_capacity(instruction.operands().len());
         // Iterate over the operands, and retrieve the register type of each operand.
@@ -398,7 +654,9 @@ impl<N: Network> FinalizeTypes<N> {
                 // Ensure the casted register type is defined.
                 match operation.register_type() {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has exactly one operand.
+                        // Note: The operand type is checked against the literal type in `Cast::output_types`.
+                        ensure!(instruction.operands().len() == 1, "Casting to a literal requires exactly one operand")
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -410,6 +668,16 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the element struct exists in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                            if !stack.program().contains_struct(&struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the array type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Unsupported operation: Cannot cast to a record (yet).")
                         // // Ensure the record type is defined in the program.
@@ -487,6 +755,8 @@ impl<N: Network> FinalizeTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.keccak256",
+                    "hash.sha3_256",
                 ]
                 .contains(&opcode)
                 {
@@ -530,6 +800,14 @@ impl<N: Network> FinalizeTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -551,6 +829,14 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{}'.",
+                    instruction.opcode()
+                );
+            }
         }
         Ok(())
     }
@@ -18,7 +18,7 @@ mod initialize;
 mod matches;
 
 use crate::{
-    finalize::{Command, Decrement, Finalize, Increment},
+    finalize::{Branch, Command, Decrement, Finalize, Get, GetOrInit, Increment, Set},
     Instruction,
     Opcode,
     Operand,
@@ -27,10 +27,21 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{EntryType, Identifier, LiteralType, PlaintextType, RecordType, Register, RegisterType, Struct},
+    program::{
+        Access,
+        ArrayType,
+        EntryType,
+        Identifier,
+        LiteralType,
+        PlaintextType,
+        RecordType,
+        Register,
+        RegisterType,
+        Struct,
+    },
 };
 
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct FinalizeTypes<N: Network> {
@@ -86,27 +97,29 @@ impl<N: Network> FinalizeTypes<N> {
                 .ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
         };
 
-        // Retrieve the member path if the register is a member. Otherwise, return the register type.
+        // Retrieve the access path if the register is an access. Otherwise, return the register type.
         let path = match &register {
             // If the register is a locator, then output the register type.
             Register::Locator(..) => return Ok(register_type),
-            // If the register is a member, then traverse the member path to output the register type.
-            Register::Member(_, path) => {
-                // Ensure the member path is valid.
-                ensure!(!path.is_empty(), "Register '{register}' references no members.");
-                // Output the member path.
+            // If the register is a member or an access, then traverse the access path to output the register type.
+            Register::Member(..) | Register::Access(..) => {
+                // Retrieve the access path.
+                let path = register.accesses();
+                // Ensure the access path is valid.
+                ensure!(!path.is_empty(), "Register '{register}' references no accesses.");
+                // Output the access path.
                 path
             }
         };
 
-        // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        // Traverse the access path to find the register type.
+        for access in path.iter() {
             // Update the register type at each step.
-            register_type = match &register_type {
-                // Ensure the plaintext type is not a literal, as the register references a member.
-                RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("'{register}' references a literal."),
+            register_type = match (&register_type, access) {
+                // Ensure the plaintext type is not a literal, as the register references an access.
+                (RegisterType::Plaintext(PlaintextType::Literal(..)), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+                (RegisterType::Plaintext(PlaintextType::Struct(struct_name)), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
@@ -114,7 +127,14 @@ impl<N: Network> FinalizeTypes<N> {
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
-                RegisterType::Record(record_name) => {
+                // Traverse the array to output the element type.
+                (RegisterType::Plaintext(PlaintextType::Array(array_type)), Access::Index(index)) => {
+                    // Ensure the index is within the bounds of the array.
+                    ensure!(**index < **array_type.length(), "Index '{index}' is out of bounds in '{array_type}'");
+                    // Update the element type.
+                    RegisterType::Plaintext(array_type.element_type())
+                }
+                (RegisterType::Record(record_name), Access::Member(path_name)) => {
                     // Ensure the record type exists.
                     ensure!(stack.program().contains_record(record_name), "Record '{record_name}' does not exist");
                     // Retrieve the member type from the record.
@@ -137,7 +157,7 @@ impl<N: Network> FinalizeTypes<N> {
                         }
                     }
                 }
-                RegisterType::ExternalRecord(locator) => {
+                (RegisterType::ExternalRecord(locator), Access::Member(path_name)) => {
                     // Ensure the external record type exists.
                     ensure!(stack.contains_external_record(locator), "External record '{locator}' does not exist");
                     // Retrieve the member type from the external record.
@@ -160,6 +180,12 @@ impl<N: Network> FinalizeTypes<N> {
                         }
                     }
                 }
+                // Ensure the member access is not on an array.
+                (RegisterType::Plaintext(PlaintextType::Array(array_type)), Access::Member(path_name)) => {
+                    bail!("'{path_name}' cannot be accessed from array '{array_type}'")
+                }
+                // Ensure the index access is on an array.
+                (_, Access::Index(index)) => bail!("'{register}' cannot be indexed with '{index}'"),
             }
         }
         // Output the member type.
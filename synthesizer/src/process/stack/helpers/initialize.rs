@@ -75,6 +75,19 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Replaces the external stack of an imported program, i.e. after the imported program is upgraded.
+    #[inline]
+    pub(crate) fn replace_external_stack(&mut self, external_stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *external_stack.program_id();
+        // Ensure the external stack was previously added.
+        ensure!(self.external_stacks.contains_key(&program_id), "Program '{program_id}' is not an external stack");
+        // Replace the external stack.
+        self.external_stacks.insert(program_id, external_stack);
+        // Return success.
+        Ok(())
+    }
+
     /// Inserts the given closure to the stack.
     #[inline]
     fn insert_closure(&mut self, closure: &Closure<N>) -> Result<()> {
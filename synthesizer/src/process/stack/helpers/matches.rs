@@ -211,6 +211,8 @@ impl<N: Network> Stack<N> {
                 }
                 // If `plaintext` is a struct, this is a mismatch.
                 Plaintext::Struct(..) => bail!("'{plaintext_type}' is invalid: expected literal, found struct"),
+                // If `plaintext` is an array, this is a mismatch.
+                Plaintext::Array(..) => bail!("'{plaintext_type}' is invalid: expected literal, found array"),
             },
             PlaintextType::Struct(struct_name) => {
                 // Ensure the struct name is valid.
@@ -231,6 +233,7 @@ impl<N: Network> Stack<N> {
                 let members = match plaintext {
                     Plaintext::Literal(..) => bail!("'{struct_name}' is invalid: expected struct, found literal"),
                     Plaintext::Struct(members, ..) => members,
+                    Plaintext::Array(..) => bail!("'{struct_name}' is invalid: expected struct, found array"),
                 };
 
                 // Ensure the number of struct members does not exceed the maximum.
@@ -263,6 +266,29 @@ impl<N: Network> Stack<N> {
                     self.matches_plaintext_internal(member, expected_type, depth + 1)?;
                 }
 
+                Ok(())
+            }
+            PlaintextType::Array(array_type) => {
+                // Retrieve the array elements.
+                let elements = match plaintext {
+                    Plaintext::Literal(..) => bail!("'{array_type}' is invalid: expected array, found literal"),
+                    Plaintext::Struct(..) => bail!("'{array_type}' is invalid: expected array, found struct"),
+                    Plaintext::Array(elements, ..) => elements,
+                };
+
+                // Ensure the number of array elements match.
+                let num_elements = elements.len();
+                let expected_num_elements = **array_type.length() as usize;
+                if expected_num_elements != num_elements {
+                    bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+                }
+
+                // Ensure each element plaintext matches (recursive call).
+                let element_type = array_type.element_type();
+                for element in elements.iter() {
+                    self.matches_plaintext_internal(element, &element_type, depth + 1)?;
+                }
+
                 Ok(())
             }
         }
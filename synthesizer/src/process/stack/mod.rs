@@ -320,6 +320,31 @@ impl<N: Network> Stack<N> {
         Ok(num_calls)
     }
 
+    /// Returns the call graph for the given function name, as the `(program ID, function name)` of each call
+    /// (including the function itself), in the order in which the transitions are added to an execution.
+    #[inline]
+    pub fn get_call_graph(&self, function_name: &Identifier<N>) -> Result<Vec<(ProgramID<N>, Identifier<N>)>> {
+        // Initialize the call graph.
+        let mut call_graph = Vec::new();
+        for instruction in self.get_function(function_name)?.instructions() {
+            if let Instruction::Call(call) = instruction {
+                // Determine if this is a function call.
+                if call.is_function_call(self)? {
+                    // Append the call graph of the called function.
+                    call_graph.extend(match call.operator() {
+                        CallOperator::Locator(locator) => {
+                            self.get_external_stack(locator.program_id())?.get_call_graph(locator.resource())?
+                        }
+                        CallOperator::Resource(resource) => self.get_call_graph(resource)?,
+                    });
+                }
+            }
+        }
+        // Append the function itself, as its transition is added after the transitions of its calls.
+        call_graph.push((*self.program_id(), *function_name));
+        Ok(call_graph)
+    }
+
     /// Returns the register types for the given closure or function name.
     #[inline]
     pub fn get_register_types(&self, name: &Identifier<N>) -> Result<&RegisterTypes<N>> {
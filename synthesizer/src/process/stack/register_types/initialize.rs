@@ -108,6 +108,13 @@ impl<N: Network> RegisterTypes<N> {
                             function.name()
                         );
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                        bail!(
+                            "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                            stack.program_id(),
+                            function.name()
+                        );
+                    }
                     RegisterType::Record(..) => {
                         bail!(
                             "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -152,7 +159,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Member(..) | Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -175,7 +182,7 @@ impl<N: Network> RegisterTypes<N> {
                 }
             }
             // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            Register::Member(..) | Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -193,6 +200,14 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -242,6 +257,14 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the element struct is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                    if !stack.program().contains_struct(&struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -409,7 +432,9 @@ impl<N: Network> RegisterTypes<N> {
                 // Ensure the casted register type is defined.
                 match operation.register_type() {
                     RegisterType::Plaintext(PlaintextType::Literal(..)) => {
-                        bail!("Casting to literal is currently unsupported")
+                        // Ensure the instruction has exactly one operand.
+                        // Note: The operand type is checked against the literal type in `Cast::output_types`.
+                        ensure!(instruction.operands().len() == 1, "Casting to a literal requires exactly one operand")
                     }
                     RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                         // Ensure the struct name exists in the program.
@@ -421,6 +446,16 @@ impl<N: Network> RegisterTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the element struct exists in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.element_type() {
+                            if !stack.program().contains_struct(&struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the array type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(record_name) => {
                         // Ensure the record type is defined in the program.
                         if !stack.program().contains_record(record_name) {
@@ -501,6 +536,8 @@ impl<N: Network> RegisterTypes<N> {
                     "hash.psd2",
                     "hash.psd4",
                     "hash.psd8",
+                    "hash.keccak256",
+                    "hash.sha3_256",
                 ]
                 .contains(&opcode)
                 {
@@ -544,6 +581,14 @@ impl<N: Network> RegisterTypes<N> {
                         matches!(instruction, Instruction::HashPSD8(..)),
                         "Instruction '{instruction}' is not for opcode '{opcode}'."
                     ),
+                    "hash.keccak256" => ensure!(
+                        matches!(instruction, Instruction::HashKeccak256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    "hash.sha3_256" => ensure!(
+                        matches!(instruction, Instruction::HashSha3_256(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
@@ -565,6 +610,14 @@ impl<N: Network> RegisterTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign => {
+                // Ensure the instruction is the correct one.
+                ensure!(
+                    matches!(instruction, Instruction::SignVerify(..)),
+                    "Instruction '{instruction}' is not for opcode '{}'.",
+                    instruction.opcode()
+                );
+            }
         }
         Ok(())
     }
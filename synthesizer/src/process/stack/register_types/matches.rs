@@ -89,6 +89,43 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the array type. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the operands is not empty.
+        if operands.is_empty() {
+            bail!("Casting to an array requires at least one operand")
+        }
+
+        // Ensure the number of array elements match.
+        let num_elements = operands.len();
+        let expected_num_elements = **array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Retrieve the element type.
+        let element_type = array_type.element_type();
+
+        // Ensure the operand types match the element type.
+        for operand in operands.iter() {
+            // Retrieve the operand type.
+            let operand_type = match operand {
+                Operand::Literal(literal) => RegisterType::Plaintext(PlaintextType::Literal(literal.to_type())),
+                Operand::Register(register) => self.get_type(stack, register)?,
+                // The program ID and caller types are addresses.
+                Operand::ProgramID(..) | Operand::Caller => {
+                    RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
+                }
+            };
+            // Ensure the operand type matches the element type.
+            ensure!(
+                operand_type == RegisterType::Plaintext(element_type),
+                "Array element of '{array_type}' expects {element_type}, but found '{operand_type}' in the operand '{operand}'.",
+            )
+        }
+        Ok(())
+    }
+
     /// Checks that the given record matches the layout of the record type.
     /// Note: Ordering for `owner` and `gates` **does** matter, however ordering
     /// for record data does **not** matter, as long as all defined members are present.
@@ -16,29 +16,14 @@
 
 use super::*;
 
-impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
-    /// Loads the literal of a given operand from the registers.
-    ///
-    /// # Errors
-    /// This method will halt if the given operand is not a literal.
-    /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
-    #[inline]
-    pub fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
-        match self.load(stack, operand)? {
-            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
-            Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
-            Value::Record(..) => bail!("Operand must be a literal"),
-        }
-    }
-
+impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N, A> {
     /// Loads the value of a given operand from the registers.
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
     /// In the case of register members, this method will halt if the member is not found.
     #[inline]
-    pub fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
+    fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
         // Retrieve the register.
         let register = match operand {
             // If the operand is a literal, return the literal.
@@ -62,12 +47,15 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => stack_value.clone(),
             // If the register is a register member, then load the specific stack value.
-            Register::Member(_, ref path) => {
+            Register::Member(..) | Register::Access(..) => {
+                // Retrieve the access path.
+                let path = register.accesses();
+
                 match stack_value {
                     // Retrieve the plaintext member from the path.
-                    Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
+                    Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(&path)?),
                     // Retrieve the record entry from the path.
-                    Value::Record(record) => match record.find(path)? {
+                    Value::Record(record) => match record.find(&path)? {
                         Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
                             Value::Plaintext(plaintext)
                         }
@@ -100,6 +88,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         match self.load_circuit(stack, operand)? {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
             circuit::Value::Plaintext(circuit::Plaintext::Struct(..)) => bail!("Operand must be a literal"),
+            circuit::Value::Plaintext(circuit::Plaintext::Array(..)) => bail!("Operand must be a literal"),
             circuit::Value::Record(..) => bail!("Operand must be a literal"),
         }
     }
@@ -146,9 +135,10 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => circuit_value.clone(),
             // If the register is a register member, then load the specific stack value.
-            Register::Member(_, ref path) => {
+            Register::Member(..) | Register::Access(..) => {
                 // Inject the path.
-                let path = path.iter().map(|member| circuit::Identifier::constant(*member)).collect::<Vec<_>>();
+                let path =
+                    register.accesses().iter().map(|access| circuit::Access::constant(*access)).collect::<Vec<_>>();
 
                 match circuit_value {
                     // Retrieve the plaintext member from the path.
@@ -16,8 +16,11 @@
 
 mod load;
 mod store;
+mod traits;
 
-use crate::{CallStack, Operand, RegisterTypes, Stack};
+pub use traits::*;
+
+use crate::{Call, CallStack, Operand, RegisterTypes, Stack};
 use console::{
     network::prelude::*,
     program::{Entry, Literal, Plaintext, Register, Value},
@@ -68,12 +71,6 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         self.call_stack.clone()
     }
 
-    /// Returns the transition caller.
-    #[inline]
-    pub fn caller(&self) -> Result<Address<N>> {
-        self.caller.ok_or_else(|| anyhow!("Caller address (console) is not set in the registers."))
-    }
-
     /// Returns the transition caller, as a circuit.
     #[inline]
     pub fn caller_circuit(&self) -> Result<circuit::Address<A>> {
@@ -92,12 +89,6 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         self.caller_circuit = Some(caller_circuit);
     }
 
-    /// Returns the transition view key.
-    #[inline]
-    pub fn tvk(&self) -> Result<Field<N>> {
-        self.tvk.ok_or_else(|| anyhow!("Transition view key (console) is not set in the registers."))
-    }
-
     /// Returns the transition view key, as a circuit.
     #[inline]
     pub fn tvk_circuit(&self) -> Result<circuit::Field<A>> {
@@ -136,3 +127,25 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Registers<N, A> {
         Ok(())
     }
 }
+
+impl<N: Network, A: circuit::Aleo<Network = N>> RegistersCaller<N> for Registers<N, A> {
+    /// Returns the transition caller.
+    #[inline]
+    fn caller(&self) -> Result<Address<N>> {
+        self.caller.ok_or_else(|| anyhow!("Caller address (console) is not set in the registers."))
+    }
+
+    /// Returns the transition view key.
+    #[inline]
+    fn tvk(&self) -> Result<Field<N>> {
+        self.tvk.ok_or_else(|| anyhow!("Transition view key (console) is not set in the registers."))
+    }
+}
+
+impl<N: Network, A: circuit::Aleo<Network = N>> RegistersCall<N> for Registers<N, A> {
+    /// Evaluates the given `call` instruction, and assigns its outputs to the registers.
+    #[inline]
+    fn evaluate_call(&mut self, stack: &Stack<N>, call: &Call<N>) -> Result<()> {
+        call.evaluate_with_registers(stack, self)
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Call, Operand, Stack};
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, Register, Value},
+    types::{Address, Field},
+};
+
+/// A trait for loading values from the registers.
+pub trait RegistersLoad<N: Network> {
+    /// Loads the value of a given operand from the registers.
+    ///
+    /// # Errors
+    /// This method will halt if the register locator is not found.
+    /// In the case of register members, this method will halt if the member is not found.
+    fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>>;
+
+    /// Loads the literal of a given operand from the registers.
+    ///
+    /// # Errors
+    /// This method will halt if the given operand is not a literal.
+    /// This method will halt if the register locator is not found.
+    /// In the case of register members, this method will halt if the member is not found.
+    #[inline]
+    fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
+        match self.load(stack, operand)? {
+            Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
+            Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
+            Value::Plaintext(Plaintext::Array(..)) => bail!("Operand must be a literal"),
+            Value::Record(..) => bail!("Operand must be a literal"),
+        }
+    }
+
+    /// Loads the plaintext of a given operand from the registers.
+    ///
+    /// # Errors
+    /// This method will halt if the given operand is not a plaintext.
+    /// This method will halt if the register locator is not found.
+    /// In the case of register members, this method will halt if the member is not found.
+    #[inline]
+    fn load_plaintext(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Plaintext<N>> {
+        match self.load(stack, operand)? {
+            Value::Plaintext(plaintext) => Ok(plaintext),
+            Value::Record(..) => bail!("Operand must be a plaintext"),
+        }
+    }
+}
+
+/// A trait for storing values into the registers.
+pub trait RegistersStore<N: Network> {
+    /// Assigns the given value to the given register, assuming the register is not already assigned.
+    ///
+    /// # Errors
+    /// This method will halt if the given register is a register member.
+    /// This method will halt if the given register is an input register.
+    /// This method will halt if the register is already used.
+    fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()>;
+
+    /// Assigns the given literal to the given register, assuming the register is not already assigned.
+    ///
+    /// # Errors
+    /// This method will halt if the given register is a register member.
+    /// This method will halt if the given register is an input register.
+    /// This method will halt if the register is already used.
+    #[inline]
+    fn store_literal(&mut self, stack: &Stack<N>, register: &Register<N>, literal: Literal<N>) -> Result<()> {
+        self.store(stack, register, Value::Plaintext(Plaintext::from(literal)))
+    }
+}
+
+/// A trait for retrieving the transition caller and transition view key from the registers.
+pub trait RegistersCaller<N: Network> {
+    /// Returns the transition caller.
+    fn caller(&self) -> Result<Address<N>>;
+
+    /// Returns the transition view key.
+    fn tvk(&self) -> Result<Field<N>>;
+}
+
+/// A trait for evaluating a `call` instruction on the registers.
+pub trait RegistersCall<N: Network> {
+    /// Evaluates the given `call` instruction, and assigns its outputs to the registers.
+    fn evaluate_call(&mut self, stack: &Stack<N>, call: &Call<N>) -> Result<()>;
+}
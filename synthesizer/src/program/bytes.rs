@@ -21,7 +21,7 @@ impl<N: Network> FromBytes for Program<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        if version > 1 {
             return Err(error("Invalid program version"));
         }
 
@@ -31,6 +31,11 @@ impl<N: Network> FromBytes for Program<N> {
         // Initialize the program.
         let mut program = Program::new(id).map_err(|e| error(e.to_string()))?;
 
+        // Read the upgrade authority, if the program is upgradable.
+        if version == 1 {
+            program.upgrade_authority = Some(Address::read_le(&mut reader)?);
+        }
+
         // Read the number of program imports.
         let imports_len = u8::read_le(&mut reader)?;
         // Read the program imports.
@@ -67,11 +72,20 @@ impl<N: Network> FromBytes for Program<N> {
 impl<N: Network> ToBytes for Program<N> {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        // Note: Version 1 is only used for upgradable programs, to preserve the encoding of all other programs.
+        match self.upgrade_authority {
+            Some(_) => 1u16.write_le(&mut writer)?,
+            None => 0u16.write_le(&mut writer)?,
+        }
 
         // Write the program ID.
         self.id.write_le(&mut writer)?;
 
+        // Write the upgrade authority, if the program is upgradable.
+        if let Some(upgrade_authority) = &self.upgrade_authority {
+            upgrade_authority.write_le(&mut writer)?;
+        }
+
         // Write the number of program imports.
         (self.imports.len() as u8).write_le(&mut writer)?;
         // Write the program imports.
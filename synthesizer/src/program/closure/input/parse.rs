@@ -35,7 +35,9 @@ impl<N: Network> Parser for Input<N> {
             // Ensure the register is not a register member.
             match &register {
                 Register::Locator(..) => Ok(register),
-                Register::Member(..) => Err(error(format!("Input register {register} cannot be a register member"))),
+                Register::Member(..) | Register::Access(..) => {
+                    Err(error(format!("Input register {register} cannot be a register member")))
+                }
             }
         })(string)?;
         // Parse the whitespace from the string.
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FinalizeRegisters, Opcode, Operand, RegistersLoad, Stack};
+use console::{network::prelude::*, program::Identifier};
+
+/// Jumps to `position`, if `first` equals `second`.
+pub type BranchEq<N> = Branch<N, { Variant::BranchEq as u8 }>;
+/// Jumps to `position`, if `first` does **not** equal `second`.
+pub type BranchNeq<N> = Branch<N, { Variant::BranchNeq as u8 }>;
+
+enum Variant {
+    BranchEq,
+    BranchNeq,
+}
+
+/// Compares `first` and `second` and jumps to `position`, if the condition is met.
+/// Note that a branch may only jump forward, to a position that is defined after it.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Branch<N: Network, const VARIANT: u8> {
+    /// The first operand.
+    first: Operand<N>,
+    /// The second operand.
+    second: Operand<N>,
+    /// The name of the position to jump to.
+    position: Identifier<N>,
+}
+
+impl<N: Network, const VARIANT: u8> Branch<N, VARIANT> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Command("branch.eq"),
+            1 => Opcode::Command("branch.neq"),
+            _ => panic!("Invalid 'branch' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.first.clone(), self.second.clone()]
+    }
+
+    /// Returns the first operand.
+    #[inline]
+    pub const fn first(&self) -> &Operand<N> {
+        &self.first
+    }
+
+    /// Returns the second operand.
+    #[inline]
+    pub const fn second(&self) -> &Operand<N> {
+        &self.second
+    }
+
+    /// Returns the name of the position to jump to.
+    #[inline]
+    pub const fn position(&self) -> &Identifier<N> {
+        &self.position
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Branch<N, VARIANT> {
+    /// Evaluates the command, returning `true` if the branch should be taken.
+    #[inline]
+    pub fn evaluate_finalize(&self, stack: &Stack<N>, registers: &FinalizeRegisters<N>) -> Result<bool> {
+        // Retrieve the inputs.
+        let first = registers.load(stack, &self.first)?;
+        let second = registers.load(stack, &self.second)?;
+
+        // Check the condition.
+        match VARIANT {
+            0 => Ok(first == second),
+            1 => Ok(first != second),
+            _ => bail!("Invalid 'branch' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for Branch<N, VARIANT> {
+    /// Parses a string into a command.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "to" keyword from the string.
+        let (string, _) = tag("to")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the position name from the string.
+        let (string, position) = Identifier::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { first, second, position }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for Branch<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for Branch<N, VARIANT> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for Branch<N, VARIANT> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {} {} to {};", Self::opcode(), self.first, self.second, self.position)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for Branch<N, VARIANT> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the first operand.
+        let first = Operand::read_le(&mut reader)?;
+        // Read the second operand.
+        let second = Operand::read_le(&mut reader)?;
+        // Read the position name.
+        let position = Identifier::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { first, second, position })
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for Branch<N, VARIANT> {
+    /// Writes the command to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the first operand.
+        self.first.write_le(&mut writer)?;
+        // Write the second operand.
+        self.second.write_le(&mut writer)?;
+        // Write the position name.
+        self.position.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, branch) = BranchEq::<CurrentNetwork>::parse("branch.eq r0 r1 to exit;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(branch.first, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(branch.second, Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(branch.position, Identifier::from_str("exit").unwrap(), "The position is incorrect");
+
+        let (string, branch) = BranchNeq::<CurrentNetwork>::parse("branch.neq r3 true to skip;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(branch.first, Operand::Register(Register::Locator(3)), "The first operand is incorrect");
+        assert_eq!(branch.second, Operand::from_str("true").unwrap(), "The second operand is incorrect");
+        assert_eq!(branch.position, Identifier::from_str("skip").unwrap(), "The position is incorrect");
+    }
+}
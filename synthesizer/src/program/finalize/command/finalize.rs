@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{Opcode, Operand, Registers, RegistersLoad, Stack};
 use console::{network::prelude::*, program::Register};
 
 /// Finalizes the operands on-chain.
@@ -14,29 +14,115 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod branch;
+pub use branch::*;
+
 mod decrement;
 pub use decrement::*;
 
 mod finalize;
 pub use finalize::*;
 
+mod get;
+pub use get::*;
+
+mod get_or_init;
+pub use get_or_init::*;
+
 mod increment;
 pub use increment::*;
 
-use crate::{program::Instruction, FinalizeRegisters, ProgramStorage, ProgramStore, Stack};
-use console::network::prelude::*;
+mod position;
+pub use position::*;
+
+mod set;
+pub use set::*;
+
+use crate::{program::Instruction, FinalizeRegisters, Operand, ProgramStorage, ProgramStore, Stack};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Register},
+};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub enum Command<N: Network> {
     /// Decrements the value stored at the `first` operand in `mapping` by the amount in the `second` operand.
     Decrement(Decrement<N>),
+    /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
+    Get(Get<N>),
+    /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
+    /// If the key is not present, `default` is stored at the `key` operand in `mapping` and into `destination`.
+    GetOrInit(GetOrInit<N>),
     /// Evaluates the instruction.
     Instruction(Instruction<N>),
     /// Increments the value stored at the `first` operand in `mapping` by the amount in the `second` operand.
     Increment(Increment<N>),
+    /// Sets the value stored at the `key` operand in `mapping` to `value`.
+    Set(Set<N>),
+    /// Jumps to `position`, if `first` equals `second`.
+    BranchEq(BranchEq<N>),
+    /// Jumps to `position`, if `first` does **not** equal `second`.
+    BranchNeq(BranchNeq<N>),
+    /// Indicates a position to which the program can branch to.
+    Position(Position<N>),
 }
 
 impl<N: Network> Command<N> {
+    /// Returns the mapping name, if the command reads from or writes to a mapping.
+    #[inline]
+    pub const fn mapping_name(&self) -> Option<&Identifier<N>> {
+        match self {
+            Command::Decrement(decrement) => Some(decrement.mapping_name()),
+            Command::Get(get) => Some(get.mapping_name()),
+            Command::GetOrInit(get_or_init) => Some(get_or_init.mapping_name()),
+            Command::Increment(increment) => Some(increment.mapping_name()),
+            Command::Set(set) => Some(set.mapping_name()),
+            Command::Instruction(_) | Command::BranchEq(_) | Command::BranchNeq(_) | Command::Position(_) => None,
+        }
+    }
+
+    /// Returns the operands in the command.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        match self {
+            Command::Decrement(decrement) => decrement.operands(),
+            Command::Get(get) => get.operands(),
+            Command::GetOrInit(get_or_init) => get_or_init.operands(),
+            Command::Instruction(instruction) => instruction.operands().to_vec(),
+            Command::Increment(increment) => increment.operands(),
+            Command::Set(set) => set.operands(),
+            Command::BranchEq(branch_eq) => branch_eq.operands(),
+            Command::BranchNeq(branch_neq) => branch_neq.operands(),
+            Command::Position(_) => vec![],
+        }
+    }
+
+    /// Returns the destination registers of the command.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        match self {
+            Command::Get(get) => vec![get.destination().clone()],
+            Command::GetOrInit(get_or_init) => vec![get_or_init.destination().clone()],
+            Command::Instruction(instruction) => instruction.destinations(),
+            Command::Decrement(_)
+            | Command::Increment(_)
+            | Command::Set(_)
+            | Command::BranchEq(_)
+            | Command::BranchNeq(_)
+            | Command::Position(_) => vec![],
+        }
+    }
+
+    /// Returns the position that the command branches to, if the command is a branch.
+    #[inline]
+    pub const fn branch_to(&self) -> Option<&Identifier<N>> {
+        match self {
+            Command::BranchEq(branch_eq) => Some(branch_eq.position()),
+            Command::BranchNeq(branch_neq) => Some(branch_neq.position()),
+            _ => None,
+        }
+    }
+
     /// Evaluates the command.
     #[inline]
     pub fn evaluate_finalize<P: ProgramStorage<N>>(
@@ -47,10 +133,15 @@ impl<N: Network> Command<N> {
     ) -> Result<()> {
         match self {
             Command::Decrement(decrement) => decrement.evaluate_finalize(stack, store, registers),
-            // TODO (howardwu): Implement support for instructions (consider using a trait for `Registers::load/store`).
-            // Command::Instruction(instruction) => instruction.evaluate_finalize(stack, registers),
-            Command::Instruction(_) => bail!("Instructions in 'finalize' are not supported (yet)."),
+            Command::Get(get) => get.evaluate_finalize(stack, store, registers),
+            Command::GetOrInit(get_or_init) => get_or_init.evaluate_finalize(stack, store, registers),
+            Command::Instruction(instruction) => instruction.evaluate(stack, registers),
             Command::Increment(increment) => increment.evaluate_finalize(stack, store, registers),
+            Command::Set(set) => set.evaluate_finalize(stack, store, registers),
+            Command::BranchEq(_) | Command::BranchNeq(_) => {
+                bail!("Branch commands must be evaluated by the finalize scope, found '{self}'")
+            }
+            Command::Position(position) => position.evaluate_finalize(),
         }
     }
 }
@@ -67,8 +158,20 @@ impl<N: Network> FromBytes for Command<N> {
             1 => Ok(Self::Instruction(Instruction::read_le(&mut reader)?)),
             // Read the increment.
             2 => Ok(Self::Increment(Increment::read_le(&mut reader)?)),
+            // Read the get.
+            3 => Ok(Self::Get(Get::read_le(&mut reader)?)),
+            // Read the get.or_init.
+            4 => Ok(Self::GetOrInit(GetOrInit::read_le(&mut reader)?)),
+            // Read the set.
+            5 => Ok(Self::Set(Set::read_le(&mut reader)?)),
+            // Read the branch.eq.
+            6 => Ok(Self::BranchEq(BranchEq::read_le(&mut reader)?)),
+            // Read the branch.neq.
+            7 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
+            // Read the position.
+            8 => Ok(Self::Position(Position::read_le(&mut reader)?)),
             // Invalid variant.
-            3.. => Err(error(format!("Invalid command variant: {variant}"))),
+            9.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -95,6 +198,42 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the increment.
                 increment.write_le(&mut writer)
             }
+            Self::Get(get) => {
+                // Write the variant.
+                3u8.write_le(&mut writer)?;
+                // Write the get.
+                get.write_le(&mut writer)
+            }
+            Self::GetOrInit(get_or_init) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the get.or_init.
+                get_or_init.write_le(&mut writer)
+            }
+            Self::Set(set) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the set.
+                set.write_le(&mut writer)
+            }
+            Self::BranchEq(branch_eq) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the branch.eq.
+                branch_eq.write_le(&mut writer)
+            }
+            Self::BranchNeq(branch_neq) => {
+                // Write the variant.
+                7u8.write_le(&mut writer)?;
+                // Write the branch.neq.
+                branch_neq.write_le(&mut writer)
+            }
+            Self::Position(position) => {
+                // Write the variant.
+                8u8.write_le(&mut writer)?;
+                // Write the position.
+                position.write_le(&mut writer)
+            }
         }
     }
 }
@@ -103,10 +242,18 @@ impl<N: Network> Parser for Command<N> {
     /// Parses the string into the command.
     #[inline]
     fn parse(string: &str) -> ParserResult<Self> {
+        // Note that the order of the parsers is important.
+        // `GetOrInit` must be parsed before `Get`, as `get` is a prefix of `get.or_init`.
         alt((
             map(Decrement::parse, |decrement| Self::Decrement(decrement)),
+            map(GetOrInit::parse, |get_or_init| Self::GetOrInit(get_or_init)),
+            map(Get::parse, |get| Self::Get(get)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
             map(Increment::parse, |increment| Self::Increment(increment)),
+            map(Set::parse, |set| Self::Set(set)),
+            map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
+            map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
+            map(Position::parse, |position| Self::Position(position)),
         ))(string)
     }
 }
@@ -141,8 +288,14 @@ impl<N: Network> Display for Command<N> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Self::Decrement(decrement) => Display::fmt(decrement, f),
+            Self::Get(get) => Display::fmt(get, f),
+            Self::GetOrInit(get_or_init) => Display::fmt(get_or_init, f),
             Self::Instruction(instruction) => Display::fmt(instruction, f),
             Self::Increment(increment) => Display::fmt(increment, f),
+            Self::Set(set) => Display::fmt(set, f),
+            Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
+            Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
+            Self::Position(position) => Display::fmt(position, f),
         }
     }
 }
@@ -162,6 +315,18 @@ mod tests {
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
 
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
         // Instruction
         let expected = "add r0 r1 into r2;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -173,6 +338,30 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Set
+        let expected = "set r0 into object[r1];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchNeq
+        let expected = "branch.neq r2 r3 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -183,6 +372,18 @@ mod tests {
         assert_eq!(Command::Decrement(Decrement::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
 
+        // Get
+        let expected = "get object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Get(Get::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // GetOrInit
+        let expected = "get.or_init object[r0] r1 into r2;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::GetOrInit(GetOrInit::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
         // Instruction
         let expected = "add r0 r1 into r2;";
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
@@ -194,5 +395,29 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Increment(Increment::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Set
+        let expected = "set r0 into object[r1];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Set(Set::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchEq(BranchEq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchNeq
+        let expected = "branch.neq r2 r3 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchNeq(BranchNeq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -32,7 +32,7 @@ use console::{
     program::{FinalizeType, Identifier, Register},
 };
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Finalize<N: Network> {
@@ -43,6 +43,8 @@ pub struct Finalize<N: Network> {
     inputs: IndexSet<Input<N>>,
     /// The commands, in order of execution.
     commands: Vec<Command<N>>,
+    /// The mapping of position names to their command index.
+    positions: IndexMap<Identifier<N>, usize>,
     /// The output statements, in order of the desired output.
     outputs: IndexSet<Output<N>>,
 }
@@ -50,7 +52,7 @@ pub struct Finalize<N: Network> {
 impl<N: Network> Finalize<N> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new(), outputs: IndexSet::new() }
+        Self { name, inputs: IndexSet::new(), commands: Vec::new(), positions: IndexMap::new(), outputs: IndexSet::new() }
     }
 
     /// Returns the name of the associated function.
@@ -73,6 +75,11 @@ impl<N: Network> Finalize<N> {
         &self.commands
     }
 
+    /// Returns the mapping of position names to their command index.
+    pub const fn positions(&self) -> &IndexMap<Identifier<N>, usize> {
+        &self.positions
+    }
+
     /// Returns the finalize outputs.
     pub const fn outputs(&self) -> &IndexSet<Output<N>> {
         &self.outputs
@@ -115,6 +122,8 @@ impl<N: Network> Finalize<N> {
     /// # Errors
     /// This method will halt if there are output statements already.
     /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if a position name was previously added.
+    /// This method will halt if a branch jumps to a previously-added position.
     #[inline]
     pub fn add_command(&mut self, command: Command<N>) -> Result<()> {
         // Ensure there are no output statements in memory.
@@ -137,6 +146,42 @@ impl<N: Network> Finalize<N> {
             }
         }
 
+        // If the command is a `get` or `get.or_init`, ensure the destination register is a locator.
+        match &command {
+            Command::Get(get) => {
+                ensure!(matches!(get.destination(), Register::Locator(..)), "Destination register must be a locator")
+            }
+            Command::GetOrInit(get_or_init) => ensure!(
+                matches!(get_or_init.destination(), Register::Locator(..)),
+                "Destination register must be a locator"
+            ),
+            _ => (),
+        }
+
+        // If the command is a branch, ensure it does not jump backwards.
+        // Note: As a branch may only jump to a position defined after it, finalize cannot loop.
+        match &command {
+            Command::BranchEq(branch_eq) => ensure!(
+                !self.positions.contains_key(branch_eq.position()),
+                "Forbidden operation: Cannot branch backwards to position '{}'",
+                branch_eq.position()
+            ),
+            Command::BranchNeq(branch_neq) => ensure!(
+                !self.positions.contains_key(branch_neq.position()),
+                "Forbidden operation: Cannot branch backwards to position '{}'",
+                branch_neq.position()
+            ),
+            _ => (),
+        }
+
+        // If the command is a position, record its name and index.
+        if let Command::Position(position) = &command {
+            // Ensure the position name was not previously added.
+            ensure!(!self.positions.contains_key(position.name()), "Cannot add duplicate position '{}'", position.name());
+            // Insert the position name and index.
+            self.positions.insert(*position.name(), self.commands.len());
+        }
+
         // Insert the command.
         self.commands.push(command);
         Ok(())
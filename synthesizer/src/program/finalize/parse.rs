@@ -151,6 +151,44 @@ finalize foo:
         assert_eq!(1, finalize.outputs.len());
     }
 
+    #[test]
+    fn test_finalize_parse_branch() {
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to exit;
+    add r0 r0 into r1;
+    position exit;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("foo", finalize.name().to_string());
+        assert_eq!(1, finalize.inputs.len());
+        assert_eq!(3, finalize.commands.len());
+        assert_eq!(Some(&2), finalize.positions().get(&Identifier::from_str("exit").unwrap()));
+
+        // Finalize with a backward branch.
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u64.public;
+    position start;
+    branch.neq r0 0u64 to start;",
+        );
+        assert!(finalize.is_err());
+
+        // Finalize with a duplicate position.
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u64.public;
+    position exit;
+    position exit;",
+        );
+        assert!(finalize.is_err());
+    }
+
     #[test]
     fn test_finalize_display() {
         let expected = r"finalize foo:
@@ -26,7 +26,7 @@ pub use operation::*;
 mod bytes;
 mod parse;
 
-use crate::{Registers, Stack};
+use crate::{Registers, RegistersCall, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::{
         prelude::{
@@ -117,6 +117,10 @@ pub enum Instruction<N: Network> {
     HashPSD4(HashPSD4<N>),
     /// Performs a Poseidon hash with an input rate of 8.
     HashPSD8(HashPSD8<N>),
+    /// Performs a Keccak hash with a 256-bit output.
+    HashKeccak256(HashKeccak256<N>),
+    /// Performs a SHA-3 hash with a 256-bit output.
+    HashSha3_256(HashSha3_256<N>),
     /// Computes the multiplicative inverse of `first`, storing the outcome in `destination`.
     Inv(Inv<N>),
     /// Computes whether `first` equals `second` as a boolean, storing the outcome in `destination`.
@@ -163,6 +167,8 @@ pub enum Instruction<N: Network> {
     Square(Square<N>),
     /// Compute the square root of 'first', storing the outcome in `destination`.
     SquareRoot(SquareRoot<N>),
+    /// Verifies the signature of a message with an address, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
     /// Computes `first - second`, storing the outcome in `destination`.
     Sub(Sub<N>),
     /// Computes `first - second`, wrapping around at the boundary of the type, and storing the outcome in `destination`.
@@ -231,6 +237,8 @@ macro_rules! instruction {
             HashPSD2,
             HashPSD4,
             HashPSD8,
+            HashKeccak256,
+            HashSha3_256,
             Inv,
             IsEq,
             IsNeq,
@@ -252,6 +260,7 @@ macro_rules! instruction {
             ShlWrapped,
             Shr,
             ShrWrapped,
+            SignVerify,
             Square,
             SquareRoot,
             Sub,
@@ -354,12 +363,12 @@ impl<N: Network> Instruction<N> {
 
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersCall<N> + RegistersCaller<N> + RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
-        instruction!(self, |instruction| instruction.evaluate::<A>(stack, registers))
+        instruction!(self, |instruction| instruction.evaluate(stack, registers))
     }
 
     /// Executes the instruction.
@@ -404,7 +413,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            59,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
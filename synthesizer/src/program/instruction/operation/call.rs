@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallStack, Opcode, Operand, Registers, Stack};
+use crate::{CallStack, Opcode, Operand, Registers, RegistersCall, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Identifier, Locator, Register, RegisterType, Request, ValueType},
@@ -163,7 +163,13 @@ impl<N: Network> Call<N> {
 
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(&self, stack: &Stack<N>, registers: &mut impl RegistersCall<N>) -> Result<()> {
+        registers.evaluate_call(stack, self)
+    }
+
+    /// Evaluates the instruction, using the registers of the calling function or closure.
+    #[inline]
+    pub(crate) fn evaluate_with_registers<A: circuit::Aleo<Network = N>>(
         &self,
         stack: &Stack<N>,
         registers: &mut Registers<N, A>,
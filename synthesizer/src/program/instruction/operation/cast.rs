@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{Opcode, Operand, Registers, RegistersCaller, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{
@@ -77,16 +77,28 @@ impl<N: Network> Cast<N> {
 impl<N: Network> Cast<N> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersCaller<N> + RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+                // Retrieve the literal from the operand.
+                let literal = match &inputs[0] {
+                    Value::Plaintext(Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires the operand to be a literal"),
+                };
+                // Cast the literal into the declared literal type.
+                let literal = literal.cast(literal_type)?;
+                // Store the casted literal.
+                registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(literal)))
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
@@ -119,6 +131,40 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the array length.
+                ensure!(
+                    inputs.len() == **array_type.length() as usize,
+                    "Casting to '{array_type}' requires exactly {} operands",
+                    array_type.length()
+                );
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -212,7 +258,23 @@ impl<N: Network> Cast<N> {
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(inputs.len() == 1, "Casting to a literal requires exactly one operand");
+                // Retrieve the literal from the operand.
+                let literal = match &inputs[0] {
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => literal,
+                    _ => bail!("Casting to a literal requires the operand to be a literal"),
+                };
+                // Cast the literal into the declared literal type.
+                let literal = literal.cast(literal_type)?;
+                // Store the casted literal.
+                registers.store_circuit(
+                    stack,
+                    &self.destination,
+                    circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, Default::default())),
+                )
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands is not empty.
                 ensure!(!inputs.is_empty(), "Casting to a struct requires at least one operand");
@@ -250,6 +312,43 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the array length.
+                ensure!(
+                    inputs.len() == **array_type.length() as usize,
+                    "Casting to '{array_type}' requires exactly {} operands",
+                    array_type.length()
+                );
+
+                // Compute the register type of each element.
+                let register_type = RegisterType::Plaintext(array_type.element_type());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the element.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(
+                                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                                &register_type,
+                            )?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        circuit::Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least 2.
                 ensure!(inputs.len() >= 2, "Casting to a record requires at least two operands");
@@ -355,7 +454,18 @@ impl<N: Network> Cast<N> {
 
         // Ensure the output type is defined in the program.
         match self.register_type {
-            RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => {
+                // Ensure there is exactly one operand.
+                ensure!(input_types.len() == 1, "Casting to a literal requires exactly one operand");
+                // Ensure the input type is a literal, and that neither side is a string.
+                match input_types[0] {
+                    RegisterType::Plaintext(PlaintextType::Literal(input_type)) => ensure!(
+                        input_type != LiteralType::String && literal_type != LiteralType::String,
+                        "Casting '{input_type}' to '{literal_type}' is not supported"
+                    ),
+                    input_type => bail!("Casting to a literal requires a literal operand, found '{input_type}'"),
+                }
+            }
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
                 let struct_ = stack.program().get_struct(&struct_name)?;
@@ -380,6 +490,27 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the number of operands matches the array length.
+                ensure!(
+                    input_types.len() == **array_type.length() as usize,
+                    "Casting to '{array_type}' requires exactly {} operands",
+                    array_type.length()
+                );
+                // Retrieve the element type.
+                let element_type = array_type.element_type();
+                // If the element type is a struct, ensure it is defined in the program.
+                if let PlaintextType::Struct(struct_name) = element_type {
+                    stack.program().get_struct(&struct_name)?;
+                }
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    ensure!(
+                        *input_type == RegisterType::Plaintext(element_type),
+                        "Array '{array_type}' element type mismatch: expected '{element_type}', found '{input_type}'"
+                    )
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(&record_name)?;
@@ -592,7 +723,10 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{network::Testnet3, program::Identifier};
+    use console::{
+        network::Testnet3,
+        program::{ArrayType, Identifier, U32},
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -625,6 +759,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_literal() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 into r1 as u8").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands, vec![Operand::Register(Register::Locator(0))], "The operands are incorrect");
+        assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U8)),
+            "The value type is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_cast_into_array() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 r1 r2 into r3 as [u8; 3u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(cast.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::Array(
+                ArrayType::new(PlaintextType::Literal(LiteralType::U8), U32::new(3)).unwrap()
+            )),
+            "The value type is incorrect"
+        );
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
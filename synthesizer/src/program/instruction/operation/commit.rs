@@ -14,7 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use super::{
+    convert_output,
+    convert_output_circuit,
+    is_valid_conversion,
+    read_destination_type_and_operand,
+    write_destination_type,
+};
+use crate::{Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
@@ -50,6 +57,8 @@ pub struct CommitInstruction<N: Network, const VARIANT: u8> {
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination register type.
+    destination_type: LiteralType,
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
@@ -81,15 +90,31 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> LiteralType {
+        self.destination_type
+    }
+
+    /// Returns the type of the commitment, which is converted into the destination type.
+    #[inline]
+    const fn output_type() -> LiteralType {
+        match VARIANT {
+            0..=3 => LiteralType::Field,
+            4 | 5 => LiteralType::Group,
+            _ => panic!("Invalid 'commit' instruction opcode"),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != 2 {
@@ -115,6 +140,8 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             5 => Literal::Group(N::commit_ped128(&input.to_bits_le(), &randomizer)?),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         };
+        // Convert the output into the destination type.
+        let output = convert_output(output, self.destination_type)?;
         // Store the output.
         registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
@@ -154,6 +181,8 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
             5 => circuit::Literal::Group(A::commit_ped128(&input.to_bits_le(), &randomizer)),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         };
+        // Convert the output into the destination type.
+        let output = convert_output_circuit(output, self.destination_type)?;
         // Convert the output to a stack value.
         let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
@@ -174,9 +203,16 @@ impl<N: Network, const VARIANT: u8> CommitInstruction<N, VARIANT> {
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
+        // Ensure the commitment can be converted into the destination type.
+        ensure!(
+            is_valid_conversion(Self::output_type(), self.destination_type),
+            "Instruction '{}' cannot output a '{}'",
+            Self::opcode(),
+            self.destination_type
+        );
+
         match VARIANT {
-            0 | 1 | 2 | 3 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))]),
-            4 | 5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Group))]),
+            0..=5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(self.destination_type))]),
             _ => bail!("Invalid 'commit' variant: {VARIANT}"),
         }
     }
@@ -204,8 +240,20 @@ impl<N: Network, const VARIANT: u8> Parser for CommitInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
-
-        Ok((string, Self { operands: vec![first, second], destination }))
+        // Parse the destination register type from the string, which defaults to the type of the commitment.
+        let (string, destination_type) = opt(map(
+            pair(pair(Sanitizer::parse_whitespaces, tag("as")), pair(Sanitizer::parse_whitespaces, LiteralType::parse)),
+            |(_, (_, destination_type))| destination_type,
+        ))(string)?;
+
+        Ok((
+            string,
+            Self {
+                operands: vec![first, second],
+                destination,
+                destination_type: destination_type.unwrap_or(Self::output_type()),
+            },
+        ))
     }
 }
 
@@ -245,24 +293,28 @@ impl<N: Network, const VARIANT: u8> Display for CommitInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {}", self.destination)?;
+        // Print the destination register type, if it is not the type of the commitment.
+        if self.destination_type != Self::output_type() {
+            write!(f, " as {}", self.destination_type)?;
+        }
+        Ok(())
     }
 }
 
 impl<N: Network, const VARIANT: u8> FromBytes for CommitInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Initialize the vector for the operands.
-        let mut operands = Vec::with_capacity(2);
-        // Read the operands.
-        for _ in 0..2 {
-            operands.push(Operand::read_le(&mut reader)?);
-        }
+        // Read the destination register type, if it is declared, and the first operand.
+        let (destination_type, first) = read_destination_type_and_operand(&mut reader, Self::output_type())?;
+        // Read the second operand.
+        let second = Operand::read_le(&mut reader)?;
+        let operands = vec![first, second];
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
 
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands, destination, destination_type })
     }
 }
 
@@ -273,6 +325,8 @@ impl<N: Network, const VARIANT: u8> ToBytes for CommitInstruction<N, VARIANT> {
         if self.operands.len() != 2 {
             return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
         }
+        // Write the destination register type, if it is not the type of the commitment.
+        write_destination_type(self.destination_type, Self::output_type(), &mut writer)?;
         // Write the operands.
         self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
         // Write the destination register.
@@ -295,5 +349,44 @@ mod tests {
         assert_eq!(commit.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(commit.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
         assert_eq!(commit.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(commit.destination_type, LiteralType::Field, "The destination type is incorrect");
+
+        let commit = CommitPED64::<CurrentNetwork>::from_str("commit.ped64 r0 r1 into r2").unwrap();
+        assert_eq!(commit.destination_type, LiteralType::Group, "The destination type is incorrect");
+    }
+
+    #[test]
+    fn test_parse_destination_type() {
+        let (string, commit) = CommitPED128::<CurrentNetwork>::parse("commit.ped128 r0 r1 into r2 as address").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(commit.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(commit.destination_type, LiteralType::Address, "The destination type is incorrect");
+        assert_eq!(commit.to_string(), "commit.ped128 r0 r1 into r2 as address");
+
+        // Ensure the destination type is only printed if it is not the type of the commitment.
+        let commit = CommitPED128::<CurrentNetwork>::from_str("commit.ped128 r0 r1 into r2 as group").unwrap();
+        assert_eq!(commit.to_string(), "commit.ped128 r0 r1 into r2");
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in
+            ["commit.ped64 r0 r1 into r2", "commit.ped64 r0 r1 into r2 as address", "commit.ped64 r0 r1 into r2 as u8"]
+        {
+            let expected = CommitPED64::<CurrentNetwork>::from_str(string)?;
+            let candidate = CommitPED64::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le()?)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(string, candidate.to_string());
+        }
+
+        // Ensure a commitment of the default type is encoded without its destination type.
+        let commit = CommitPED64::<CurrentNetwork>::from_str("commit.ped64 r0 r1 into r2")?;
+        let mut expected = vec![];
+        commit.operands[0].write_le(&mut expected)?;
+        commit.operands[1].write_le(&mut expected)?;
+        commit.destination.write_le(&mut expected)?;
+        assert_eq!(expected, commit.to_bytes_le()?);
+        Ok(())
     }
 }
@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Registers, Stack};
+use crate::{Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
-    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    program::{ArrayType, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::{Address, Field, U32, U8},
 };
 
 /// BHP256 is a collision-resistant hash function that processes inputs in 256-bit chunks.
@@ -41,6 +42,11 @@ pub type HashPSD4<N> = HashInstruction<N, { Hasher::PSD4 as u8 }>;
 /// Poseidon8 is a cryptographic hash function that processes inputs in 8-field chunks.
 pub type HashPSD8<N> = HashInstruction<N, { Hasher::PSD8 as u8 }>;
 
+/// Keccak256 is the Keccak hash function with a 256-bit output, as used by Ethereum.
+pub type HashKeccak256<N> = HashInstruction<N, { Hasher::Keccak256 as u8 }>;
+/// Sha3_256 is the SHA-3 hash function with a 256-bit output, as standardized in FIPS 202.
+pub type HashSha3_256<N> = HashInstruction<N, { Hasher::Sha3_256 as u8 }>;
+
 enum Hasher {
     BHP256,
     BHP512,
@@ -51,6 +57,8 @@ enum Hasher {
     PSD2,
     PSD4,
     PSD8,
+    Keccak256,
+    Sha3_256,
 }
 
 /// Hashes the operand into the declared type.
@@ -60,6 +68,8 @@ pub struct HashInstruction<N: Network, const VARIANT: u8> {
     operands: Vec<Operand<N>>,
     /// The destination register.
     destination: Register<N>,
+    /// The destination register type.
+    destination_type: PlaintextType<N>,
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
@@ -76,6 +86,8 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
             6 => Opcode::Hash("hash.psd2"),
             7 => Opcode::Hash("hash.psd4"),
             8 => Opcode::Hash("hash.psd8"),
+            9 => Opcode::Hash("hash.keccak256"),
+            10 => Opcode::Hash("hash.sha3_256"),
             _ => panic!("Invalid 'hash' instruction opcode"),
         }
     }
@@ -94,15 +106,40 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     pub fn destinations(&self) -> Vec<Register<N>> {
         vec![self.destination.clone()]
     }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> PlaintextType<N> {
+        self.destination_type
+    }
+
+    /// Returns the destination register type, if the instruction does not declare one.
+    /// Note: The Keccak hash functions output their 256-bit digest as an array of bytes by default.
+    #[inline]
+    fn default_destination_type() -> Result<PlaintextType<N>> {
+        match VARIANT {
+            9 | 10 => Ok(PlaintextType::Array(ArrayType::new(PlaintextType::Literal(LiteralType::U8), U32::new(32))?)),
+            _ => Ok(PlaintextType::Literal(LiteralType::Field)),
+        }
+    }
+
+    /// Returns the destination register type as a literal type.
+    /// Note: Only the Keccak hash functions are able to output an array.
+    fn destination_literal_type(&self) -> Result<LiteralType> {
+        match self.destination_type {
+            PlaintextType::Literal(literal_type) => Ok(literal_type),
+            destination_type => bail!("Instruction '{}' cannot output a '{destination_type}'", Self::opcode()),
+        }
+    }
 }
 
 impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != 1 {
@@ -110,21 +147,40 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         }
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+        // Hash the raw bits of the input with Keccak, and convert the digest into the destination type.
+        if let 9 | 10 = VARIANT {
+            let digest = match VARIANT {
+                9 => N::hash_keccak256(&raw_bits_le(&input)?)?,
+                _ => N::hash_sha3_256(&raw_bits_le(&input)?)?,
+            };
+            let output = convert_digest(digest, self.destination_type)?;
+            return registers.store(stack, &self.destination, Value::Plaintext(output));
+        }
+        // Retrieve the destination type.
+        let destination_type = self.destination_literal_type()?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => N::hash_bhp256(&input.to_bits_le())?,
-            1 => N::hash_bhp512(&input.to_bits_le())?,
-            2 => N::hash_bhp768(&input.to_bits_le())?,
-            3 => N::hash_bhp1024(&input.to_bits_le())?,
-            4 => N::hash_ped64(&input.to_bits_le())?,
-            5 => N::hash_ped128(&input.to_bits_le())?,
-            6 => N::hash_psd2(&input.to_fields()?)?,
-            7 => N::hash_psd4(&input.to_fields()?)?,
-            8 => N::hash_psd8(&input.to_fields()?)?,
+        let output = match (VARIANT, Self::output_type(destination_type)?) {
+            (0, _) => Literal::Field(N::hash_bhp256(&input.to_bits_le())?),
+            (1, _) => Literal::Field(N::hash_bhp512(&input.to_bits_le())?),
+            (2, _) => Literal::Field(N::hash_bhp768(&input.to_bits_le())?),
+            (3, _) => Literal::Field(N::hash_bhp1024(&input.to_bits_le())?),
+            (4, _) => Literal::Field(N::hash_ped64(&input.to_bits_le())?),
+            (5, _) => Literal::Field(N::hash_ped128(&input.to_bits_le())?),
+            (6, LiteralType::Group) => Literal::Group(N::hash_to_group_psd2(&input.to_fields()?)?),
+            (6, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd2(&input.to_fields()?)?),
+            (6, _) => Literal::Field(N::hash_psd2(&input.to_fields()?)?),
+            (7, LiteralType::Group) => Literal::Group(N::hash_to_group_psd4(&input.to_fields()?)?),
+            (7, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd4(&input.to_fields()?)?),
+            (7, _) => Literal::Field(N::hash_psd4(&input.to_fields()?)?),
+            (8, LiteralType::Group) => Literal::Group(N::hash_to_group_psd8(&input.to_fields()?)?),
+            (8, LiteralType::Scalar) => Literal::Scalar(N::hash_to_scalar_psd8(&input.to_fields()?)?),
+            (8, _) => Literal::Field(N::hash_psd8(&input.to_fields()?)?),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
+        // Convert the output into the destination type.
+        let output = convert_output(output, destination_type)?;
         // Store the output.
-        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(Literal::Field(output))))
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
     }
 
     /// Executes the instruction.
@@ -142,22 +198,40 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         }
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+        // Hash the raw bits of the input with Keccak, and convert the digest into the destination type.
+        if let 9 | 10 = VARIANT {
+            let digest = match VARIANT {
+                9 => A::hash_keccak256(&raw_bits_le_circuit(&input)?),
+                _ => A::hash_sha3_256(&raw_bits_le_circuit(&input)?),
+            };
+            let output = convert_digest_circuit(digest, self.destination_type)?;
+            return registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output));
+        }
+        // Retrieve the destination type.
+        let destination_type = self.destination_literal_type()?;
         // Hash the input.
-        let output = match VARIANT {
-            0 => A::hash_bhp256(&input.to_bits_le()),
-            1 => A::hash_bhp512(&input.to_bits_le()),
-            2 => A::hash_bhp768(&input.to_bits_le()),
-            3 => A::hash_bhp1024(&input.to_bits_le()),
-            4 => A::hash_ped64(&input.to_bits_le()),
-            5 => A::hash_ped128(&input.to_bits_le()),
-            6 => A::hash_psd2(&input.to_fields()),
-            7 => A::hash_psd4(&input.to_fields()),
-            8 => A::hash_psd8(&input.to_fields()),
+        let output = match (VARIANT, Self::output_type(destination_type)?) {
+            (0, _) => circuit::Literal::Field(A::hash_bhp256(&input.to_bits_le())),
+            (1, _) => circuit::Literal::Field(A::hash_bhp512(&input.to_bits_le())),
+            (2, _) => circuit::Literal::Field(A::hash_bhp768(&input.to_bits_le())),
+            (3, _) => circuit::Literal::Field(A::hash_bhp1024(&input.to_bits_le())),
+            (4, _) => circuit::Literal::Field(A::hash_ped64(&input.to_bits_le())),
+            (5, _) => circuit::Literal::Field(A::hash_ped128(&input.to_bits_le())),
+            (6, LiteralType::Group) => circuit::Literal::Group(A::hash_to_group_psd2(&input.to_fields())),
+            (6, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd2(&input.to_fields())),
+            (6, _) => circuit::Literal::Field(A::hash_psd2(&input.to_fields())),
+            (7, LiteralType::Group) => circuit::Literal::Group(A::hash_to_group_psd4(&input.to_fields())),
+            (7, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd4(&input.to_fields())),
+            (7, _) => circuit::Literal::Field(A::hash_psd4(&input.to_fields())),
+            (8, LiteralType::Group) => circuit::Literal::Group(A::hash_to_group_psd8(&input.to_fields())),
+            (8, LiteralType::Scalar) => circuit::Literal::Scalar(A::hash_to_scalar_psd8(&input.to_fields())),
+            (8, _) => circuit::Literal::Field(A::hash_psd8(&input.to_fields())),
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         };
+        // Convert the output into the destination type.
+        let output = convert_output_circuit(output, destination_type)?;
         // Convert the output to a stack value.
-        let output =
-            circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::Field(output), Default::default()));
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
         // Store the output.
         registers.store_circuit(stack, &self.destination, output)
     }
@@ -177,11 +251,297 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
         match VARIANT {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 => {
-                Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Field))])
+            0..=8 => {
+                // Ensure the hash output can be converted into the destination type.
+                let destination_type = self.destination_literal_type()?;
+                ensure!(
+                    is_valid_conversion(Self::output_type(destination_type)?, destination_type),
+                    "Instruction '{}' cannot output a '{destination_type}'",
+                    Self::opcode(),
+                );
+            }
+            9 | 10 => {
+                // Ensure the input is a literal or an array of literals.
+                ensure!(
+                    match &input_types[0] {
+                        RegisterType::Plaintext(PlaintextType::Literal(..)) => true,
+                        RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                            matches!(array_type.element_type(), PlaintextType::Literal(..))
+                        }
+                        _ => false,
+                    },
+                    "Instruction '{}' expects a literal or an array of literals. Found '{}'",
+                    Self::opcode(),
+                    input_types[0]
+                );
+                // Ensure the digest can be converted into the destination type.
+                ensure!(
+                    is_valid_digest_type(self.destination_type),
+                    "Instruction '{}' cannot output a '{}'",
+                    Self::opcode(),
+                    self.destination_type
+                );
             }
             _ => bail!("Invalid 'hash' variant: {VARIANT}"),
         }
+
+        Ok(vec![RegisterType::Plaintext(self.destination_type)])
+    }
+
+    /// Returns the type of the hash output that is converted into the given destination type.
+    /// Note: Only the Poseidon hash functions are able to hash directly to a group element or a scalar.
+    fn output_type(destination_type: LiteralType) -> Result<LiteralType> {
+        match (VARIANT, destination_type) {
+            (0..=5, _) => Ok(LiteralType::Field),
+            (6..=8, LiteralType::Address | LiteralType::Group) => Ok(LiteralType::Group),
+            (6..=8, LiteralType::Scalar) => Ok(LiteralType::Scalar),
+            (6..=8, _) => Ok(LiteralType::Field),
+            _ => bail!("Invalid 'hash' variant: {VARIANT}"),
+        }
+    }
+}
+
+/// Returns the number of bits in the given integer type, or `None` if the given type is not an integer.
+const fn integer_size_in_bits(literal_type: LiteralType) -> Option<usize> {
+    match literal_type {
+        LiteralType::I8 | LiteralType::U8 => Some(8),
+        LiteralType::I16 | LiteralType::U16 => Some(16),
+        LiteralType::I32 | LiteralType::U32 => Some(32),
+        LiteralType::I64 | LiteralType::U64 => Some(64),
+        LiteralType::I128 | LiteralType::U128 => Some(128),
+        _ => None,
+    }
+}
+
+/// Returns `true` if a hash or commitment output of the given type can be converted into the destination type.
+pub(crate) fn is_valid_conversion(output_type: LiteralType, destination_type: LiteralType) -> bool {
+    match (output_type, destination_type) {
+        // An output is always valid as its own type.
+        (output_type, destination_type) if output_type == destination_type => true,
+        // A group output may be stored as an address, or as its x-coordinate.
+        (LiteralType::Group, LiteralType::Address | LiteralType::Field) => true,
+        // A field or group output may be truncated into an integer.
+        (LiteralType::Field | LiteralType::Group, destination_type) => integer_size_in_bits(destination_type).is_some(),
+        _ => false,
+    }
+}
+
+/// Converts the given hash or commitment output into the destination type.
+///
+/// A field output, or the x-coordinate of a group output, is converted into an integer
+/// by truncating its little-endian bits to the size of the integer.
+pub(crate) fn convert_output<N: Network>(output: Literal<N>, destination_type: LiteralType) -> Result<Literal<N>> {
+    // Ensure the output can be converted into the destination type.
+    ensure!(
+        is_valid_conversion(output.to_type(), destination_type),
+        "Cannot convert a '{}' output into a '{destination_type}'",
+        output.to_type()
+    );
+    match (output, destination_type) {
+        (output, destination_type) if output.to_type() == destination_type => Ok(output),
+        (Literal::Group(group), LiteralType::Address) => Ok(Literal::Address(Address::new(group))),
+        (Literal::Group(group), LiteralType::Field) => Ok(Literal::Field(group.to_x_coordinate())),
+        (Literal::Field(field), destination_type) => truncate(field, destination_type),
+        (Literal::Group(group), destination_type) => truncate(group.to_x_coordinate(), destination_type),
+        (output, destination_type) => {
+            bail!("Cannot convert a '{}' output into a '{destination_type}'", output.to_type())
+        }
+    }
+}
+
+/// Truncates the given field element into the given integer type.
+fn truncate<N: Network>(field: Field<N>, integer_type: LiteralType) -> Result<Literal<N>> {
+    match integer_size_in_bits(integer_type) {
+        Some(size) => Literal::from_bits_le(integer_type as u8, &field.to_bits_le()[..size]),
+        None => bail!("Cannot truncate a field element into a '{integer_type}'"),
+    }
+}
+
+/// Converts the given hash or commitment output circuit into the destination type.
+///
+/// A field output, or the x-coordinate of a group output, is converted into an integer
+/// by truncating its little-endian bits to the size of the integer.
+pub(crate) fn convert_output_circuit<A: circuit::Aleo>(
+    output: circuit::Literal<A>,
+    destination_type: LiteralType,
+) -> Result<circuit::Literal<A>> {
+    // Ensure the output can be converted into the destination type.
+    ensure!(
+        is_valid_conversion(output.to_type(), destination_type),
+        "Cannot convert a '{}' output into a '{destination_type}'",
+        output.to_type()
+    );
+    match (output, destination_type) {
+        (output, destination_type) if output.to_type() == destination_type => Ok(output),
+        (circuit::Literal::Group(group), LiteralType::Address) => {
+            Ok(circuit::Literal::Address(circuit::Address::from(group)))
+        }
+        (circuit::Literal::Group(group), LiteralType::Field) => Ok(circuit::Literal::Field(group.to_x_coordinate())),
+        (circuit::Literal::Field(field), destination_type) => truncate_circuit(field, destination_type),
+        (circuit::Literal::Group(group), destination_type) => {
+            truncate_circuit(group.to_x_coordinate(), destination_type)
+        }
+        (output, destination_type) => {
+            bail!("Cannot convert a '{}' output into a '{destination_type}'", output.to_type())
+        }
+    }
+}
+
+/// Truncates the given field element circuit into the given integer type.
+fn truncate_circuit<A: circuit::Aleo>(
+    field: circuit::Field<A>,
+    integer_type: LiteralType,
+) -> Result<circuit::Literal<A>> {
+    use circuit::{Inject, ToBits};
+
+    match integer_size_in_bits(integer_type) {
+        Some(size) => {
+            // Prepare the integer variant as a constant.
+            let variant = circuit::U8::constant(console::types::U8::new(integer_type as u8));
+            // Construct the integer from the least significant bits of the field element.
+            Ok(circuit::Literal::from_bits_le(&variant, &field.to_bits_le()[..size]))
+        }
+        None => bail!("Cannot truncate a field element into a '{integer_type}'"),
+    }
+}
+
+/// Returns the little-endian bits of the given literal or array of literals, without their type tags.
+/// Note: This is the encoding hashed by the Keccak hash functions, so that the hash of an array of bytes
+/// matches the standard Keccak hash of those bytes.
+fn raw_bits_le<N: Network>(input: &Value<N>) -> Result<Vec<bool>> {
+    match input {
+        Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal.to_bits_le()),
+        Value::Plaintext(Plaintext::Array(elements, ..)) => {
+            let mut bits_le = vec![];
+            for element in elements {
+                match element {
+                    Plaintext::Literal(literal, ..) => bits_le.extend(literal.to_bits_le()),
+                    _ => bail!("Cannot hash the raw bits of an array of non-literals"),
+                }
+            }
+            Ok(bits_le)
+        }
+        _ => bail!("Cannot hash the raw bits of a value that is not a literal or an array of literals"),
+    }
+}
+
+/// Returns the little-endian bits of the given literal or array of literals circuit, without their type tags.
+fn raw_bits_le_circuit<A: circuit::Aleo>(input: &circuit::Value<A>) -> Result<Vec<circuit::Boolean<A>>> {
+    use circuit::ToBits;
+
+    match input {
+        circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal.to_bits_le()),
+        circuit::Value::Plaintext(circuit::Plaintext::Array(elements, ..)) => {
+            let mut bits_le = vec![];
+            for element in elements {
+                match element {
+                    circuit::Plaintext::Literal(literal, ..) => bits_le.extend(literal.to_bits_le()),
+                    _ => bail!("Cannot hash the raw bits of an array of non-literals"),
+                }
+            }
+            Ok(bits_le)
+        }
+        _ => bail!("Cannot hash the raw bits of a value that is not a literal or an array of literals"),
+    }
+}
+
+/// Returns `true` if a 256-bit digest can be converted into the destination type.
+/// Note: A digest is output as `[u8; 32u32]`, or is compressed with BHP256 into a field element,
+/// which is then converted into the given literal type.
+fn is_valid_digest_type<N: Network>(destination_type: PlaintextType<N>) -> bool {
+    match destination_type {
+        PlaintextType::Literal(literal_type) => is_valid_conversion(LiteralType::Field, literal_type),
+        PlaintextType::Array(array_type) => {
+            array_type.element_type() == PlaintextType::Literal(LiteralType::U8) && **array_type.length() == 32
+        }
+        PlaintextType::Struct(..) => false,
+    }
+}
+
+/// Converts the given 256-bit digest into the destination type.
+fn convert_digest<N: Network>(digest: Vec<bool>, destination_type: PlaintextType<N>) -> Result<Plaintext<N>> {
+    // Ensure the digest can be converted into the destination type.
+    ensure!(is_valid_digest_type(destination_type), "Cannot convert a digest into a '{destination_type}'");
+    match destination_type {
+        PlaintextType::Literal(literal_type) => {
+            Ok(Plaintext::from(convert_output(Literal::Field(N::hash_bhp256(&digest)?), literal_type)?))
+        }
+        PlaintextType::Array(..) => Ok(Plaintext::Array(
+            digest
+                .chunks(8)
+                .map(|bits_le| Ok(Plaintext::from(Literal::U8(U8::from_bits_le(bits_le)?))))
+                .collect::<Result<Vec<_>>>()?,
+            Default::default(),
+        )),
+        PlaintextType::Struct(..) => bail!("Cannot convert a digest into a '{destination_type}'"),
+    }
+}
+
+/// Converts the given 256-bit digest circuit into the destination type.
+fn convert_digest_circuit<A: circuit::Aleo>(
+    digest: Vec<circuit::Boolean<A>>,
+    destination_type: PlaintextType<A::Network>,
+) -> Result<circuit::Plaintext<A>> {
+    use circuit::FromBits;
+
+    // Ensure the digest can be converted into the destination type.
+    ensure!(is_valid_digest_type(destination_type), "Cannot convert a digest into a '{destination_type}'");
+    match destination_type {
+        PlaintextType::Literal(literal_type) => {
+            let output = convert_output_circuit(circuit::Literal::Field(A::hash_bhp256(&digest)), literal_type)?;
+            Ok(circuit::Plaintext::Literal(output, Default::default()))
+        }
+        PlaintextType::Array(..) => Ok(circuit::Plaintext::Array(
+            digest
+                .chunks(8)
+                .map(|bits_le| {
+                    let byte = circuit::Literal::U8(circuit::U8::from_bits_le(bits_le));
+                    circuit::Plaintext::Literal(byte, Default::default())
+                })
+                .collect(),
+            Default::default(),
+        )),
+        PlaintextType::Struct(..) => bail!("Cannot convert a digest into a '{destination_type}'"),
+    }
+}
+
+/// The byte that precedes the destination type in the encoding of a hash or commit instruction.
+/// Note: This byte is not a valid operand variant, so it is distinguished from the first operand.
+const DESTINATION_TYPE_MARKER: u8 = u8::MAX;
+
+/// Writes the destination type of a hash or commit instruction, if it is not the given default type.
+/// Note: An instruction with the default type is encoded without its destination type, as it was before
+/// the destination type was configurable.
+pub(crate) fn write_destination_type<T: PartialEq + ToBytes, W: Write>(
+    destination_type: T,
+    default_type: T,
+    mut writer: W,
+) -> IoResult<()> {
+    if destination_type != default_type {
+        DESTINATION_TYPE_MARKER.write_le(&mut writer)?;
+        destination_type.write_le(&mut writer)?;
+    }
+    Ok(())
+}
+
+/// Reads the destination type of a hash or commit instruction, which is the given default type if it is not
+/// encoded, followed by the first operand.
+pub(crate) fn read_destination_type_and_operand<N: Network, T: PartialEq + Display + FromBytes, R: Read>(
+    mut reader: R,
+    default_type: T,
+) -> IoResult<(T, Operand<N>)> {
+    match u8::read_le(&mut reader)? {
+        DESTINATION_TYPE_MARKER => {
+            // Read the destination type.
+            let destination_type = T::read_le(&mut reader)?;
+            // Ensure the default type is not encoded, so the encoding is unique.
+            if destination_type == default_type {
+                return Err(error(format!("The default destination type '{default_type}' must not be encoded")));
+            }
+            Ok((destination_type, Operand::read_le(&mut reader)?))
+        }
+        // Otherwise, the byte is the variant of the first operand.
+        variant => Ok((default_type, Operand::read_le([variant].as_slice().chain(&mut reader))?)),
     }
 }
 
@@ -203,8 +563,22 @@ impl<N: Network, const VARIANT: u8> Parser for HashInstruction<N, VARIANT> {
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the destination register from the string.
         let (string, destination) = Register::parse(string)?;
+        // Parse the destination register type from the string, or use the default type if it is not declared.
+        let (string, destination_type) = map_res(
+            opt(map(
+                pair(
+                    pair(Sanitizer::parse_whitespaces, tag("as")),
+                    pair(Sanitizer::parse_whitespaces, PlaintextType::parse),
+                ),
+                |(_, (_, destination_type))| destination_type,
+            )),
+            |destination_type| match destination_type {
+                Some(destination_type) => Ok(destination_type),
+                None => Self::default_destination_type(),
+            },
+        )(string)?;
 
-        Ok((string, Self { operands: vec![operand], destination }))
+        Ok((string, Self { operands: vec![operand], destination, destination_type }))
     }
 }
 
@@ -244,19 +618,25 @@ impl<N: Network, const VARIANT: u8> Display for HashInstruction<N, VARIANT> {
         // Print the operation.
         write!(f, "{} ", Self::opcode())?;
         self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
-        write!(f, "into {}", self.destination)
+        write!(f, "into {}", self.destination)?;
+        // Print the destination register type, if it is not the default type.
+        match Self::default_destination_type() {
+            Ok(default_type) if default_type == self.destination_type => Ok(()),
+            _ => write!(f, " as {}", self.destination_type),
+        }
     }
 }
 
 impl<N: Network, const VARIANT: u8> FromBytes for HashInstruction<N, VARIANT> {
     /// Reads the operation from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        // Read the operand.
-        let operands = vec![Operand::read_le(&mut reader)?];
+        // Read the destination register type, if it is declared, and the operand.
+        let default_type = Self::default_destination_type().map_err(|e| error(e.to_string()))?;
+        let (destination_type, operand) = read_destination_type_and_operand(&mut reader, default_type)?;
         // Read the destination register.
         let destination = Register::read_le(&mut reader)?;
         // Return the operation.
-        Ok(Self { operands, destination })
+        Ok(Self { operands: vec![operand], destination, destination_type })
     }
 }
 
@@ -267,6 +647,9 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
         if self.operands.len() != 1 {
             return Err(error(format!("The number of operands must be 1, found {}", self.operands.len())));
         }
+        // Write the destination register type, if it is not the default type.
+        let default_type = Self::default_destination_type().map_err(|e| error(e.to_string()))?;
+        write_destination_type(self.destination_type, default_type, &mut writer)?;
         // Write the operand.
         self.operands[0].write_le(&mut writer)?;
         // Write the destination register.
@@ -277,7 +660,10 @@ impl<N: Network, const VARIANT: u8> ToBytes for HashInstruction<N, VARIANT> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::network::Testnet3;
+    use console::{
+        network::Testnet3,
+        types::{Group, I8, U64},
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -288,5 +674,244 @@ mod tests {
         assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
         assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
         assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, PlaintextType::from(LiteralType::Field), "The destination type is incorrect");
+    }
+
+    #[test]
+    fn test_parse_destination_type() {
+        let (string, hash) = HashPSD2::<CurrentNetwork>::parse("hash.psd2 r0 into r1 as u64").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(hash.operands.len(), 1, "The number of operands is incorrect");
+        assert_eq!(hash.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(hash.destination, Register::Locator(1), "The destination register is incorrect");
+        assert_eq!(hash.destination_type, PlaintextType::from(LiteralType::U64), "The destination type is incorrect");
+        assert_eq!(hash.to_string(), "hash.psd2 r0 into r1 as u64");
+
+        let hash = HashPSD4::<CurrentNetwork>::from_str("hash.psd4 r0 into r1 as group").unwrap();
+        assert_eq!(hash.destination_type, PlaintextType::from(LiteralType::Group), "The destination type is incorrect");
+        let hash = HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1 as scalar").unwrap();
+        assert_eq!(
+            hash.destination_type,
+            PlaintextType::from(LiteralType::Scalar),
+            "The destination type is incorrect"
+        );
+
+        // Ensure the destination type is only printed if it is not a field element.
+        let hash = HashPSD2::<CurrentNetwork>::from_str("hash.psd2 r0 into r1 as field").unwrap();
+        assert_eq!(hash.to_string(), "hash.psd2 r0 into r1");
+    }
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        for string in ["hash.psd2 r0 into r1", "hash.psd2 r0 into r1 as group", "hash.psd2 r0 into r1 as u64"] {
+            let expected = HashPSD2::<CurrentNetwork>::from_str(string)?;
+            let candidate = HashPSD2::<CurrentNetwork>::from_bytes_le(&expected.to_bytes_le()?)?;
+            assert_eq!(expected, candidate);
+            assert_eq!(string, candidate.to_string());
+        }
+
+        // Ensure a hash of the default type is encoded without its destination type.
+        let hash = HashPSD2::<CurrentNetwork>::from_str("hash.psd2 r0 into r1")?;
+        let mut expected = vec![];
+        hash.operands[0].write_le(&mut expected)?;
+        hash.destination.write_le(&mut expected)?;
+        assert_eq!(expected, hash.to_bytes_le()?);
+
+        // Ensure an encoded default destination type is rejected.
+        let mut bytes = vec![];
+        DESTINATION_TYPE_MARKER.write_le(&mut bytes)?;
+        LiteralType::Field.write_le(&mut bytes)?;
+        bytes.extend(expected);
+        assert!(HashPSD2::<CurrentNetwork>::from_bytes_le(&bytes).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_keccak() -> Result<()> {
+        // Ensure the Keccak hash functions output an array of bytes by default.
+        let hash = HashKeccak256::<CurrentNetwork>::from_str("hash.keccak256 r0 into r1")?;
+        assert_eq!(hash.destination_type, PlaintextType::from_str("[u8; 32u32]")?, "The destination type is incorrect");
+        assert_eq!(hash.to_string(), "hash.keccak256 r0 into r1");
+        assert_eq!(hash, HashKeccak256::from_str("hash.keccak256 r0 into r1 as [u8; 32u32]")?);
+        assert_eq!(hash, HashKeccak256::from_bytes_le(&hash.to_bytes_le()?)?);
+
+        // Ensure the digest is only compressed into a field element if it is declared.
+        let hash = HashSha3_256::<CurrentNetwork>::from_str("hash.sha3_256 r0 into r1 as field")?;
+        assert_eq!(hash.destination_type, PlaintextType::from(LiteralType::Field), "The destination type is incorrect");
+        assert_eq!(hash.to_string(), "hash.sha3_256 r0 into r1 as field");
+        assert_eq!(hash, HashSha3_256::from_bytes_le(&hash.to_bytes_le()?)?);
+
+        // Ensure the digest is not converted into a group element, or an array of the wrong type.
+        assert!(!is_valid_digest_type::<CurrentNetwork>(PlaintextType::from(LiteralType::Group)));
+        assert!(!is_valid_digest_type::<CurrentNetwork>(PlaintextType::from_str("[u8; 16u32]")?));
+        assert!(!is_valid_digest_type::<CurrentNetwork>(PlaintextType::from_str("[u16; 32u32]")?));
+
+        // Ensure the other hash functions do not output an array.
+        let hash = HashBHP256::<CurrentNetwork>::from_str("hash.bhp256 r0 into r1 as [u8; 32u32]")?;
+        assert!(hash.destination_literal_type().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_output() -> Result<()> {
+        let field = Field::<CurrentNetwork>::from_u128(u64::MAX as u128 + 2);
+
+        // Ensure a field output is truncated into an integer.
+        assert_eq!(convert_output(Literal::Field(field), LiteralType::U64)?, Literal::U64(U64::new(1)));
+        assert_eq!(convert_output(Literal::Field(field), LiteralType::I8)?, Literal::I8(I8::new(1)));
+        assert_eq!(convert_output(Literal::Field(field), LiteralType::Field)?, Literal::Field(field));
+
+        // Ensure a field output is not converted into a non-integer type.
+        assert!(convert_output(Literal::Field(field), LiteralType::Boolean).is_err());
+        assert!(convert_output(Literal::Field(field), LiteralType::Group).is_err());
+        assert!(convert_output(Literal::Field(field), LiteralType::Scalar).is_err());
+        assert!(convert_output(Literal::Field(field), LiteralType::String).is_err());
+
+        // Ensure a group output is converted into an address or its x-coordinate.
+        let group = Group::<CurrentNetwork>::generator();
+        assert_eq!(convert_output(Literal::Group(group), LiteralType::Address)?, Literal::Address(Address::new(group)));
+        assert_eq!(convert_output(Literal::Group(group), LiteralType::Field)?, Literal::Field(group.to_x_coordinate()));
+        Ok(())
+    }
+
+    /// Evaluates and executes the given hash instruction on the given input, checks the circuit output
+    /// matches the console output, and returns the output.
+    fn run_hash<const VARIANT: u8>(
+        input: Plaintext<CurrentNetwork>,
+        input_type: &str,
+        destination_type: &str,
+    ) -> Result<Value<CurrentNetwork>> {
+        use crate::{Authorization, CallStack, Process, Program};
+        use circuit::{Eject, Environment, Inject};
+        use console::program::Identifier;
+
+        // Initialize the instruction.
+        let instruction =
+            format!("{} r0 into r1{destination_type}", HashInstruction::<CurrentNetwork, VARIANT>::opcode());
+        let hash = HashInstruction::<CurrentNetwork, VARIANT>::from_str(&instruction)?;
+
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as {input_type}.private;
+                {instruction};"
+        ))?;
+        let stack = Stack::new(&Process::load()?, &program)?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, circuit::AleoV0>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+        let (r0, r1) = (Register::Locator(0), Register::Locator(1));
+        let input = Value::Plaintext(input);
+        registers.store(&stack, &r0, input.clone())?;
+        registers.store_circuit(&stack, &r0, circuit::Value::new(circuit::Mode::Private, input))?;
+
+        // Evaluate and execute the instruction.
+        hash.evaluate(&stack, &mut registers)?;
+        hash.execute::<circuit::AleoV0>(&stack, &mut registers)?;
+
+        // Ensure the circuit output matches the console output.
+        let expected = registers.load(&stack, &Operand::Register(r1.clone()))?;
+        let candidate = registers.load_circuit(&stack, &Operand::Register(r1))?;
+        assert_eq!(expected, candidate.eject_value());
+        assert!(circuit::AleoV0::is_satisfied());
+        circuit::AleoV0::reset();
+        Ok(expected)
+    }
+
+    /// Evaluates and executes the given hash instruction on a random field element,
+    /// and checks the output is of the given destination type.
+    fn check_hash<const VARIANT: u8>(destination_type: LiteralType, rng: &mut TestRng) -> Result<()> {
+        let input = Plaintext::from(Literal::Field(Field::rand(rng)));
+        match run_hash::<VARIANT>(input, "field", &format!(" as {destination_type}"))? {
+            Value::Plaintext(Plaintext::Literal(output, ..)) => assert_eq!(destination_type, output.to_type()),
+            output => bail!("Expected a literal output, found '{output}'"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_destination_types() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for destination_type in [LiteralType::Group, LiteralType::Scalar, LiteralType::Address] {
+            check_hash::<{ Hasher::PSD2 as u8 }>(destination_type, &mut rng)?;
+            check_hash::<{ Hasher::PSD4 as u8 }>(destination_type, &mut rng)?;
+            check_hash::<{ Hasher::PSD8 as u8 }>(destination_type, &mut rng)?;
+        }
+        check_hash::<{ Hasher::BHP256 as u8 }>(LiteralType::U64, &mut rng)?;
+        Ok(())
+    }
+
+    /// Returns the given bytes as an array of `u8` literals.
+    fn to_byte_array(bytes: &[u8]) -> Plaintext<CurrentNetwork> {
+        Plaintext::Array(
+            bytes.iter().map(|byte| Plaintext::from(Literal::U8(U8::new(*byte)))).collect(),
+            Default::default(),
+        )
+    }
+
+    #[test]
+    fn test_keccak256() -> Result<()> {
+        const KECCAK256: u8 = Hasher::Keccak256 as u8;
+        // The Keccak-256 hash of "abc".
+        let expected = [
+            0x4e, 0x03, 0x65, 0x7a, 0xea, 0x45, 0xa9, 0x4f, 0xc7, 0xd4, 0x7b, 0xa8, 0x26, 0xc8, 0xd6, 0x67, 0xc0, 0xd1,
+            0xe6, 0xe3, 0x3a, 0x64, 0xa0, 0x36, 0xec, 0x44, 0xf5, 0x8f, 0xa1, 0x2d, 0x6c, 0x45,
+        ];
+        let input = to_byte_array(b"abc");
+
+        // Ensure the digest is output as an array of bytes.
+        let output = run_hash::<KECCAK256>(input.clone(), "[u8; 3u32]", "")?;
+        assert_eq!(output, Value::Plaintext(to_byte_array(&expected)));
+
+        // Ensure the digest is compressed into a field element, if it is declared.
+        let output = run_hash::<KECCAK256>(input, "[u8; 3u32]", " as field")?;
+        let field = CurrentNetwork::hash_bhp256(&expected.to_bits_le())?;
+        assert_eq!(output, Value::Plaintext(Plaintext::from(Literal::Field(field))));
+
+        // Ensure a literal is hashed without its type tags, i.e. `97u8` is hashed as the byte "a".
+        let output = run_hash::<KECCAK256>(Plaintext::from(Literal::U8(U8::new(b'a'))), "u8", "")?;
+        let expected = [
+            0x3a, 0xc2, 0x25, 0x16, 0x8d, 0xf5, 0x42, 0x12, 0xa2, 0x5c, 0x1c, 0x01, 0xfd, 0x35, 0xbe, 0xbf, 0xea, 0x40,
+            0x8f, 0xda, 0xc2, 0xe3, 0x1d, 0xdd, 0x6f, 0x80, 0xa4, 0xbb, 0xf9, 0xa5, 0xf1, 0xcb,
+        ];
+        assert_eq!(output, Value::Plaintext(to_byte_array(&expected)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sha3_256() -> Result<()> {
+        // The SHA3-256 hash of "abc".
+        let expected = [
+            0x3a, 0x98, 0x5d, 0xa7, 0x4f, 0xe2, 0x25, 0xb2, 0x04, 0x5c, 0x17, 0x2d, 0x6b, 0xd3, 0x90, 0xbd, 0x85, 0x5f,
+            0x08, 0x6e, 0x3e, 0x9d, 0x52, 0x5b, 0x46, 0xbf, 0xe2, 0x45, 0x11, 0x43, 0x15, 0x32,
+        ];
+        let output = run_hash::<{ Hasher::Sha3_256 as u8 }>(to_byte_array(b"abc"), "[u8; 3u32]", "")?;
+        assert_eq!(output, Value::Plaintext(to_byte_array(&expected)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_output_circuit() -> Result<()> {
+        use circuit::{Eject, Inject};
+
+        let mut rng = TestRng::default();
+
+        for destination_type in [LiteralType::Field, LiteralType::I16, LiteralType::U32, LiteralType::U128] {
+            // Sample a random field element.
+            let field = Field::<CurrentNetwork>::rand(&mut rng);
+            // Ensure the circuit conversion matches the console conversion.
+            let expected = convert_output(Literal::Field(field), destination_type)?;
+            let candidate = convert_output_circuit(
+                circuit::Literal::<circuit::AleoV0>::Field(circuit::Field::new(circuit::Mode::Private, field)),
+                destination_type,
+            )?;
+            assert_eq!(expected, candidate.eject_value());
+        }
+        Ok(())
     }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Opcode, Operand, Operation, Registers, Stack};
+use crate::{Opcode, Operand, Operation, Registers, RegistersLoad, RegistersStore, Stack};
 use console::{
     network::prelude::*,
     program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
@@ -66,10 +66,10 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
 {
     /// Evaluates the instruction.
     #[inline]
-    pub fn evaluate<A: circuit::Aleo<Network = N>>(
+    pub fn evaluate(
         &self,
         stack: &Stack<N>,
-        registers: &mut Registers<N, A>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
     ) -> Result<()> {
         // Ensure the number of operands is correct.
         if self.operands.len() != NUM_OPERANDS {
@@ -152,7 +152,7 @@ impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const N
             .copied()
             .map(|input_type| match input_type {
                 RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => Ok(literal_type),
-                RegisterType::Plaintext(PlaintextType::Struct(..)) => {
+                RegisterType::Plaintext(PlaintextType::Struct(..) | PlaintextType::Array(..)) => {
                     bail!("Expected literal type, found '{input_type}'")
                 }
                 RegisterType::Record(..) => bail!("Expected literal type, found '{input_type}'"),
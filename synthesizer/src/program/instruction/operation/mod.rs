@@ -35,6 +35,9 @@ pub use is::*;
 mod literals;
 pub use literals::*;
 
+mod sign_verify;
+pub use sign_verify::*;
+
 mod macros;
 
 use crate::Opcode;
@@ -0,0 +1,382 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Opcode, Operand, Registers, RegistersLoad, RegistersStore, Stack};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Boolean,
+};
+
+/// Computes whether `signature` is valid for the given `address` and `message`, storing the outcome in `destination`.
+///
+/// The `message` may be any value, and the signature is checked against its field elements, i.e. `message.to_fields()`.
+/// To sign a plaintext message for this instruction, use `snarkvm account sign --message <plaintext>`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SignVerify<N: Network> {
+    /// The operands, i.e. `(signature, address, message)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Sign
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Signature(signature) => signature,
+            _ => bail!("Invalid signature type for the '{}' evaluation, expected a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Address(address) => address,
+            _ => bail!("Invalid address type for the '{}' evaluation, expected an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = registers.load(stack, &self.operands[2])?;
+
+        // Verify the signature.
+        let output = Literal::Boolean(Boolean::new(signature.verify(&address, &message.to_fields()?)));
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut Registers<N, A>,
+    ) -> Result<()> {
+        use circuit::ToFields;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Signature(signature) => signature,
+            _ => bail!("Invalid signature type for the '{}' execution, expected a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Address(address) => address,
+            _ => bail!("Invalid address type for the '{}' execution, expected an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = registers.load_circuit(stack, &self.operands[2])?;
+
+        // Verify the signature.
+        let output = circuit::Literal::Boolean(signature.verify(&address, &message.to_fields()));
+        // Convert the output to a stack value.
+        let output = circuit::Value::Plaintext(circuit::Plaintext::Literal(output, Default::default()));
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, output)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first input is a signature.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'signature'. Found '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+        // Ensure the second input is an address.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!(
+                "Instruction '{}' expects the second input to be an 'address'. Found '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+        // Ensure the third input is a plaintext.
+        if !matches!(input_types[2], RegisterType::Plaintext(..)) {
+            bail!(
+                "Instruction '{}' expects the third input to be a plaintext. Found '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for SignVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the signature operand from the string.
+        let (string, signature) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the address operand from the string.
+        let (string, address) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the message operand from the string.
+        let (string, message) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![signature, address, message], destination }))
+    }
+}
+
+impl<N: Network> FromStr for SignVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for SignVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for SignVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for SignVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for SignVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program};
+    use circuit::{AleoV0, Eject, Environment, Inject};
+    use console::{
+        account::{PrivateKey, Signature},
+        network::Testnet3,
+        program::Identifier,
+        types::{Address, Field, Scalar},
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    /// Evaluates and executes `sign.verify` on the given inputs, and returns the console output.
+    fn check_sign_verify(
+        signature: Signature<CurrentNetwork>,
+        address: Address<CurrentNetwork>,
+        message: Field<CurrentNetwork>,
+    ) -> Result<bool> {
+        // Initialize the instruction.
+        let instruction = SignVerify::<CurrentNetwork>::from_str("sign.verify r0 r1 r2 into r3")?;
+
+        // Initialize the stack.
+        let program = Program::<CurrentNetwork>::from_str(&format!(
+            "program testing.aleo;
+            function run:
+                input r0 as signature.private;
+                input r1 as address.private;
+                input r2 as field.private;
+                {instruction};"
+        ))?;
+        let stack = Stack::new(&Process::load()?, &program)?;
+
+        // Initialize the registers.
+        let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+            CallStack::evaluate(Authorization::new(&[]))?,
+            stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+        );
+        let inputs = [Literal::Signature(Box::new(signature)), Literal::Address(address), Literal::Field(message)];
+        for (index, input) in inputs.into_iter().enumerate() {
+            let register = Register::Locator(index as u64);
+            let input = Value::Plaintext(Plaintext::from(input));
+            registers.store(&stack, &register, input.clone())?;
+            registers.store_circuit(&stack, &register, circuit::Value::new(circuit::Mode::Private, input))?;
+        }
+
+        // Evaluate and execute the instruction.
+        instruction.evaluate(&stack, &mut registers)?;
+        instruction.execute::<CurrentAleo>(&stack, &mut registers)?;
+
+        // Ensure the circuit output matches the console output.
+        let destination = Operand::Register(Register::Locator(3));
+        let expected = registers.load_literal(&stack, &destination)?;
+        let candidate = registers.load_literal_circuit(&stack, &destination)?;
+        assert_eq!(expected, candidate.eject_value());
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+
+        match expected {
+            Literal::Boolean(output) => Ok(*output),
+            _ => bail!("Expected a boolean output"),
+        }
+    }
+
+    #[test]
+    fn test_sign_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key and its address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Sign a message, encoded as the field elements of its plaintext value.
+        let message = Field::<CurrentNetwork>::rand(rng);
+        let message_fields = Value::Plaintext(Plaintext::from(Literal::Field(message))).to_fields()?;
+        let signature = Signature::sign(&private_key, &message_fields, rng)?;
+
+        // Ensure a valid signature is accepted.
+        assert!(check_sign_verify(signature, address, message)?);
+
+        // Ensure a signature on a different message is rejected.
+        assert!(!check_sign_verify(signature, address, Field::rand(rng))?);
+
+        // Ensure a signature for a different address is rejected.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(!check_sign_verify(signature, other_address, message)?);
+
+        // Ensure a tampered signature is rejected.
+        let tampered =
+            Signature::from((signature.challenge(), signature.response() + Scalar::one(), signature.compute_key()));
+        assert!(!check_sign_verify(tampered, address, message)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse() {
+        let (string, sign) = SignVerify::<CurrentNetwork>::parse("sign.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(sign.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(sign.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(sign.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(sign.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(sign.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Check the display and byte representations.
+        assert_eq!(sign.to_string(), "sign.verify r0 r1 r2 into r3");
+        assert_eq!(sign, SignVerify::from_bytes_le(&sign.to_bytes_le().unwrap()).unwrap());
+
+        // Ensure the instruction requires three operands.
+        assert!(SignVerify::<CurrentNetwork>::from_str("sign.verify r0 r1 into r2").is_err());
+    }
+}
@@ -38,6 +38,7 @@ mod serialize;
 use console::{
     network::prelude::*,
     program::{EntryType, Identifier, PlaintextType, ProgramID, RecordType, Struct},
+    types::Address,
 };
 
 use indexmap::IndexMap;
@@ -60,6 +61,8 @@ enum ProgramDefinition {
 pub struct Program<N: Network> {
     /// The ID of the program.
     id: ProgramID<N>,
+    /// The address that is authorized to upgrade the program, if the program is upgradable.
+    upgrade_authority: Option<Address<N>>,
     /// A map of the declared imports for the program.
     imports: IndexMap<ProgramID<N>, Import<N>>,
     /// A map of identifiers to their program declaration.
@@ -87,6 +90,7 @@ impl<N: Network> Program<N> {
 
         Ok(Self {
             id,
+            upgrade_authority: None,
             imports: IndexMap::new(),
             identifiers: IndexMap::new(),
             mappings: IndexMap::new(),
@@ -104,6 +108,10 @@ impl<N: Network> Program<N> {
             r"
 program credits.aleo;
 
+mapping account:
+    key owner as address.public;
+    value gates as u64.public;
+
 record credits:
     owner as address.private;
     gates as u64.private;
@@ -155,6 +163,11 @@ function fee:
         &self.id
     }
 
+    /// Returns the upgrade authority of the program, if the program is upgradable.
+    pub const fn upgrade_authority(&self) -> Option<&Address<N>> {
+        self.upgrade_authority.as_ref()
+    }
+
     /// Returns the imports in the program.
     pub const fn imports(&self) -> &IndexMap<ProgramID<N>, Import<N>> {
         &self.imports
@@ -270,6 +283,36 @@ function fee:
         // Return the function.
         Ok(function)
     }
+
+    /// Checks that the program is a valid upgrade of the given (previous) program.
+    /// An upgrade may replace the closures and the function bodies, but it must preserve the program ID,
+    /// the mappings, the structs, the records, and the name, inputs, and outputs of each function.
+    pub fn check_upgrade_of(&self, previous: &Program<N>) -> Result<()> {
+        let program_id = previous.id();
+
+        // Ensure the program ID matches.
+        ensure!(self.id == previous.id, "Cannot upgrade program '{program_id}' with program '{}'", self.id);
+        // Ensure the mappings are preserved.
+        ensure!(self.mappings == previous.mappings, "The upgrade of '{program_id}' must preserve the mappings");
+        // Ensure the structs are preserved.
+        ensure!(self.structs == previous.structs, "The upgrade of '{program_id}' must preserve the structs");
+        // Ensure the records are preserved.
+        ensure!(self.records == previous.records, "The upgrade of '{program_id}' must preserve the records");
+        // Ensure the functions are preserved, in order.
+        ensure!(
+            self.functions.keys().eq(previous.functions.keys()),
+            "The upgrade of '{program_id}' must preserve the functions"
+        );
+        // Ensure the inputs and outputs of each function are preserved.
+        for (function, previous_function) in self.functions.values().zip(previous.functions.values()) {
+            ensure!(
+                function.inputs() == previous_function.inputs() && function.outputs() == previous_function.outputs(),
+                "The upgrade of '{program_id}' must preserve the inputs and outputs of '{}'",
+                function.name()
+            );
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> Program<N> {
@@ -359,14 +402,8 @@ impl<N: Network> Program<N> {
             // Ensure the member name is not a reserved keyword.
             ensure!(!Self::is_reserved_keyword(identifier), "'{identifier}' is a reserved keyword.");
             // Ensure the member type is already defined in the program.
-            match plaintext_type {
-                PlaintextType::Literal(..) => continue,
-                PlaintextType::Struct(member_identifier) => {
-                    // Ensure the member struct name exists in the program.
-                    if !self.structs.contains_key(member_identifier) {
-                        bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
-                    }
-                }
+            if let Some(member_identifier) = self.find_undefined_struct(plaintext_type) {
+                bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
             }
         }
 
@@ -413,14 +450,11 @@ impl<N: Network> Program<N> {
                 // Ensure the plaintext type is already defined.
                 EntryType::Constant(plaintext_type)
                 | EntryType::Public(plaintext_type)
-                | EntryType::Private(plaintext_type) => match plaintext_type {
-                    PlaintextType::Literal(..) => continue,
-                    PlaintextType::Struct(identifier) => {
-                        if !self.structs.contains_key(identifier) {
-                            bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
-                        }
+                | EntryType::Private(plaintext_type) => {
+                    if let Some(identifier) = self.find_undefined_struct(plaintext_type) {
+                        bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                     }
-                },
+                }
             }
         }
 
@@ -552,6 +586,7 @@ impl<N: Network> Program<N> {
         "u128",
         "scalar",
         "string",
+        "signature",
         // Boolean
         "true",
         "false",
@@ -597,6 +632,16 @@ impl<N: Network> Program<N> {
         "type",
     ];
 
+    /// Returns the first struct in the given plaintext type that is not defined in the program, if any.
+    /// Note: The element type of an array is checked recursively.
+    fn find_undefined_struct(&self, plaintext_type: &PlaintextType<N>) -> Option<Identifier<N>> {
+        match plaintext_type {
+            PlaintextType::Literal(..) => None,
+            PlaintextType::Struct(struct_name) => (!self.structs.contains_key(struct_name)).then_some(*struct_name),
+            PlaintextType::Array(array_type) => self.find_undefined_struct(&array_type.element_type()),
+        }
+    }
+
     /// Returns `true` if the given name does not already exist in the program.
     fn is_unique_name(&self, name: &Identifier<N>) -> bool {
         !self.identifiers.contains_key(name)
@@ -695,6 +740,45 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_struct_with_array() -> Result<()> {
+        // Create a new struct with an array member.
+        let struct_ = Struct::<CurrentNetwork>::from_str(
+            r"
+struct message:
+    first as field;
+    second as [u8; 4u32];",
+        )?;
+        // Create a new struct with an array of an undefined struct.
+        let undefined = Struct::<CurrentNetwork>::from_str(
+            r"
+struct batch:
+    messages as [undefined; 2u32];",
+        )?;
+
+        // Initialize a new program.
+        let mut program = Program::<CurrentNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+
+        // Add the struct to the program.
+        program.add_struct(struct_.clone())?;
+        // Ensure the retrieved struct matches.
+        assert_eq!(struct_, program.get_struct(&Identifier::from_str("message")?)?);
+        // Ensure the struct with an undefined element struct is rejected.
+        assert!(program.add_struct(undefined).is_err());
+
+        // Ensure a record with an array of an undefined struct is rejected.
+        let record = RecordType::<CurrentNetwork>::from_str(
+            r"
+record token:
+    owner as address.private;
+    gates as u64.private;
+    batch as [undefined; 2u32].private;",
+        )?;
+        assert!(program.add_record(record).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
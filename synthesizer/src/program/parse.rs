@@ -44,6 +44,9 @@ impl<N: Network> Parser for Program<N> {
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
 
+        // Parse the optional upgrade authority from the string.
+        let (string, upgrade_authority) = opt(Self::parse_upgrade_authority)(string)?;
+
         // Parse the struct or function from the string.
         let (string, components) = many1(alt((
             map(Mapping::parse, |mapping| P::<N>::M(mapping)),
@@ -65,6 +68,8 @@ impl<N: Network> Parser for Program<N> {
                     return Err(error);
                 }
             };
+            // Set the upgrade authority, if one was declared.
+            program.upgrade_authority = upgrade_authority;
             // Construct the program with the parsed components.
             for component in components.iter() {
                 let result = match component {
@@ -99,6 +104,29 @@ impl<N: Network> Parser for Program<N> {
     }
 }
 
+impl<N: Network> Program<N> {
+    /// The keyword used to declare the upgrade authority of a program.
+    const UPGRADE_AUTHORITY: &'static str = "upgrade_authority";
+
+    /// Parses the upgrade authority declaration, of the form `upgrade_authority {address};`.
+    fn parse_upgrade_authority(string: &str) -> ParserResult<Address<N>> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the 'upgrade_authority' keyword from the string.
+        let (string, _) = tag(Self::UPGRADE_AUTHORITY)(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the address from the string.
+        let (string, address) = Address::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon ';' keyword from the string.
+        let (string, _) = tag(";")(string)?;
+        // Return the address.
+        Ok((string, address))
+    }
+}
+
 impl<N: Network> FromStr for Program<N> {
     type Err = Error;
 
@@ -143,6 +171,11 @@ impl<N: Network> Display for Program<N> {
         // Print the program name.
         program += &format!("{} {};\n\n", Self::type_name(), self.id);
 
+        // Print the upgrade authority, if it is declared.
+        if let Some(upgrade_authority) = &self.upgrade_authority {
+            program += &format!("{} {upgrade_authority};\n\n", Self::UPGRADE_AUTHORITY);
+        }
+
         for (identifier, definition) in self.identifiers.iter() {
             match definition {
                 ProgramDefinition::Mapping => match self.mappings.get(identifier) {
@@ -263,4 +296,40 @@ function compute:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_upgrade_authority() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an upgrade authority.
+        let private_key = console::account::PrivateKey::<CurrentNetwork>::new(rng)?;
+        let authority = Address::try_from(&private_key)?;
+
+        let expected = format!(
+            r"program to_parse.aleo;
+
+upgrade_authority {authority};
+
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.private;
+"
+        );
+        // Parse a new program.
+        let program = Program::<CurrentNetwork>::from_str(&expected)?;
+        // Ensure the upgrade authority is declared.
+        assert_eq!(program.upgrade_authority(), Some(&authority));
+        // Ensure the program string matches.
+        assert_eq!(expected, format!("{program}"));
+        // Ensure the program bytes round-trip.
+        assert_eq!(program, Program::from_bytes_le(&program.to_bytes_le()?)?);
+
+        // Ensure a program without an upgrade authority does not declare one.
+        let declaration = format!("upgrade_authority {authority};\n\n");
+        let program = Program::<CurrentNetwork>::from_str(&expected.replace(&declaration, ""))?;
+        assert_eq!(program.upgrade_authority(), None);
+
+        Ok(())
+    }
 }
@@ -14,14 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod scan;
+pub use scan::*;
+
 use crate::{
     atomic_write_batch,
-    block::{Block, Header, Transactions},
+    block::{Block, Header, Transaction, Transactions},
     coinbase_puzzle::{CoinbaseSolution, PuzzleCommitment},
     cow_to_cloned,
     cow_to_copied,
     store::{
         helpers::{memory_map::MemoryMap, Map, MapRead},
+        FinalizeStatus,
         TransactionMemory,
         TransactionStorage,
         TransactionStore,
@@ -34,11 +38,13 @@ use crate::{
 use console::{
     account::Signature,
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
-    types::Field,
+    program::{BlockTree, HeaderLeaf, Identifier, ProgramID, StatePath},
+    types::{Address, Field},
 };
 
 use anyhow::Result;
+use core::ops::Range;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{borrow::Cow, sync::Arc};
 
@@ -78,6 +84,22 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type CoinbasePuzzleCommitmentMap: for<'a> Map<'a, PuzzleCommitment<N>, N::BlockHash>;
     /// The mapping of `block hash` to `block signature`.
     type SignatureMap: for<'a> Map<'a, N::BlockHash, Signature<N>>;
+    /// The mapping of `block hash` to `(prover address => coinbase reward)`.
+    type ProverRewardsMap: for<'a> Map<'a, N::BlockHash, IndexMap<Address<N>, u64>>;
+    /// The mapping of `prover address` to `total coinbase reward`.
+    type ProverTotalRewardsMap: for<'a> Map<'a, Address<N>, u64>;
+    /// The mapping of `(program ID, index)` to `transaction ID`.
+    type ProgramTransactionsMap: for<'a> Map<'a, (ProgramID<N>, u32), N::TransactionID>;
+    /// The mapping of `program ID` to `number of program transactions`.
+    type ProgramTransactionsCountMap: for<'a> Map<'a, ProgramID<N>, u32>;
+    /// The mapping of `(program ID, function name, index)` to `transaction ID`.
+    type FunctionTransactionsMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u32), N::TransactionID>;
+    /// The mapping of `(program ID, function name)` to `number of function transactions`.
+    type FunctionTransactionsCountMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u32>;
+    /// The mapping of `(program ID, mapping name, index)` to `transition ID`.
+    type MappingTransitionsMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u32), N::TransitionID>;
+    /// The mapping of `(program ID, mapping name)` to `number of mapping transitions`.
+    type MappingTransitionsCountMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>), u32>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -104,6 +126,22 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap;
     /// Returns the signature map.
     fn signature_map(&self) -> &Self::SignatureMap;
+    /// Returns the prover rewards map.
+    fn prover_rewards_map(&self) -> &Self::ProverRewardsMap;
+    /// Returns the prover total rewards map.
+    fn prover_total_rewards_map(&self) -> &Self::ProverTotalRewardsMap;
+    /// Returns the program transactions map.
+    fn program_transactions_map(&self) -> &Self::ProgramTransactionsMap;
+    /// Returns the program transactions count map.
+    fn program_transactions_count_map(&self) -> &Self::ProgramTransactionsCountMap;
+    /// Returns the function transactions map.
+    fn function_transactions_map(&self) -> &Self::FunctionTransactionsMap;
+    /// Returns the function transactions count map.
+    fn function_transactions_count_map(&self) -> &Self::FunctionTransactionsCountMap;
+    /// Returns the mapping transitions map.
+    fn mapping_transitions_map(&self) -> &Self::MappingTransitionsMap;
+    /// Returns the mapping transitions count map.
+    fn mapping_transitions_count_map(&self) -> &Self::MappingTransitionsCountMap;
 
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
@@ -128,6 +166,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.coinbase_solution_map().start_atomic();
         self.coinbase_puzzle_commitment_map().start_atomic();
         self.signature_map().start_atomic();
+        self.prover_rewards_map().start_atomic();
+        self.prover_total_rewards_map().start_atomic();
+        self.program_transactions_map().start_atomic();
+        self.program_transactions_count_map().start_atomic();
+        self.function_transactions_map().start_atomic();
+        self.function_transactions_count_map().start_atomic();
+        self.mapping_transitions_map().start_atomic();
+        self.mapping_transitions_count_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -143,6 +189,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.coinbase_solution_map().is_atomic_in_progress()
             || self.coinbase_puzzle_commitment_map().is_atomic_in_progress()
             || self.signature_map().is_atomic_in_progress()
+            || self.prover_rewards_map().is_atomic_in_progress()
+            || self.prover_total_rewards_map().is_atomic_in_progress()
+            || self.program_transactions_map().is_atomic_in_progress()
+            || self.program_transactions_count_map().is_atomic_in_progress()
+            || self.function_transactions_map().is_atomic_in_progress()
+            || self.function_transactions_count_map().is_atomic_in_progress()
+            || self.mapping_transitions_map().is_atomic_in_progress()
+            || self.mapping_transitions_count_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -158,6 +212,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.coinbase_solution_map().abort_atomic();
         self.coinbase_puzzle_commitment_map().abort_atomic();
         self.signature_map().abort_atomic();
+        self.prover_rewards_map().abort_atomic();
+        self.prover_total_rewards_map().abort_atomic();
+        self.program_transactions_map().abort_atomic();
+        self.program_transactions_count_map().abort_atomic();
+        self.function_transactions_map().abort_atomic();
+        self.function_transactions_count_map().abort_atomic();
+        self.mapping_transitions_map().abort_atomic();
+        self.mapping_transitions_count_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -172,7 +234,15 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.transaction_store().finish_atomic()?;
         self.coinbase_solution_map().finish_atomic()?;
         self.coinbase_puzzle_commitment_map().finish_atomic()?;
-        self.signature_map().finish_atomic()
+        self.signature_map().finish_atomic()?;
+        self.prover_rewards_map().finish_atomic()?;
+        self.prover_total_rewards_map().finish_atomic()?;
+        self.program_transactions_map().finish_atomic()?;
+        self.program_transactions_count_map().finish_atomic()?;
+        self.function_transactions_map().finish_atomic()?;
+        self.function_transactions_count_map().finish_atomic()?;
+        self.mapping_transitions_map().finish_atomic()?;
+        self.mapping_transitions_count_map().finish_atomic()
     }
 
     /// Stores the given `(state root, block)` pair into storage.
@@ -193,6 +263,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             // Store the transaction IDs.
             self.transactions_map().insert(block.hash(), block.transaction_ids().copied().collect())?;
 
+            // Store the secondary indexes.
+            self.insert_indexes(block)?;
+
             // Store the block transactions.
             for transaction in block.transactions().values() {
                 // Store the reverse transaction ID.
@@ -244,6 +317,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 bail!("Failed to remove block: missing coinbase solution for block '{block_height}' ('{block_hash}')")
             }
         };
+        // Retrieve the prover rewards.
+        let rewards = self.get_prover_rewards(block_hash)?.unwrap_or_default();
 
         atomic_write_batch!(self, {
             // Remove the (block height, state root) pair.
@@ -261,6 +336,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             // Remove the transaction IDs.
             self.transactions_map().remove(block_hash)?;
 
+            // Remove the secondary indexes.
+            // Note: This must precede removing the transactions, so the programs can still be retrieved.
+            self.remove_indexes(&transaction_ids)?;
+
             // Remove the block transactions.
             for transaction_id in transaction_ids.iter() {
                 // Remove the reverse transaction ID.
@@ -281,7 +360,229 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 
             // Remove the block signature.
             self.signature_map().remove(block_hash)?;
+            // Remove the prover rewards, and deduct them from the total reward of each prover.
+            self.prover_rewards_map().remove(block_hash)?;
+            for (address, reward) in rewards {
+                let total = match self.prover_total_rewards_map().get_speculative(&address)? {
+                    Some(total) => cow_to_copied!(total).checked_sub(reward),
+                    None => None,
+                };
+                match total {
+                    Some(0) => self.prover_total_rewards_map().remove(&address)?,
+                    Some(total) => self.prover_total_rewards_map().insert(address, total)?,
+                    None => bail!("Failed to remove block: the total reward of prover '{address}' underflowed"),
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Appends the given block to the program and function indexes of its transactions.
+    /// Note: The indexes are only stored if the `history` feature is enabled.
+    fn insert_indexes(&self, block: &Block<N>) -> Result<()> {
+        // If the `history` feature is disabled, skip the indexes.
+        if !cfg!(feature = "history") {
+            return Ok(());
+        }
+
+        for transaction in block.transactions().values() {
+            let transaction_id = transaction.id();
+
+            // Collect the programs and functions of the transaction.
+            let mut program_ids = IndexSet::new();
+            let mut function_keys = IndexSet::new();
+            if let Transaction::Deploy(_, deployment, _) = transaction {
+                program_ids.insert(*deployment.program_id());
+            }
+            for transition in transaction.transitions() {
+                program_ids.insert(*transition.program_id());
+                function_keys.insert((*transition.program_id(), *transition.function_name()));
+            }
+
+            // Append the transaction to the index of each program.
+            for program_id in program_ids {
+                let index = match self.program_transactions_count_map().get_speculative(&program_id)? {
+                    Some(count) => cow_to_copied!(count),
+                    None => 0,
+                };
+                self.program_transactions_map().insert((program_id, index), transaction_id)?;
+                self.program_transactions_count_map().insert(program_id, index + 1)?;
+            }
+            // Append the transaction to the index of each function.
+            for (program_id, function_name) in function_keys {
+                let index =
+                    match self.function_transactions_count_map().get_speculative(&(program_id, function_name))? {
+                        Some(count) => cow_to_copied!(count),
+                        None => 0,
+                    };
+                self.function_transactions_map().insert((program_id, function_name, index), transaction_id)?;
+                self.function_transactions_count_map().insert((program_id, function_name), index + 1)?;
+            }
+        }
+        Ok(())
+    }
 
+    /// Appends the given transitions to the index of each `(program ID, mapping name)` touched by their finalize scope.
+    /// Note: The indexes are only stored if the `history` feature is enabled.
+    fn insert_mapping_transitions(
+        &self,
+        mapping_transitions: &[(ProgramID<N>, Identifier<N>, N::TransitionID)],
+    ) -> Result<()> {
+        // If the `history` feature is disabled, skip the indexes.
+        if !cfg!(feature = "history") {
+            return Ok(());
+        }
+
+        atomic_write_batch!(self, {
+            for (program_id, mapping_name, transition_id) in mapping_transitions {
+                let index = match self.mapping_transitions_count_map().get_speculative(&(*program_id, *mapping_name))? {
+                    Some(count) => cow_to_copied!(count),
+                    None => 0,
+                };
+                self.mapping_transitions_map().insert((*program_id, *mapping_name, index), *transition_id)?;
+                self.mapping_transitions_count_map().insert((*program_id, *mapping_name), index + 1)?;
+            }
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Removes the given transaction IDs from the secondary indexes.
+    /// Note: The blocks are removed in reverse order, so the entries of the given transactions are the latest entries
+    /// of each index, and are removed from the end.
+    fn remove_indexes(&self, transaction_ids: &[N::TransactionID]) -> Result<()> {
+        // If the `history` feature is disabled, skip the indexes.
+        if !cfg!(feature = "history") {
+            return Ok(());
+        }
+
+        // Retrieve the transactions.
+        let transactions = transaction_ids
+            .iter()
+            .map(|transaction_id| match self.transaction_store().get_transaction(transaction_id)? {
+                Some(transaction) => Ok(transaction),
+                None => bail!("Failed to remove the indexes: missing transaction '{transaction_id}'"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Collect the index keys of the transactions.
+        let mut program_ids = IndexSet::new();
+        let mut function_keys = IndexSet::new();
+        let mut mapping_keys = IndexSet::new();
+        let mut transition_ids = IndexSet::new();
+        for transaction in &transactions {
+            if let Transaction::Deploy(_, deployment, _) = transaction {
+                program_ids.insert(*deployment.program_id());
+            }
+            for transition in transaction.transitions() {
+                let program_id = *transition.program_id();
+                program_ids.insert(program_id);
+                function_keys.insert((program_id, *transition.function_name()));
+                transition_ids.insert(*transition.id());
+                // Note: Every mapping of the program is checked, as only the touched mappings contain the transition.
+                if let Some(program) = self.transaction_store().get_program(&program_id)? {
+                    mapping_keys.extend(program.mappings().keys().map(|mapping_name| (program_id, *mapping_name)));
+                }
+            }
+        }
+
+        // Remove the transactions from the end of the index of each program.
+        for program_id in program_ids {
+            let mut count = match self.program_transactions_count_map().get_speculative(&program_id)? {
+                Some(count) => cow_to_copied!(count),
+                None => continue,
+            };
+            while count > 0 {
+                match self.program_transactions_map().get_speculative(&(program_id, count - 1))? {
+                    Some(transaction_id) if transaction_ids.contains(&*transaction_id) => {
+                        self.program_transactions_map().remove(&(program_id, count - 1))?
+                    }
+                    _ => break,
+                }
+                count -= 1;
+            }
+            match count {
+                0 => self.program_transactions_count_map().remove(&program_id)?,
+                _ => self.program_transactions_count_map().insert(program_id, count)?,
+            }
+        }
+        // Remove the transactions from the end of the index of each function.
+        for (program_id, function_name) in function_keys {
+            let mut count =
+                match self.function_transactions_count_map().get_speculative(&(program_id, function_name))? {
+                    Some(count) => cow_to_copied!(count),
+                    None => continue,
+                };
+            while count > 0 {
+                match self.function_transactions_map().get_speculative(&(program_id, function_name, count - 1))? {
+                    Some(transaction_id) if transaction_ids.contains(&*transaction_id) => {
+                        self.function_transactions_map().remove(&(program_id, function_name, count - 1))?
+                    }
+                    _ => break,
+                }
+                count -= 1;
+            }
+            match count {
+                0 => self.function_transactions_count_map().remove(&(program_id, function_name))?,
+                _ => self.function_transactions_count_map().insert((program_id, function_name), count)?,
+            }
+        }
+        // Remove the transitions from the end of the index of each mapping.
+        for (program_id, mapping_name) in mapping_keys {
+            let mut count = match self.mapping_transitions_count_map().get_speculative(&(program_id, mapping_name))? {
+                Some(count) => cow_to_copied!(count),
+                None => continue,
+            };
+            while count > 0 {
+                match self.mapping_transitions_map().get_speculative(&(program_id, mapping_name, count - 1))? {
+                    Some(transition_id) if transition_ids.contains(&*transition_id) => {
+                        self.mapping_transitions_map().remove(&(program_id, mapping_name, count - 1))?
+                    }
+                    _ => break,
+                }
+                count -= 1;
+            }
+            match count {
+                0 => self.mapping_transitions_count_map().remove(&(program_id, mapping_name))?,
+                _ => self.mapping_transitions_count_map().insert((program_id, mapping_name), count)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores the coinbase reward of each prover for the given `block hash`, and adds it to the total reward of each prover.
+    /// Note: The prover rewards are a record of the coinbase, which is credited in `credits.aleo/account`.
+    fn insert_prover_rewards(&self, block_hash: &N::BlockHash, rewards: IndexMap<Address<N>, u64>) -> Result<()> {
+        // Ensure the block exists.
+        if !self.reverse_id_map().contains_key_speculative(block_hash)? {
+            bail!("Failed to insert the prover rewards: block '{block_hash}' does not exist");
+        }
+        // Compute the updated total reward of each prover.
+        let totals = rewards
+            .iter()
+            .map(|(address, reward)| {
+                let total = match self.prover_total_rewards_map().get_speculative(address)? {
+                    Some(total) => cow_to_copied!(total),
+                    None => 0,
+                };
+                match total.checked_add(*reward) {
+                    Some(total) => Ok((*address, total)),
+                    None => bail!("Failed to insert the prover rewards: prover '{address}' total reward overflowed"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        atomic_write_batch!(self, {
+            // Store the prover rewards.
+            self.prover_rewards_map().insert(*block_hash, rewards)?;
+            // Store the total reward of each prover.
+            for (address, total) in totals {
+                self.prover_total_rewards_map().insert(address, total)?;
+            }
             Ok(())
         });
 
@@ -476,6 +777,84 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the coinbase reward of each prover for the given `block hash`.
+    fn get_prover_rewards(&self, block_hash: &N::BlockHash) -> Result<Option<IndexMap<Address<N>, u64>>> {
+        match self.prover_rewards_map().get(block_hash)? {
+            Some(rewards) => Ok(Some(cow_to_cloned!(rewards))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the total coinbase reward of the given prover, across all blocks.
+    fn get_total_prover_reward(&self, address: &Address<N>) -> Result<u64> {
+        match self.prover_total_rewards_map().get(address)? {
+            Some(total) => Ok(cow_to_copied!(total)),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns the IDs of the transactions that deployed or executed the given `program ID`.
+    fn get_program_transactions(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransactionID>> {
+        // Ensure the `history` feature is enabled.
+        ensure!(cfg!(feature = "history"), "The program transactions are only indexed with the 'history' feature");
+        // Retrieve the number of program transactions.
+        let count = match self.program_transactions_count_map().get(program_id)? {
+            Some(count) => cow_to_copied!(count),
+            None => return Ok(vec![]),
+        };
+        // Retrieve the program transactions.
+        (0..count)
+            .map(|index| match self.program_transactions_map().get(&(*program_id, index))? {
+                Some(transaction_id) => Ok(cow_to_copied!(transaction_id)),
+                None => bail!("Missing transaction {index} of program '{program_id}'"),
+            })
+            .collect()
+    }
+
+    /// Returns the IDs of the transactions that executed the given `(program ID, function name)`.
+    fn get_function_transactions(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<N::TransactionID>> {
+        // Ensure the `history` feature is enabled.
+        ensure!(cfg!(feature = "history"), "The function transactions are only indexed with the 'history' feature");
+        // Retrieve the number of function transactions.
+        let count = match self.function_transactions_count_map().get(&(*program_id, *function_name))? {
+            Some(count) => cow_to_copied!(count),
+            None => return Ok(vec![]),
+        };
+        // Retrieve the function transactions.
+        (0..count)
+            .map(|index| match self.function_transactions_map().get(&(*program_id, *function_name, index))? {
+                Some(transaction_id) => Ok(cow_to_copied!(transaction_id)),
+                None => bail!("Missing transaction {index} of function '{program_id}/{function_name}'"),
+            })
+            .collect()
+    }
+
+    /// Returns the IDs of the accepted transitions whose finalize scope touched the given `(program ID, mapping name)`.
+    fn get_mapping_transitions(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Vec<N::TransitionID>> {
+        // Ensure the `history` feature is enabled.
+        ensure!(cfg!(feature = "history"), "The mapping transitions are only indexed with the 'history' feature");
+        // Retrieve the number of mapping transitions.
+        let count = match self.mapping_transitions_count_map().get(&(*program_id, *mapping_name))? {
+            Some(count) => cow_to_copied!(count),
+            None => return Ok(vec![]),
+        };
+        // Retrieve the mapping transitions.
+        (0..count)
+            .map(|index| match self.mapping_transitions_map().get(&(*program_id, *mapping_name, index))? {
+                Some(transition_id) => Ok(cow_to_copied!(transition_id)),
+                None => bail!("Missing transition {index} of mapping '{program_id}/{mapping_name}'"),
+            })
+            .collect()
+    }
+
     /// Returns the block for the given `block hash`.
     fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         // Retrieve the block height.
@@ -545,6 +924,22 @@ pub struct BlockMemory<N: Network> {
     coinbase_puzzle_commitment_map: MemoryMap<PuzzleCommitment<N>, N::BlockHash>,
     /// The signature map.
     signature_map: MemoryMap<N::BlockHash, Signature<N>>,
+    /// The prover rewards map.
+    prover_rewards_map: MemoryMap<N::BlockHash, IndexMap<Address<N>, u64>>,
+    /// The prover total rewards map.
+    prover_total_rewards_map: MemoryMap<Address<N>, u64>,
+    /// The program transactions map.
+    program_transactions_map: MemoryMap<(ProgramID<N>, u32), N::TransactionID>,
+    /// The program transactions count map.
+    program_transactions_count_map: MemoryMap<ProgramID<N>, u32>,
+    /// The function transactions map.
+    function_transactions_map: MemoryMap<(ProgramID<N>, Identifier<N>, u32), N::TransactionID>,
+    /// The function transactions count map.
+    function_transactions_count_map: MemoryMap<(ProgramID<N>, Identifier<N>), u32>,
+    /// The mapping transitions map.
+    mapping_transitions_map: MemoryMap<(ProgramID<N>, Identifier<N>, u32), N::TransitionID>,
+    /// The mapping transitions count map.
+    mapping_transitions_count_map: MemoryMap<(ProgramID<N>, Identifier<N>), u32>,
 }
 
 #[rustfmt::skip]
@@ -561,6 +956,14 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type CoinbaseSolutionMap = MemoryMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type CoinbasePuzzleCommitmentMap = MemoryMap<PuzzleCommitment<N>, N::BlockHash>;
     type SignatureMap = MemoryMap<N::BlockHash, Signature<N>>;
+    type ProverRewardsMap = MemoryMap<N::BlockHash, IndexMap<Address<N>, u64>>;
+    type ProverTotalRewardsMap = MemoryMap<Address<N>, u64>;
+    type ProgramTransactionsMap = MemoryMap<(ProgramID<N>, u32), N::TransactionID>;
+    type ProgramTransactionsCountMap = MemoryMap<ProgramID<N>, u32>;
+    type FunctionTransactionsMap = MemoryMap<(ProgramID<N>, Identifier<N>, u32), N::TransactionID>;
+    type FunctionTransactionsCountMap = MemoryMap<(ProgramID<N>, Identifier<N>), u32>;
+    type MappingTransitionsMap = MemoryMap<(ProgramID<N>, Identifier<N>, u32), N::TransitionID>;
+    type MappingTransitionsCountMap = MemoryMap<(ProgramID<N>, Identifier<N>), u32>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -581,6 +984,14 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             coinbase_solution_map: MemoryMap::default(),
             coinbase_puzzle_commitment_map: MemoryMap::default(),
             signature_map: MemoryMap::default(),
+            prover_rewards_map: MemoryMap::default(),
+            prover_total_rewards_map: MemoryMap::default(),
+            program_transactions_map: MemoryMap::default(),
+            program_transactions_count_map: MemoryMap::default(),
+            function_transactions_map: MemoryMap::default(),
+            function_transactions_count_map: MemoryMap::default(),
+            mapping_transitions_map: MemoryMap::default(),
+            mapping_transitions_count_map: MemoryMap::default(),
         })
     }
 
@@ -638,6 +1049,46 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     fn signature_map(&self) -> &Self::SignatureMap {
         &self.signature_map
     }
+
+    /// Returns the prover rewards map.
+    fn prover_rewards_map(&self) -> &Self::ProverRewardsMap {
+        &self.prover_rewards_map
+    }
+
+    /// Returns the prover total rewards map.
+    fn prover_total_rewards_map(&self) -> &Self::ProverTotalRewardsMap {
+        &self.prover_total_rewards_map
+    }
+
+    /// Returns the program transactions map.
+    fn program_transactions_map(&self) -> &Self::ProgramTransactionsMap {
+        &self.program_transactions_map
+    }
+
+    /// Returns the program transactions count map.
+    fn program_transactions_count_map(&self) -> &Self::ProgramTransactionsCountMap {
+        &self.program_transactions_count_map
+    }
+
+    /// Returns the function transactions map.
+    fn function_transactions_map(&self) -> &Self::FunctionTransactionsMap {
+        &self.function_transactions_map
+    }
+
+    /// Returns the function transactions count map.
+    fn function_transactions_count_map(&self) -> &Self::FunctionTransactionsCountMap {
+        &self.function_transactions_count_map
+    }
+
+    /// Returns the mapping transitions map.
+    fn mapping_transitions_map(&self) -> &Self::MappingTransitionsMap {
+        &self.mapping_transitions_map
+    }
+
+    /// Returns the mapping transitions count map.
+    fn mapping_transitions_count_map(&self) -> &Self::MappingTransitionsCountMap {
+        &self.mapping_transitions_count_map
+    }
 }
 
 /// The block store.
@@ -687,6 +1138,20 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
+        // Ensure the block timestamp is after the timestamp of the previous block.
+        if let Some(previous_height) = block.height().checked_sub(1) {
+            let previous_hash = match self.get_block_hash(previous_height)? {
+                Some(previous_hash) => previous_hash,
+                None => bail!("Missing block hash for block {previous_height}"),
+            };
+            let previous_header = match self.get_block_header(&previous_hash)? {
+                Some(previous_header) => previous_header,
+                None => bail!("Missing block header for block {previous_height}"),
+            };
+            if block.timestamp() <= previous_header.timestamp() {
+                bail!("Attempted to insert a block with a non-increasing timestamp into storage")
+            }
+        }
         // Insert the (state root, block height) pair.
         self.storage.insert((*updated_tree.root()).into(), block)?;
         // Update the block tree.
@@ -731,7 +1196,8 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         // Prepare an updated Merkle tree removing the last 'n' block hashes.
         let updated_tree = tree.prepare_remove_last_n(usize::try_from(n)?)?;
 
-        atomic_write_batch!(self, {
+        // Note: The batch is on the storage, as aborting the block store would acquire the lock on the block tree.
+        atomic_write_batch!(self.storage, {
             // Remove the blocks, in descending order.
             for block_hash in hashes.iter().rev() {
                 self.storage.remove(block_hash)?;
@@ -745,6 +1211,20 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Stores the coinbase reward of each prover for the given `block hash`.
+    /// Note: The prover rewards are a record of the coinbase, which is credited in `credits.aleo/account`.
+    pub fn insert_prover_rewards(&self, block_hash: &N::BlockHash, rewards: IndexMap<Address<N>, u64>) -> Result<()> {
+        self.storage.insert_prover_rewards(block_hash, rewards)
+    }
+
+    /// Appends the given transitions to the index of each `(program ID, mapping name)` touched by their finalize scope.
+    pub fn insert_mapping_transitions(
+        &self,
+        mapping_transitions: &[(ProgramID<N>, Identifier<N>, N::TransitionID)],
+    ) -> Result<()> {
+        self.storage.insert_mapping_transitions(mapping_transitions)
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -768,6 +1248,22 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+
+        // Revert the block tree to the blocks in storage, as the aborted batch may have inserted blocks.
+        let mut tree = self.tree.write();
+        let mut num_aborted = 0;
+        while let Some(height) = tree.number_of_leaves().checked_sub(num_aborted + 1) {
+            match u32::try_from(height).map(|height| self.storage.id_map().contains_key(&height)) {
+                Ok(Ok(false)) => num_aborted += 1,
+                _ => break,
+            }
+        }
+        if num_aborted > 0 {
+            match tree.prepare_remove_last_n(num_aborted) {
+                Ok(updated_tree) => *tree = updated_tree,
+                Err(error) => error!("Failed to revert the block tree: {error}"),
+            }
+        }
     }
 
     /// Finishes an atomic batch write operation.
@@ -807,6 +1303,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the height of the latest block, or `None` if there are no blocks.
+    pub fn current_block_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -852,6 +1353,71 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block_signature(block_hash)
     }
 
+    /// Returns the coinbase reward of each prover for the given `block hash`.
+    pub fn get_prover_rewards(&self, block_hash: &N::BlockHash) -> Result<Option<IndexMap<Address<N>, u64>>> {
+        self.storage.get_prover_rewards(block_hash)
+    }
+
+    /// Returns the total coinbase reward of the given prover, across all blocks.
+    pub fn get_total_prover_reward(&self, address: &Address<N>) -> Result<u64> {
+        self.storage.get_total_prover_reward(address)
+    }
+
+    /// Returns the IDs of the transactions that deployed or executed the given `program ID`, in ledger order.
+    pub fn get_program_transactions(&self, program_id: &ProgramID<N>) -> Result<Vec<N::TransactionID>> {
+        self.storage.get_program_transactions(program_id)
+    }
+
+    /// Returns the IDs of the transactions that executed the given `(program ID, function name)`, in ledger order.
+    pub fn get_function_transactions(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+    ) -> Result<Vec<N::TransactionID>> {
+        self.storage.get_function_transactions(program_id, function_name)
+    }
+
+    /// Returns the IDs of the accepted transitions whose finalize scope touched the given `(program ID, mapping name)`,
+    /// in ledger order.
+    pub fn get_mapping_transitions(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Vec<N::TransitionID>> {
+        self.storage.get_mapping_transitions(program_id, mapping_name)
+    }
+
+    /// Returns the heights of the blocks with a timestamp in the given `timestamps` range.
+    /// Note: The block timestamps strictly increase with the block height, as this is enforced when a block
+    /// is inserted, so the blocks are found by binary search.
+    pub fn get_block_heights_in_timestamp_range(&self, timestamps: Range<i64>) -> Result<Range<u32>> {
+        // Returns the first block height with a timestamp that is at least the given timestamp.
+        let partition_point = |timestamp: i64| -> Result<u32> {
+            let (mut low, mut high) = (0u32, self.current_block_height().map_or(0, |height| height + 1));
+            while low < high {
+                let middle = low + (high - low) / 2;
+                let block_hash = match self.get_block_hash(middle)? {
+                    Some(block_hash) => block_hash,
+                    None => bail!("Missing block hash for block {middle}"),
+                };
+                let header = match self.get_block_header(&block_hash)? {
+                    Some(header) => header,
+                    None => bail!("Missing block header for block {middle}"),
+                };
+                match header.timestamp() < timestamp {
+                    true => low = middle + 1,
+                    false => high = middle,
+                }
+            }
+            Ok(low)
+        };
+        // Return an empty range if the timestamps range is empty.
+        if timestamps.start >= timestamps.end {
+            return Ok(0..0);
+        }
+        Ok(partition_point(timestamps.start)?..partition_point(timestamps.end)?)
+    }
+
     /// Returns the block for the given `block hash`.
     pub fn get_block(&self, block_hash: &N::BlockHash) -> Result<Option<Block<N>>> {
         self.storage.get_block(block_hash)
@@ -861,6 +1427,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
     }
+
+    /// Returns the finalize status for the given `transaction ID`.
+    pub fn get_finalize_status(&self, transaction_id: &N::TransactionID) -> Result<Option<FinalizeStatus>> {
+        self.storage.transaction_store().get_finalize_status(transaction_id)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -905,6 +1476,13 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn puzzle_commitments(&self) -> impl '_ + Iterator<Item = Cow<'_, PuzzleCommitment<N>>> {
         self.storage.coinbase_puzzle_commitment_map().keys()
     }
+
+    /// Returns an iterator over the `(block hash, prover rewards)` pairs, for all blocks in `self`.
+    pub fn prover_rewards(
+        &self,
+    ) -> impl '_ + Iterator<Item = (Cow<'_, N::BlockHash>, Cow<'_, IndexMap<Address<N>, u64>>)> {
+        self.storage.prover_rewards_map().iter()
+    }
 }
 
 #[cfg(test)]
@@ -981,4 +1559,178 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    #[cfg(feature = "history")]
+    fn test_secondary_indexes() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let transition = block.transitions().next().unwrap();
+        let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+        let transaction_ids = block.transaction_ids().copied().collect::<Vec<_>>();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Ensure the indexes are empty.
+        assert!(block_store.get_program_transactions(&program_id).unwrap().is_empty());
+        assert!(block_store.get_function_transactions(&program_id, &function_name).unwrap().is_empty());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the transactions are indexed.
+        assert_eq!(transaction_ids, block_store.get_program_transactions(&program_id).unwrap());
+        assert_eq!(transaction_ids, block_store.get_function_transactions(&program_id, &function_name).unwrap());
+
+        // Index a transition for a mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        block_store.insert_mapping_transitions(&[(program_id, mapping_name, *transition.id())]).unwrap();
+        assert_eq!(vec![*transition.id()], block_store.get_mapping_transitions(&program_id, &mapping_name).unwrap());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the indexes are empty.
+        assert!(block_store.get_program_transactions(&program_id).unwrap().is_empty());
+        assert!(block_store.get_function_transactions(&program_id, &function_name).unwrap().is_empty());
+    }
+
+    #[test]
+    #[cfg(not(feature = "history"))]
+    fn test_secondary_indexes_require_history() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let transition = block.transitions().next().unwrap();
+        let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+
+        // Initialize a new block store, and insert the block.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Ensure the indexes are unavailable.
+        assert!(block_store.get_program_transactions(&program_id).is_err());
+        assert!(block_store.get_function_transactions(&program_id, &function_name).is_err());
+    }
+
+    #[test]
+    fn test_find_block_heights_in_timestamp_range() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        assert_eq!(0..0, block_store.get_block_heights_in_timestamp_range(i64::MIN..i64::MAX).unwrap());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the block is found by its timestamp.
+        let timestamp = block.timestamp();
+        assert_eq!(0..1, block_store.get_block_heights_in_timestamp_range(timestamp..timestamp + 1).unwrap());
+        assert_eq!(0..0, block_store.get_block_heights_in_timestamp_range(timestamp + 1..i64::MAX).unwrap());
+        assert_eq!(0..0, block_store.get_block_heights_in_timestamp_range(i64::MIN..timestamp).unwrap());
+    }
+
+    #[test]
+    fn test_insert_requires_increasing_timestamps() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let block_store = vm.block_store();
+
+        // Construct the next block, with the given timestamp.
+        let transactions = [crate::vm::test_helpers::sample_fee_execution_transaction(rng)];
+        let sample_block = |timestamp, rng: &mut TestRng| {
+            crate::vm::test_helpers::sample_block(&vm, &private_key, &transactions, 1, timestamp, rng).unwrap()
+        };
+
+        // Ensure a block that is not after the previous block is rejected.
+        assert!(block_store.insert(&sample_block(genesis.timestamp(), rng)).is_err());
+        assert!(block_store.insert(&sample_block(genesis.timestamp() - 1, rng)).is_err());
+        assert_eq!(Some(0), block_store.current_block_height());
+
+        // Ensure a block that is after the previous block is inserted.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &private_key, &transactions, rng).unwrap();
+        block_store.insert(&block).unwrap();
+        assert_eq!(Some(1), block_store.current_block_height());
+        let range = genesis.timestamp()..block.timestamp() + 1;
+        assert_eq!(0..2, block_store.get_block_heights_in_timestamp_range(range).unwrap());
+    }
+
+    #[test]
+    fn test_insert_get_remove_prover_rewards() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let block_hash = block.hash();
+
+        // Sample the prover rewards.
+        let address = Address::new(Uniform::rand(&mut rng));
+        let rewards = IndexMap::from([(address, 100)]);
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Ensure the prover rewards cannot be inserted for a missing block.
+        assert!(block_store.insert_prover_rewards(&block_hash, rewards.clone()).is_err());
+
+        // Insert the block and the prover rewards.
+        block_store.insert(&block).unwrap();
+        block_store.insert_prover_rewards(&block_hash, rewards.clone()).unwrap();
+
+        // Retrieve the prover rewards.
+        assert_eq!(Some(rewards), block_store.get_prover_rewards(&block_hash).unwrap());
+        assert_eq!(100, block_store.get_total_prover_reward(&address).unwrap());
+
+        // Remove the block.
+        block_store.remove_last_n(1).unwrap();
+
+        // Ensure the prover rewards do not exist.
+        assert_eq!(None, block_store.get_prover_rewards(&block_hash).unwrap());
+        assert_eq!(0, block_store.get_total_prover_reward(&address).unwrap());
+    }
+
+    #[test]
+    fn test_abort_atomic_reverts_block_tree() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let block_hash = block.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+        let state_root = block_store.current_state_root();
+
+        // Insert the block and the prover rewards in an atomic batch, and abort it.
+        let address = Address::new(Uniform::rand(&mut rng));
+        block_store.start_atomic();
+        block_store.insert(&block).unwrap();
+        block_store.insert_prover_rewards(&block_hash, IndexMap::from([(address, 100)])).unwrap();
+        assert_eq!(Some(0), block_store.current_block_height());
+        block_store.abort_atomic();
+
+        // Ensure none of the writes persist, and the block tree is reverted.
+        assert_eq!(None, block_store.current_block_height());
+        assert_eq!(state_root, block_store.current_state_root());
+        assert!(!block_store.contains_block_hash(&block_hash).unwrap());
+        assert_eq!(None, block_store.get_prover_rewards(&block_hash).unwrap());
+        assert_eq!(0, block_store.get_total_prover_reward(&address).unwrap());
+
+        // Ensure the block can be inserted again.
+        block_store.insert(&block).unwrap();
+        assert_eq!(Some(0), block_store.current_block_height());
+        assert!(block_store.contains_block_hash(&block_hash).unwrap());
+    }
 }
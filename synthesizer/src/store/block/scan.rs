@@ -0,0 +1,322 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::{
+    account::{PrivateKey, ViewKey},
+    program::{Ciphertext, Plaintext, Record},
+};
+
+use core::ops::Range;
+
+/// The spent status of a scanned record.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordStatus {
+    /// The record has not been spent.
+    Unspent,
+    /// The record has been spent.
+    Spent,
+    /// The spent status is unknown, as no private key was provided.
+    Unknown,
+}
+
+/// A record owned by the scanning view key, decrypted and annotated with its location in the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// The height of the block containing the record.
+    block_height: u32,
+    /// The spent status of the record.
+    status: RecordStatus,
+}
+
+impl<N: Network> ScannedRecord<N> {
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the height of the block containing the record.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the spent status of the record.
+    pub const fn status(&self) -> RecordStatus {
+        self.status
+    }
+}
+
+/// The result of scanning a range of blocks for records.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordScan<N: Network> {
+    /// The owned records, in ledger order.
+    records: Vec<ScannedRecord<N>>,
+    /// The block height to resume scanning from.
+    cursor: u32,
+}
+
+impl<N: Network> RecordScan<N> {
+    /// Returns the owned records, in ledger order.
+    pub fn records(&self) -> &[ScannedRecord<N>] {
+        &self.records
+    }
+
+    /// Returns the block height to resume scanning from.
+    pub const fn cursor(&self) -> u32 {
+        self.cursor
+    }
+
+    /// Returns the owned records, in ledger order.
+    pub fn into_records(self) -> Vec<ScannedRecord<N>> {
+        self.records
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Scans the blocks in the given `heights` range for the records owned by the given view key,
+    /// and returns the decrypted records along with the block height to resume scanning from.
+    ///
+    /// If a private key is provided, the spent status of each record is determined from its serial number.
+    /// The records output by a rejected transaction are skipped, except for the records of its fee.
+    /// The range is truncated at the latest block, so the returned cursor never skips an unscanned block.
+    pub fn scan_records(
+        &self,
+        view_key: &ViewKey<N>,
+        private_key: Option<&PrivateKey<N>>,
+        heights: Range<u32>,
+    ) -> Result<RecordScan<N>> {
+        // Ensure the private key corresponds to the view key.
+        if let Some(private_key) = private_key {
+            ensure!(ViewKey::try_from(private_key)? == *view_key, "The private key does not match the view key");
+        }
+
+        // Truncate the range at the latest block.
+        let end = match self.current_block_height() {
+            Some(height) => heights.end.min(height.saturating_add(1)),
+            None => heights.start,
+        };
+        // If the range is empty, return without scanning.
+        if heights.start >= end {
+            return Ok(RecordScan { records: vec![], cursor: heights.start.max(end) });
+        }
+
+        // Compute the address x-coordinate once, as it is shared by every ownership check.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+
+        // Scan each block for its owned records.
+        let records = cfg_into_iter!(heights.start..end)
+            .map(|height| {
+                // Retrieve the block.
+                let block = match self.get_block_hash(height)? {
+                    Some(block_hash) => match self.get_block(&block_hash)? {
+                        Some(block) => block,
+                        None => bail!("Missing block '{block_hash}' at height {height}"),
+                    },
+                    None => bail!("Missing block hash for block {height}"),
+                };
+                // Retrieve the records of the block, keeping only the fee records of a rejected transaction.
+                let mut records = Vec::new();
+                for transaction in block.transactions().values() {
+                    match self.get_finalize_status(&transaction.id())? {
+                        Some(FinalizeStatus::Rejected) => {
+                            let fee = match transaction {
+                                Transaction::Deploy(_, _, fee) => Some(fee),
+                                Transaction::Execute(_, _, additional_fee) => additional_fee.as_ref(),
+                            };
+                            records.extend(fee.into_iter().flat_map(|fee| fee.records()));
+                        }
+                        _ => records.extend(transaction.records()),
+                    }
+                }
+                // Decrypt the records owned by the view key.
+                records
+                    .into_iter()
+                    .filter(|(_, record)| record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate))
+                    .map(|(commitment, record)| self.scan_record(view_key, private_key, height, commitment, record))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        Ok(RecordScan { records, cursor: end })
+    }
+
+    /// Decrypts the given owned record, and determines its spent status.
+    fn scan_record(
+        &self,
+        view_key: &ViewKey<N>,
+        private_key: Option<&PrivateKey<N>>,
+        block_height: u32,
+        commitment: &Field<N>,
+        record: &Record<N, Ciphertext<N>>,
+    ) -> Result<ScannedRecord<N>> {
+        // Decrypt the record.
+        let record = record.decrypt(view_key)?;
+        // Determine the spent status of the record.
+        let status = match private_key {
+            Some(private_key) => {
+                let serial_number = Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment)?;
+                match self.transition_store().contains_serial_number(&serial_number)? {
+                    true => RecordStatus::Spent,
+                    false => RecordStatus::Unspent,
+                }
+            }
+            None => RecordStatus::Unknown,
+        };
+        Ok(ScannedRecord { commitment: *commitment, record, block_height, status })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{sample_genesis_block, sample_genesis_private_key, CurrentNetwork};
+
+    #[test]
+    fn test_scan_records() {
+        let mut rng = TestRng::default();
+
+        // Sample the genesis block and its owner.
+        let block = sample_genesis_block(&mut rng);
+        let private_key = sample_genesis_private_key(&mut rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        // Ensure an empty block store yields no records.
+        let scan = block_store.scan_records(&view_key, Some(&private_key), 0..10).unwrap();
+        assert!(scan.records().is_empty());
+        assert_eq!(0, scan.cursor());
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Scan the block store, and ensure the cursor stops at the latest block.
+        let scan = block_store.scan_records(&view_key, Some(&private_key), 0..10).unwrap();
+        assert_eq!(1, scan.cursor());
+        assert_eq!(block.records().count(), scan.records().len());
+        for (scanned, (commitment, _)) in scan.records().iter().zip(block.records()) {
+            assert_eq!(commitment, scanned.commitment());
+            assert_eq!(0, scanned.block_height());
+            assert_eq!(RecordStatus::Unspent, scanned.status());
+        }
+
+        // Ensure the spent status is unknown without a private key.
+        let scan = block_store.scan_records(&view_key, None, 0..10).unwrap();
+        assert!(scan.records().iter().all(|scanned| scanned.status() == RecordStatus::Unknown));
+
+        // Ensure resuming from the cursor yields no records.
+        let scan = block_store.scan_records(&view_key, Some(&private_key), scan.cursor()..10).unwrap();
+        assert!(scan.records().is_empty());
+        assert_eq!(1, scan.cursor());
+
+        // Ensure another view key does not own the records.
+        let other_view_key = ViewKey::try_from(&PrivateKey::new(&mut rng).unwrap()).unwrap();
+        assert!(block_store.scan_records(&other_view_key, None, 0..10).unwrap().records().is_empty());
+
+        // Ensure a mismatched private key is rejected.
+        assert!(block_store.scan_records(&other_view_key, Some(&private_key), 0..10).is_err());
+    }
+
+    #[test]
+    fn test_scan_records_after_spend() {
+        let rng = &mut TestRng::default();
+
+        // Sample the owner of the genesis block.
+        let private_key = sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Initialize the VM with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Ensure the genesis record is unspent.
+        let scan = vm.block_store().scan_records(&view_key, Some(&private_key), 0..10).unwrap();
+        assert_eq!(1, scan.records().len());
+        let genesis_commitment = *scan.records()[0].commitment();
+        assert_eq!(RecordStatus::Unspent, scan.records()[0].status());
+
+        // Sample a transaction that spends the genesis record.
+        let transaction = crate::vm::test_helpers::sample_fee_execution_transaction(rng);
+        assert!(transaction.serial_numbers().next().is_some());
+
+        // Construct the next block, containing the transaction.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &private_key, &[transaction], rng).unwrap();
+
+        // Add the block.
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the genesis record is now spent, and the new record is unspent.
+        let scan = vm.block_store().scan_records(&view_key, Some(&private_key), 0..10).unwrap();
+        assert_eq!(2, scan.cursor());
+        for scanned in scan.records() {
+            match scanned.block_height() {
+                0 => {
+                    assert_eq!(&genesis_commitment, scanned.commitment());
+                    assert_eq!(RecordStatus::Spent, scanned.status());
+                }
+                _ => assert_eq!(RecordStatus::Unspent, scanned.status()),
+            }
+        }
+        assert!(scan.records().iter().any(|scanned| scanned.block_height() == 1));
+    }
+
+    #[test]
+    fn test_scan_records_skips_rejected_transactions() {
+        let rng = &mut TestRng::default();
+
+        // Sample the owner of the genesis block.
+        let private_key = sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Initialize the VM with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let block_store = vm.block_store();
+
+        // Sample an execution that mints a record to the owner, and pays its fee with the genesis record.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_fee(rng);
+        let fee = match &transaction {
+            Transaction::Execute(_, _, Some(fee)) => fee.clone(),
+            _ => panic!("Expected an execution with an additional fee"),
+        };
+
+        // Construct the next block, containing the transaction.
+        let transactions = std::slice::from_ref(&transaction);
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &private_key, transactions, rng).unwrap();
+
+        // Insert the block, and reject the transaction.
+        block_store.insert(&block).unwrap();
+        block_store.transaction_store().insert_finalize_status(&transaction, FinalizeStatus::Rejected).unwrap();
+
+        // Ensure only the fee records of the rejected transaction are scanned.
+        let scan = block_store.scan_records(&view_key, Some(&private_key), 1..2).unwrap();
+        let commitments = scan.records().iter().map(|scanned| *scanned.commitment()).collect::<Vec<_>>();
+        assert!(!commitments.is_empty());
+        assert_eq!(commitments, fee.records().map(|(commitment, _)| *commitment).collect::<Vec<_>>());
+        assert!(scan.records().iter().all(|scanned| scanned.status() == RecordStatus::Unspent));
+    }
+}
@@ -14,24 +14,32 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::store::{
-    BlockMemory,
-    BlockStorage,
-    BlockStore,
-    ProgramMemory,
-    ProgramStorage,
-    ProgramStore,
-    TransactionMemory,
-    TransactionStorage,
-    TransactionStore,
-    TransitionMemory,
-    TransitionStorage,
-    TransitionStore,
+use crate::{
+    atomic_write_batch,
+    store::{
+        BlockMemory,
+        BlockStorage,
+        BlockStore,
+        ProgramMemory,
+        ProgramStorage,
+        ProgramStore,
+        TransactionMemory,
+        TransactionStorage,
+        TransactionStore,
+        TransitionMemory,
+        TransitionStorage,
+        TransitionStore,
+    },
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, Plaintext, ProgramID, Value},
+    types::{Address, U64},
 };
-use console::network::prelude::*;
 
 use anyhow::Result;
 use core::marker::PhantomData;
+use indexmap::IndexMap;
 
 /// A trait for consensus storage.
 pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
@@ -173,6 +181,89 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.transition_store()
     }
 
+    /// Removes the last 'n' blocks from storage, along with the finalize outputs of their transitions.
+    /// The coinbase rewards of the blocks are debited from the `account` mapping of `credits.aleo`.
+    pub fn remove_last_n(&self, n: u32) -> Result<()> {
+        // Ensure 'n' is non-zero.
+        ensure!(n > 0, "Cannot remove zero blocks");
+        // Determine the block heights to remove.
+        let end_height = match self.block_store().current_block_height() {
+            Some(height) => height,
+            None => bail!("Failed to remove last '{n}' blocks: no blocks in storage"),
+        };
+        let start_height = end_height
+            .checked_sub(n - 1)
+            .ok_or_else(|| anyhow!("Failed to remove last '{n}' blocks: block height underflow"))?;
+
+        // Fetch the transition IDs and the total coinbase reward of each prover, for the blocks to remove.
+        let mut transition_ids = Vec::new();
+        let mut rewards = IndexMap::<Address<N>, u64>::new();
+        for height in start_height..=end_height {
+            let block_hash = match self.block_store().get_block_hash(height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Failed to remove last '{n}' blocks: missing block hash for block {height}"),
+            };
+            match self.block_store().get_block_transactions(&block_hash)? {
+                Some(transactions) => transition_ids.extend(transactions.transition_ids().copied()),
+                None => bail!("Failed to remove last '{n}' blocks: missing transactions for block {height}"),
+            }
+            for (address, reward) in self.block_store().get_prover_rewards(&block_hash)?.unwrap_or_default() {
+                let total = rewards.entry(address).or_default();
+                *total = total.checked_add(reward).ok_or_else(|| anyhow!("The rewards of '{address}' overflow"))?;
+            }
+        }
+
+        atomic_write_batch!(self, {
+            // Remove the finalize outputs of the transitions.
+            for transition_id in transition_ids.iter() {
+                self.program_store().remove_finalize_outputs(transition_id)?;
+            }
+            // Debit the coinbase rewards from the account of each prover.
+            self.debit_prover_rewards(&rewards)?;
+            // Remove the blocks.
+            self.block_store().remove_last_n(n)?;
+            Ok(())
+        });
+        Ok(())
+    }
+
+    /// Debits the given coinbase reward of each prover from its account, in the `account` mapping of `credits.aleo`.
+    /// If the balance of a prover becomes zero, its account is removed.
+    ///
+    /// Note: The finalize writes of the removed blocks are not reverted, so a prover may have already moved part
+    /// of its coinbase reward. In that case, the balance of the prover is debited to zero.
+    fn debit_prover_rewards(&self, rewards: &IndexMap<Address<N>, u64>) -> Result<()> {
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str("account")?;
+        for (address, reward) in rewards {
+            let key = Plaintext::from(Literal::Address(*address));
+            // Retrieve the current balance of the prover.
+            let balance = match self.program_store().get_value(&program_id, &mapping_name, &key)? {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                Some(value) => bail!("Invalid balance '{value}' for '{address}' in 'credits.aleo/account'"),
+                None => 0,
+            };
+            // Compute the new balance of the prover.
+            let new_balance = match balance.checked_sub(*reward) {
+                Some(new_balance) => new_balance,
+                None => {
+                    warn!("The balance of '{address}' ({balance}) is less than its coinbase reward ({reward})");
+                    0
+                }
+            };
+            match new_balance {
+                // Note: If the balance is already zero, there is nothing to debit.
+                0 if balance == 0 => (),
+                0 => self.program_store().remove_key_value(&program_id, &mapping_name, &key)?,
+                _ => {
+                    let value = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(new_balance))));
+                    self.program_store().update_key_value(&program_id, &mapping_name, key, value)?
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -198,3 +289,36 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.dev()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::program::Value;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_remove_last_n_removes_finalize_outputs() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let transition_id = *block.transition_ids().next().unwrap();
+
+        // Initialize a new consensus store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+
+        // Insert the block, and the finalize outputs of one of its transitions.
+        store.block_store().insert(&block).unwrap();
+        let outputs = vec![Value::from_str("1u64").unwrap()];
+        store.program_store().insert_finalize_outputs(&transition_id, outputs.clone()).unwrap();
+        assert_eq!(store.program_store().get_finalize_outputs(&transition_id).unwrap(), Some(outputs));
+
+        // Remove the block.
+        store.remove_last_n(1).unwrap();
+
+        // Ensure the block and the finalize outputs were removed.
+        assert_eq!(store.block_store().get_block(&block.hash()).unwrap(), None);
+        assert_eq!(store.program_store().get_finalize_outputs(&transition_id).unwrap(), None);
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::{borrow::Borrow, hash::Hash};
+use indexmap::IndexMap;
+
+/// The pending operations of an atomic batch write, in the order they were queued.
+///
+/// The batch is indexed by key, so the latest operation of a key is found in constant time,
+/// while the queue order is kept, so the batch can be rewound to a checkpoint.
+pub struct AtomicBatch<K: Copy + Eq + Hash, V> {
+    /// The queued operations, as `(key, value, index of the previous operation on the key)`.
+    operations: Vec<(K, Option<V>, Option<usize>)>,
+    /// The index of the latest queued operation of each key.
+    latest: IndexMap<K, usize>,
+}
+
+impl<K: Copy + Eq + Hash, V> Default for AtomicBatch<K, V> {
+    fn default() -> Self {
+        Self { operations: Default::default(), latest: Default::default() }
+    }
+}
+
+impl<K: Copy + Eq + Hash, V> AtomicBatch<K, V> {
+    /// Returns the number of queued operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if there are no queued operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Queues the given operation, where `None` removes the key.
+    pub fn push(&mut self, key: K, value: Option<V>) {
+        let previous = self.latest.insert(key, self.operations.len());
+        self.operations.push((key, value, previous));
+    }
+
+    /// Returns the latest queued operation for the given key, if any.
+    pub fn get<Q>(&self, key: &Q) -> Option<&Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.latest.get(key).map(|index| &self.operations[*index].1)
+    }
+
+    /// Discards the operations queued after the first `len` operations.
+    pub fn truncate(&mut self, len: usize) {
+        while self.operations.len() > len {
+            if let Some((key, _, previous)) = self.operations.pop() {
+                // Restore the previous operation of the key, if any.
+                match previous {
+                    Some(previous) => self.latest.insert(key, previous),
+                    None => self.latest.swap_remove(&key),
+                };
+            }
+        }
+    }
+
+    /// Returns the queued operations, in the order they were queued.
+    pub fn into_operations(self) -> impl Iterator<Item = (K, Option<V>)> {
+        self.operations.into_iter().map(|(key, value, _)| (key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_and_truncate() {
+        let mut batch = AtomicBatch::<usize, &str>::default();
+        assert!(batch.is_empty());
+
+        batch.push(0, Some("a"));
+        batch.push(1, Some("b"));
+        batch.push(0, None);
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch.get(&0), Some(&None));
+        assert_eq!(batch.get(&1), Some(&Some("b")));
+        assert_eq!(batch.get(&2), None);
+
+        // Rewinding restores the previous operation of each key.
+        batch.truncate(1);
+        assert_eq!(batch.get(&0), Some(&Some("a")));
+        assert_eq!(batch.get(&1), None);
+
+        batch.truncate(0);
+        assert_eq!(batch.get(&0), None);
+        assert!(batch.into_operations().next().is_none());
+    }
+}
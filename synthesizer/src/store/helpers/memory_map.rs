@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::store::helpers::{Map, MapRead};
+use crate::store::helpers::{AtomicBatch, Map, MapRead};
 use console::network::prelude::*;
 use indexmap::IndexMap;
 
@@ -36,7 +36,8 @@ pub struct MemoryMap<
 > {
     map: Arc<RwLock<IndexMap<K, V>>>,
     batch_in_progress: Arc<AtomicBool>,
-    atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+    atomic_batch: Arc<Mutex<AtomicBatch<K, V>>>,
+    checkpoints: Arc<Mutex<Vec<usize>>>,
 }
 
 impl<
@@ -45,7 +46,12 @@ impl<
 > Default for MemoryMap<K, V>
 {
     fn default() -> Self {
-        Self { map: Default::default(), batch_in_progress: Default::default(), atomic_batch: Default::default() }
+        Self {
+            map: Default::default(),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        }
     }
 }
 
@@ -60,6 +66,7 @@ impl<
             map: Arc::new(RwLock::new(IndexMap::from_iter(iter))),
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
+            checkpoints: Default::default(),
         }
     }
 }
@@ -80,7 +87,7 @@ impl<
         match is_batch {
             // If a batch is in progress, add the key-value pair to the batch.
             true => {
-                self.atomic_batch.lock().insert(key, Some(value));
+                self.atomic_batch.lock().push(key, Some(value));
             }
             // Otherwise, insert the key-value pair directly into the map.
             false => {
@@ -100,7 +107,7 @@ impl<
         match is_batch {
             // If a batch is in progress, add the key-None pair to the batch.
             true => {
-                self.atomic_batch.lock().insert(*key, None);
+                self.atomic_batch.lock().push(*key, None);
             }
             // Otherwise, remove the key-value pair directly from the map.
             false => {
@@ -136,22 +143,53 @@ impl<
     fn abort_atomic(&self) {
         // Clear the atomic batch.
         *self.atomic_batch.lock() = Default::default();
+        // Clear the checkpoints.
+        self.checkpoints.lock().clear();
         // Set the atomic batch flag to `false`.
         self.batch_in_progress.store(false, Ordering::SeqCst);
     }
 
+    ///
+    /// Saves the current list of pending operations, so that `atomic_rewind` can later
+    /// discard any operations queued after this point.
+    ///
+    fn atomic_checkpoint(&self) {
+        // Push the current length of the atomic batch as a checkpoint.
+        let checkpoint = self.atomic_batch.lock().len();
+        self.checkpoints.lock().push(checkpoint);
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint, keeping all of the pending operations.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        self.checkpoints.lock().pop();
+    }
+
+    ///
+    /// Removes all of the pending operations queued after the latest atomic checkpoint.
+    ///
+    fn atomic_rewind(&self) {
+        // Retrieve the latest checkpoint, or rewind the entire atomic batch if there is none.
+        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+        // Discard the operations queued after the checkpoint.
+        self.atomic_batch.lock().truncate(checkpoint);
+    }
+
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
     fn finish_atomic(&self) -> Result<()> {
         // Retrieve the atomic batch.
         let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        // Clear the checkpoints.
+        self.checkpoints.lock().clear();
 
         if !operations.is_empty() {
             // Acquire a write lock on the map.
             let mut locked_map = self.map.write();
             // Perform all the queued operations.
-            for operation in operations {
+            for operation in operations.into_operations() {
                 match operation {
                     (key, Some(value)) => locked_map.insert(key, value),
                     (key, None) => locked_map.remove(&key),
@@ -212,7 +250,11 @@ impl<
         Q: PartialEq + Eq + Hash + Serialize + ?Sized,
     {
         // Return early if there is no atomic batch in progress.
-        if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
+        match self.batch_in_progress.load(Ordering::SeqCst) {
+            // Note: The latest queued operation on the key takes precedence.
+            true => self.atomic_batch.lock().get(key).cloned(),
+            false => None,
+        }
     }
 
     ///
@@ -379,6 +421,46 @@ mod tests {
         assert!(map.iter().next().is_none());
     }
 
+    #[test]
+    fn test_atomic_checkpoint_and_rewind() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = Default::default();
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Insert an item into the map, and save a checkpoint.
+        map.insert(0, "0".to_string()).unwrap();
+        map.atomic_checkpoint();
+
+        // Update the item, and insert another item.
+        map.insert(0, "1".to_string()).unwrap();
+        map.insert(1, "1".to_string()).unwrap();
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("1".to_string())));
+        assert_eq!(map.get_speculative(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+
+        // Rewind to the checkpoint.
+        map.atomic_rewind();
+
+        // Check that only the operations queued before the checkpoint remain.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert_eq!(map.get_batched(&1), None);
+
+        // Save a checkpoint, remove the item, and clear the checkpoint.
+        map.atomic_checkpoint();
+        map.remove(&0).unwrap();
+        map.clear_latest_checkpoint();
+
+        // Check that the removal is kept.
+        assert_eq!(map.get_batched(&0), Some(None));
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the map is empty now.
+        assert!(map.iter().next().is_none());
+    }
+
     #[test]
     fn test_atomic_writes_are_batched() {
         // The number of items that will be inserted into the map.
@@ -14,8 +14,14 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod atomic_batch;
+pub use atomic_batch::AtomicBatch;
+
 pub mod memory_map;
 
+#[cfg(feature = "rocks")]
+pub mod rocksdb;
+
 use console::network::prelude::*;
 
 use core::{borrow::Borrow, hash::Hash};
@@ -56,6 +62,22 @@ pub trait Map<
     ///
     fn abort_atomic(&self);
 
+    ///
+    /// Saves the current list of pending operations, so that `atomic_rewind` can later
+    /// discard any operations queued after this point.
+    ///
+    fn atomic_checkpoint(&self);
+
+    ///
+    /// Removes the latest atomic checkpoint, keeping all of the pending operations.
+    ///
+    fn clear_latest_checkpoint(&self);
+
+    ///
+    /// Removes all of the pending operations queued after the latest atomic checkpoint.
+    ///
+    fn atomic_rewind(&self);
+
     ///
     /// Finishes an atomic operation, performing all the queued writes.
     ///
@@ -125,6 +147,26 @@ pub trait MapRead<
         }
     }
 
+    ///
+    /// Returns `true` if the given key exists in the map, or is scheduled to be inserted
+    /// as part of an atomic batch.
+    ///
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early in case of errors in order to not conceal them.
+        let map_contains = self.contains_key(key)?;
+
+        // Return the atomic batch status, if it exists, or the map status, otherwise.
+        match self.get_batched(key) {
+            Some(Some(_)) => Ok(true),
+            Some(None) => Ok(false),
+            None => Ok(map_contains),
+        }
+    }
+
     ///
     /// Returns an iterator visiting each key-value pair in the map.
     ///
@@ -145,9 +187,11 @@ pub trait MapRead<
 /// atomic write batch in progress yet. This ensures that complex atomic operations consisting of
 /// multiple lower-level operations - which might also need to be atomic if executed individually -
 /// are executed as a single large atomic operation regardless.
+///
+/// The macro evaluates to the output of the given block of operations, on success.
 #[macro_export]
 macro_rules! atomic_write_batch {
-    ($self:expr, $ops:block) => {
+    ($self:expr, $ops:block) => {{
         // Check if an atomic batch write is already in progress. If there isn't one, this means
         // this operation is a "top-level" one and is the one to start and finalize the batch.
         let is_part_of_atomic_batch = $self.is_atomic_in_progress();
@@ -159,12 +203,12 @@ macro_rules! atomic_write_batch {
 
         // Wrap the operations that should be batched in a closure to be able to abort the entire
         // write batch if any of them fails.
-        let run_atomic_ops = || -> Result<()> { $ops };
+        let run_atomic_ops = || -> Result<_> { $ops };
 
         // Abort the batch if any of the associated operations has failed. It's crucial that there
         // is an early return (via `?`) here, in order for any higher-level atomic write batch to
         // also abort, cascading to all the owned storage objects.
-        run_atomic_ops().map_err(|err| {
+        let output = run_atomic_ops().map_err(|err| {
             $self.abort_atomic();
             err
         })?;
@@ -173,5 +217,7 @@ macro_rules! atomic_write_batch {
         if !is_part_of_atomic_batch {
             $self.finish_atomic()?;
         }
-    };
+
+        output
+    }};
 }
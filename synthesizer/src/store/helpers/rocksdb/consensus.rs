@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::{
+    helpers::rocksdb::{BlockDB, ProgramDB, TransactionDB, TransitionDB},
+    BlockStore,
+    ConsensusStorage,
+    ProgramStore,
+};
+use console::network::prelude::*;
+
+/// A RocksDB consensus storage.
+#[derive(Clone)]
+pub struct ConsensusDB<N: Network> {
+    /// The program store.
+    program_store: ProgramStore<N, ProgramDB<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockDB<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
+    type ProgramStorage = ProgramDB<N>;
+    type BlockStorage = BlockDB<N>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the consensus storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the program store.
+        let program_store = ProgramStore::<N, ProgramDB<N>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockDB<N>>::open(dev)?;
+        // Return the consensus storage.
+        Ok(Self {
+            program_store,
+            block_store,
+        })
+    }
+
+    /// Returns the program store.
+    fn program_store(&self) -> &ProgramStore<N, Self::ProgramStorage> {
+        &self.program_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        store::{helpers::rocksdb::test_helpers::TemporaryDev, ConsensusStore, FinalizeStatus},
+        VM,
+    };
+    use console::{
+        network::Testnet3,
+        program::{Identifier, Plaintext, ProgramID, Value},
+    };
+
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_reopen_consensus_store() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let value = Value::from_str("100u64").unwrap();
+
+        {
+            // Initialize a new consensus store.
+            let store =
+                ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(Some(temp_dev.dev)).unwrap();
+            let program_store = store.program_store();
+
+            // Insert a key-value pair into a new mapping.
+            program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+            assert!(program_store.contains_program(&program_id).unwrap());
+        }
+
+        // Reopen the consensus store.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(Some(temp_dev.dev)).unwrap();
+        let program_store = store.program_store();
+
+        // Ensure the mapping and its key-value pair were persisted.
+        assert!(program_store.contains_program(&program_id).unwrap());
+        assert!(program_store.contains_mapping(&program_id, &mapping_name).unwrap());
+        assert_eq!(program_store.get_value(&program_id, &mapping_name, &key).unwrap(), Some(value));
+        // Ensure the block store is empty.
+        assert!(store.block_store().heights().next().is_none());
+    }
+
+    #[test]
+    fn test_reopen_vm_with_deployments() {
+        let rng = &mut TestRng::default();
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Fetch the genesis block and a deployment transaction.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let program_id = *crate::vm::test_helpers::sample_program().id();
+
+        {
+            // Initialize a new VM.
+            let store =
+                ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(Some(temp_dev.dev)).unwrap();
+            let vm = VM::from(store).unwrap();
+            vm.add_next_block(&genesis).unwrap();
+
+            // Add a block with the deployment.
+            let transactions = std::slice::from_ref(&deployment_transaction);
+            let block =
+                crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, transactions, rng).unwrap();
+            vm.add_next_block(&block).unwrap();
+            assert!(vm.contains_program(&program_id));
+            assert!(!vm.is_atomic_in_progress());
+        }
+
+        // Reopen the VM.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(Some(temp_dev.dev)).unwrap();
+        let vm = VM::from(store).unwrap();
+
+        // Ensure the blocks and the deployment were persisted, and the program is loaded.
+        assert_eq!(vm.block_store().current_block_height(), Some(1));
+        assert_eq!(
+            vm.transaction_store().get_finalize_status(&deployment_transaction.id()).unwrap(),
+            Some(FinalizeStatus::Accepted)
+        );
+        assert!(vm.contains_program(&program_id));
+    }
+
+    #[test]
+    fn test_speculate() {
+        let rng = &mut TestRng::default();
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize a new VM.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::open(Some(temp_dev.dev)).unwrap();
+        let vm = VM::from(store).unwrap();
+        vm.add_next_block(&crate::vm::test_helpers::sample_genesis_block(rng)).unwrap();
+        vm.add_next_block(&crate::vm::test_helpers::sample_funding_block(rng)).unwrap();
+
+        // Fetch a deployment transaction and an execution transaction.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let program_id = *crate::vm::test_helpers::sample_program().id();
+
+        // Speculate on the transactions, including a duplicate deployment.
+        let candidates = [deployment_transaction.clone(), execution_transaction, deployment_transaction];
+        let (accepted, aborted) = vm.speculate(&candidates).unwrap();
+        assert_eq!(accepted, vec![candidates[0].id(), candidates[1].id()]);
+        assert_eq!(aborted.len(), 1);
+
+        // Ensure nothing was committed, and the atomic batch of the database was released.
+        assert!(!vm.contains_program(&program_id));
+        assert!(!vm.program_store().contains_program(&program_id).unwrap());
+        assert!(!vm.is_atomic_in_progress());
+
+        // Ensure the accepted transactions can be added in a block.
+        let block =
+            crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &candidates[..2], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.contains_program(&program_id));
+        assert!(vm.program_store().contains_program(&program_id).unwrap());
+
+        // Ensure a redeployment is now aborted, and the block writes persist.
+        let (accepted, aborted) = vm.speculate(&candidates[..1]).unwrap();
+        assert!(accepted.is_empty());
+        assert_eq!(aborted.len(), 1);
+        assert_eq!(vm.block_store().current_block_height(), Some(2));
+        assert!(vm.program_store().contains_program(&program_id).unwrap());
+    }
+}
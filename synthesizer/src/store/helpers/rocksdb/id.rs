@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// Declares the `MapID` enum, along with the column family name of each map.
+macro_rules! map_ids {
+    ($($variant:ident => $name:literal,)+) => {
+        /// The ID of a map in RocksDB. Each map is stored in its own column family.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum MapID {
+            $($variant,)+
+        }
+
+        impl MapID {
+            /// The list of all map IDs.
+            pub const ALL: &'static [MapID] = &[$(MapID::$variant,)+];
+
+            /// Returns the column family name of the map.
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(MapID::$variant => $name,)+
+                }
+            }
+        }
+    };
+}
+
+map_ids! {
+    BlockStateRoot => "block/state_root",
+    BlockReverseStateRoot => "block/reverse_state_root",
+    BlockID => "block/id",
+    BlockReverseID => "block/reverse_id",
+    BlockHeader => "block/header",
+    BlockTransactions => "block/transactions",
+    BlockReverseTransactions => "block/reverse_transactions",
+    BlockCoinbaseSolution => "block/coinbase_solution",
+    BlockCoinbasePuzzleCommitment => "block/coinbase_puzzle_commitment",
+    BlockSignature => "block/signature",
+    BlockProverRewards => "block/prover_rewards",
+    BlockProverTotalRewards => "block/prover_total_rewards",
+    BlockProgramTransactions => "block/program_transactions",
+    BlockProgramTransactionsCount => "block/program_transactions_count",
+    BlockFunctionTransactions => "block/function_transactions",
+    BlockFunctionTransactionsCount => "block/function_transactions_count",
+    BlockMappingTransitions => "block/mapping_transitions",
+    BlockMappingTransitionsCount => "block/mapping_transitions_count",
+
+    TransactionID => "transaction/id",
+    TransactionFinalizeStatus => "transaction/finalize_status",
+
+    DeploymentID => "deployment/id",
+    DeploymentEdition => "deployment/edition",
+    DeploymentReverseID => "deployment/reverse_id",
+    DeploymentProgram => "deployment/program",
+    DeploymentVerifyingKey => "deployment/verifying_key",
+    DeploymentCertificate => "deployment/certificate",
+    DeploymentSignature => "deployment/signature",
+    DeploymentFee => "deployment/fee",
+    DeploymentReverseFee => "deployment/reverse_fee",
+
+    ExecutionID => "execution/id",
+    ExecutionReverseID => "execution/reverse_id",
+    ExecutionInclusion => "execution/inclusion",
+    ExecutionFee => "execution/fee",
+    ExecutionRejected => "execution/rejected",
+
+    TransitionLocator => "transition/locator",
+    TransitionFinalize => "transition/finalize",
+    TransitionProof => "transition/proof",
+    TransitionTPK => "transition/tpk",
+    TransitionReverseTPK => "transition/reverse_tpk",
+    TransitionTCM => "transition/tcm",
+    TransitionReverseTCM => "transition/reverse_tcm",
+    TransitionFee => "transition/fee",
+
+    InputID => "input/id",
+    InputReverseID => "input/reverse_id",
+    InputConstant => "input/constant",
+    InputPublic => "input/public",
+    InputPrivate => "input/private",
+    InputRecord => "input/record",
+    InputRecordTag => "input/record_tag",
+    InputExternalRecord => "input/external_record",
+
+    OutputID => "output/id",
+    OutputReverseID => "output/reverse_id",
+    OutputConstant => "output/constant",
+    OutputPublic => "output/public",
+    OutputPrivate => "output/private",
+    OutputRecord => "output/record",
+    OutputRecordNonce => "output/record_nonce",
+    OutputExternalRecord => "output/external_record",
+
+    ProgramID => "program/program_id",
+    ProgramMappingID => "program/mapping_id",
+    ProgramKeyValueID => "program/key_value_id",
+    ProgramKey => "program/key",
+    ProgramValue => "program/value",
+    ProgramFinalizeOutputs => "program/finalize_outputs",
+    ProgramEdition => "program/edition",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_map_names_are_unique() {
+        let names: HashSet<_> = MapID::ALL.iter().map(|map_id| map_id.name()).collect();
+        assert_eq!(names.len(), MapID::ALL.len());
+    }
+}
@@ -0,0 +1,637 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{MapID, RocksDB};
+use crate::store::helpers::{AtomicBatch, Map, MapRead};
+use console::network::prelude::*;
+
+use core::{borrow::Borrow, hash::Hash, marker::PhantomData};
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A map that is persisted in a RocksDB column family.
+#[derive(Clone)]
+pub struct DataMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    database: RocksDB,
+    map_id: MapID,
+    batch_in_progress: Arc<AtomicBool>,
+    atomic_batch: Arc<Mutex<AtomicBatch<K, V>>>,
+    checkpoints: Arc<Mutex<Vec<usize>>>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> DataMap<K, V>
+{
+    /// Opens the map with the given map ID in the given database.
+    pub fn open(database: RocksDB, map_id: MapID) -> Result<Self> {
+        // Ensure the column family exists.
+        database.cf_handle(map_id)?;
+        Ok(Self {
+            database,
+            map_id,
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            checkpoints: Default::default(),
+        })
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> Map<'a, K, V> for DataMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => {
+                self.atomic_batch.lock().push(key, Some(value));
+            }
+            // Otherwise, insert the key-value pair directly into the map.
+            false => {
+                let cf_handle = self.database.cf_handle(self.map_id)?;
+                self.database.rocksdb().put_cf(cf_handle, bincode::serialize(&key)?, bincode::serialize(&value)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.batch_in_progress.load(Ordering::SeqCst);
+
+        match is_batch {
+            // If a batch is in progress, add the key-None pair to the batch.
+            true => {
+                self.atomic_batch.lock().push(*key, None);
+            }
+            // Otherwise, remove the key-value pair directly from the map.
+            false => {
+                let cf_handle = self.database.cf_handle(self.map_id)?;
+                self.database.rocksdb().delete_cf(cf_handle, bincode::serialize(key)?)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Register the atomic batch with the database.
+        self.database.start_atomic();
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        // Clear the atomic batch.
+        *self.atomic_batch.lock() = Default::default();
+        // Clear the checkpoints.
+        self.checkpoints.lock().clear();
+        // Set the atomic batch flag to `false`, and end the part of this map in the atomic batch of the database.
+        // Note: The map may already have been aborted, as part of a larger atomic batch.
+        if self.batch_in_progress.swap(false, Ordering::SeqCst) {
+            if let Err(error) = self.database.abort_atomic() {
+                error!("Failed to write the atomic batch after an abort: {error}");
+            }
+        }
+    }
+
+    ///
+    /// Saves the current list of pending operations, so that `atomic_rewind` can later
+    /// discard any operations queued after this point.
+    ///
+    fn atomic_checkpoint(&self) {
+        // Push the current length of the atomic batch as a checkpoint.
+        let checkpoint = self.atomic_batch.lock().len();
+        self.checkpoints.lock().push(checkpoint);
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint, keeping all of the pending operations.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        self.checkpoints.lock().pop();
+    }
+
+    ///
+    /// Removes all of the pending operations queued after the latest atomic checkpoint.
+    ///
+    fn atomic_rewind(&self) {
+        // Retrieve the latest checkpoint, or rewind the entire atomic batch if there is none.
+        let checkpoint = self.checkpoints.lock().pop().unwrap_or(0);
+        // Discard the operations queued after the checkpoint.
+        self.atomic_batch.lock().truncate(checkpoint);
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    ///
+    /// Note: The writes are committed to disk once every map in the database
+    /// that is part of the atomic operation has finished.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        // Clear the checkpoints.
+        self.checkpoints.lock().clear();
+
+        // Serialize the queued operations.
+        let operations = operations
+            .into_operations()
+            .map(|(key, value)| {
+                let value = value.map(|value| bincode::serialize(&value)).transpose()?;
+                Ok((bincode::serialize(&key)?, value))
+            })
+            .collect::<Result<Vec<_>>>();
+
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+
+        // If serialization failed, abort the atomic operation in the database.
+        let operations = match operations {
+            Ok(operations) => operations,
+            Err(error) => {
+                self.database.abort_atomic()?;
+                return Err(error);
+            }
+        };
+
+        // Queue the operations into the atomic batch of the database.
+        let cf_handle = self.database.cf_handle(self.map_id)?;
+        self.database.finish_atomic(|batch| {
+            for (key, value) in operations {
+                match value {
+                    Some(value) => batch.put_cf(cf_handle, key, value),
+                    None => batch.delete_cf(cf_handle, key),
+                }
+            }
+        })
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> MapRead<'a, K, V> for DataMap<K, V>
+{
+    type Iterator = Iter<'a, K, V>;
+    type Keys = Keys<'a, K, V>;
+    type Values = Values<'a, K, V>;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        let cf_handle = self.database.cf_handle(self.map_id)?;
+        Ok(self.database.rocksdb().get_pinned_cf(cf_handle, bincode::serialize(key)?)?.is_some())
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        let cf_handle = self.database.cf_handle(self.map_id)?;
+        match self.database.rocksdb().get_pinned_cf(cf_handle, bincode::serialize(key)?)? {
+            Some(bytes) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
+            None => Ok(None),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early if there is no atomic batch in progress.
+        match self.batch_in_progress.load(Ordering::SeqCst) {
+            // Note: The latest queued operation on the key takes precedence.
+            true => self.atomic_batch.lock().get(key).cloned(),
+            false => None,
+        }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        Iter::new(&self.database, self.map_id)
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        Keys { iter: Iter::new(&self.database, self.map_id) }
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values(&'a self) -> Self::Values {
+        Values { iter: Iter::new(&self.database, self.map_id) }
+    }
+}
+
+/// An iterator over the key-value pairs of a `DataMap`.
+pub struct Iter<'a, K, V> {
+    /// The RocksDB iterator, or `None` if the column family is missing.
+    db_iter: Option<rocksdb::DBIteratorWithThreadMode<'a, rocksdb::DB>>,
+    /// PhantomData.
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    /// Initializes a new iterator over the given map.
+    fn new(database: &'a RocksDB, map_id: MapID) -> Self {
+        let db_iter = match database.cf_handle(map_id) {
+            Ok(cf_handle) => Some(database.rocksdb().iterator_cf(cf_handle, rocksdb::IteratorMode::Start)),
+            Err(error) => {
+                error!("Failed to iterate over map '{}': {error}", map_id.name());
+                None
+            }
+        };
+        Self { db_iter, _phantom: PhantomData }
+    }
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator
+    for Iter<'a, K, V>
+{
+    type Item = (Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Retrieve the next key-value pair.
+        let (key, value) = match self.db_iter.as_mut()?.next()? {
+            Ok(entry) => entry,
+            Err(error) => {
+                error!("RocksDB iterator error: {error}");
+                return None;
+            }
+        };
+        // Deserialize the key-value pair.
+        match (bincode::deserialize(&key), bincode::deserialize(&value)) {
+            (Ok(key), Ok(value)) => Some((Cow::Owned(key), Cow::Owned(value))),
+            _ => {
+                error!("RocksDB iterator error: failed to deserialize a key-value pair");
+                None
+            }
+        }
+    }
+}
+
+/// An iterator over the keys of a `DataMap`.
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator
+    for Keys<'a, K, V>
+{
+    type Item = Cow<'a, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+}
+
+/// An iterator over the values of a `DataMap`.
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator
+    for Values<'a, K, V>
+{
+    type Item = Cow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::rocksdb::test_helpers::TemporaryDev;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Opens a new map in a temporary database.
+    fn open_map<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    >(
+        temp_dev: &TemporaryDev,
+    ) -> DataMap<K, V> {
+        RocksDB::open_map(CurrentNetwork::ID, Some(temp_dev.dev), MapID::TransitionFee).unwrap()
+    }
+
+    #[test]
+    fn test_contains_key() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize an address.
+        let address =
+            Address::<CurrentNetwork>::from_str("aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8")
+                .unwrap();
+
+        // Initialize a map.
+        let map: DataMap<Address<CurrentNetwork>, ()> = open_map(&temp_dev);
+        map.insert(address, ()).unwrap();
+        assert!(map.contains_key(&address).unwrap());
+    }
+
+    #[test]
+    fn test_insert_and_get_speculative() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize a map.
+        let map: DataMap<usize, String> = open_map(&temp_dev);
+
+        // Sanity check.
+        assert!(map.iter().next().is_none());
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) several updates to the same item.
+        for i in 0..10 {
+            // Update the item in the map.
+            map.insert(0, i.to_string()).unwrap();
+
+            // Check that the item is not yet in the map.
+            assert!(map.get(&0).unwrap().is_none());
+            // Check that the updated item is in the batch.
+            assert_eq!(map.get_batched(&0), Some(Some(i.to_string())));
+            // Check that the updated item can be speculatively retrieved.
+            assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned(i.to_string())));
+        }
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is present in the map now.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("9".to_string())));
+        // Check that the item is not in the batch.
+        assert_eq!(map.get_batched(&0), None);
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("9".to_string())));
+    }
+
+    #[test]
+    fn test_remove_and_get_speculative() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize a map.
+        let map: DataMap<usize, String> = open_map(&temp_dev);
+
+        // Insert an item into the map.
+        map.insert(0, "0".to_string()).unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Remove the item from the map.
+        map.remove(&0).unwrap();
+
+        // Check that the item still exists in the map.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        // Check that the item is removed in the batch.
+        assert_eq!(map.get_batched(&0), Some(None));
+        // Check that the item is removed when speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), None);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is not present in the map now.
+        assert!(map.get(&0).unwrap().is_none());
+        assert_eq!(map.get_speculative(&0).unwrap(), None);
+        assert!(map.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_atomic_writes_can_be_aborted() {
+        // The number of items that will be queued to be inserted into the map.
+        const NUM_ITEMS: usize = 10;
+
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize a map.
+        let map: DataMap<usize, String> = open_map(&temp_dev);
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Abort the current atomic write batch.
+        map.abort_atomic();
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Start another atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // The map should contain NUM_ITEMS items now.
+        assert_eq!(map.iter().count(), NUM_ITEMS);
+        assert_eq!(map.keys().count(), NUM_ITEMS);
+        assert_eq!(map.values().count(), NUM_ITEMS);
+    }
+
+    #[test]
+    fn test_atomic_writes_span_maps() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize two maps in the same database.
+        let database = RocksDB::open(CurrentNetwork::ID, Some(temp_dev.dev)).unwrap();
+        let map_a: DataMap<usize, String> = DataMap::open(database.clone(), MapID::TransitionFee).unwrap();
+        let map_b: DataMap<usize, String> = DataMap::open(database, MapID::TransitionTCM).unwrap();
+
+        // Start an atomic write batch on both maps.
+        map_a.start_atomic();
+        map_b.start_atomic();
+
+        map_a.insert(0, "a".to_string()).unwrap();
+        map_b.insert(0, "b".to_string()).unwrap();
+
+        // Finish the first map. The writes must not be visible until the second map finishes.
+        map_a.finish_atomic().unwrap();
+        assert!(map_a.get(&0).unwrap().is_none());
+        assert!(map_b.get(&0).unwrap().is_none());
+
+        // Finish the second map.
+        map_b.finish_atomic().unwrap();
+        assert_eq!(map_a.get(&0).unwrap(), Some(Cow::Owned("a".to_string())));
+        assert_eq!(map_b.get(&0).unwrap(), Some(Cow::Owned("b".to_string())));
+    }
+
+    #[test]
+    fn test_abort_does_not_discard_other_writers() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize two maps in the same database.
+        let database = RocksDB::open(CurrentNetwork::ID, Some(temp_dev.dev)).unwrap();
+        let map_a: DataMap<usize, String> = DataMap::open(database.clone(), MapID::TransitionFee).unwrap();
+        let map_b: DataMap<usize, String> = DataMap::open(database, MapID::TransitionTCM).unwrap();
+
+        // Start an atomic write batch on the first map.
+        map_a.start_atomic();
+        map_a.insert(0, "a".to_string()).unwrap();
+
+        // Write to the second map from another thread, which waits for the first batch to end.
+        let writer = {
+            let map_b = map_b.clone();
+            std::thread::spawn(move || {
+                map_b.start_atomic();
+                map_b.insert(0, "b".to_string()).unwrap();
+                map_b.finish_atomic().unwrap();
+            })
+        };
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        // Abort the first atomic write batch.
+        map_a.abort_atomic();
+        writer.join().unwrap();
+
+        // Ensure only the aborted writes were discarded.
+        assert!(map_a.get(&0).unwrap().is_none());
+        assert_eq!(map_b.get(&0).unwrap(), Some(Cow::Owned("b".to_string())));
+    }
+
+    #[test]
+    fn test_abort_does_not_discard_other_maps() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        // Initialize two maps in the same database.
+        let database = RocksDB::open(CurrentNetwork::ID, Some(temp_dev.dev)).unwrap();
+        let map_a: DataMap<usize, String> = DataMap::open(database.clone(), MapID::TransitionFee).unwrap();
+        let map_b: DataMap<usize, String> = DataMap::open(database, MapID::TransitionTCM).unwrap();
+
+        // Start an atomic write batch on both maps.
+        map_a.start_atomic();
+        map_b.start_atomic();
+
+        map_a.insert(0, "a".to_string()).unwrap();
+        map_b.insert(0, "b".to_string()).unwrap();
+
+        // Abort the first map, twice, as a larger atomic batch may abort a map that was already aborted.
+        map_a.abort_atomic();
+        map_a.abort_atomic();
+        assert!(!map_a.is_atomic_in_progress());
+        // Ensure the second map is still part of the atomic batch, and its writes are still queued.
+        assert!(map_b.is_atomic_in_progress());
+        assert_eq!(map_b.get_speculative(&0).unwrap(), Some(Cow::Owned("b".to_string())));
+
+        // Finish the second map.
+        map_b.finish_atomic().unwrap();
+        assert!(map_a.get(&0).unwrap().is_none());
+        assert_eq!(map_b.get(&0).unwrap(), Some(Cow::Owned("b".to_string())));
+
+        // Ensure a new atomic write batch can be written.
+        map_a.start_atomic();
+        map_a.insert(1, "a".to_string()).unwrap();
+        map_a.finish_atomic().unwrap();
+        assert_eq!(map_a.get(&1).unwrap(), Some(Cow::Owned("a".to_string())));
+    }
+
+    #[test]
+    fn test_reopen() {
+        let temp_dev = TemporaryDev::new(CurrentNetwork::ID);
+
+        {
+            // Initialize a map, and insert an item.
+            let map: DataMap<usize, String> = open_map(&temp_dev);
+            map.insert(0, "0".to_string()).unwrap();
+        }
+
+        // Reopen the map, and check that the item is still present.
+        let map: DataMap<usize, String> = open_map(&temp_dev);
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+    }
+}
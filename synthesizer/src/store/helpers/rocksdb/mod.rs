@@ -0,0 +1,278 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod block;
+pub use block::*;
+
+mod consensus;
+pub use consensus::*;
+
+mod id;
+pub use id::*;
+
+mod map;
+pub use map::*;
+
+mod program;
+pub use program::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod transition;
+pub use transition::*;
+
+use console::network::prelude::*;
+
+use core::hash::Hash;
+use once_cell::sync::OnceCell;
+use parking_lot::{Condvar, Mutex};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Weak},
+    thread::{self, ThreadId},
+};
+
+/// The state of the atomic batch that is shared by all of the maps of a RocksDB instance.
+#[derive(Default)]
+struct AtomicState {
+    /// The queued writes of all maps that have finished their part of the current atomic batch.
+    batch: rocksdb::WriteBatch,
+    /// The number of `start_atomic` calls that have not been matched by a `finish_atomic` call yet.
+    depth: usize,
+    /// The thread that is currently writing the atomic batch, if any.
+    writer: Option<ThreadId>,
+}
+
+/// The shared state of an open RocksDB instance.
+struct RocksDBInner {
+    /// The RocksDB instance.
+    rocksdb: rocksdb::DB,
+    /// The optional development ID.
+    dev: Option<u16>,
+    /// The atomic batch that is shared by all of the maps.
+    atomic_state: Mutex<AtomicState>,
+    /// Notifies the threads that are waiting to start an atomic batch that the current one has ended.
+    atomic_released: Condvar,
+}
+
+/// A RocksDB instance, shared by all of the maps of a ledger.
+///
+/// Each map is stored in its own column family (see `MapID`), and all maps of a ledger
+/// share a single write batch, so that an atomic operation spanning several maps is
+/// written to disk all at once, or not at all.
+///
+/// The shared write batch has a single writer: the thread that starts an atomic batch owns it
+/// until the batch is written or aborted, and any other thread that starts an atomic batch in
+/// the meantime waits for it to end. Aborting an atomic batch only discards the pending writes
+/// of the aborted map, and never the writes of another map or of another thread.
+#[derive(Clone)]
+pub struct RocksDB {
+    inner: Arc<RocksDBInner>,
+}
+
+impl RocksDB {
+    /// Opens the RocksDB instance for the given network ID and optional development ID.
+    ///
+    /// In production mode, the database is stored in `~/.aleo/storage/ledger-{network}`.
+    /// In development mode, the database is stored in `/path/to/repo/.ledger-{network}-{id}`.
+    pub fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
+        Self::open_path(aleo_std::aleo_ledger_dir(network_id, dev), dev)
+    }
+
+    /// Opens the RocksDB instance at the given path.
+    ///
+    /// If the database at this path is already open in this process, the existing instance is returned.
+    pub fn open_path<P: AsRef<Path>>(path: P, dev: Option<u16>) -> Result<Self> {
+        /// The RocksDB instances that are currently open, indexed by path.
+        static INSTANCES: OnceCell<Mutex<HashMap<PathBuf, Weak<RocksDBInner>>>> = OnceCell::new();
+
+        let path = path.as_ref().to_path_buf();
+
+        // Acquire the lock on the open instances, for the remainder of this function.
+        let mut instances = INSTANCES.get_or_init(Default::default).lock();
+
+        // If the database is already open, return the existing instance.
+        if let Some(inner) = instances.get(&path).and_then(Weak::upgrade) {
+            // Ensure the development ID matches.
+            if inner.dev != dev {
+                bail!("RocksDB at '{}' is already open with a different development ID", path.display())
+            }
+            return Ok(Self { inner });
+        }
+
+        // Prepare the database options.
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+
+        // Open a column family for every map, along with any column families that already exist on disk.
+        let mut column_families: Vec<String> = MapID::ALL.iter().map(|map_id| map_id.name().to_string()).collect();
+        if let Ok(existing) = rocksdb::DB::list_cf(&options, &path) {
+            for name in existing {
+                if !column_families.contains(&name) {
+                    column_families.push(name);
+                }
+            }
+        }
+        let descriptors = column_families
+            .into_iter()
+            .map(|name| rocksdb::ColumnFamilyDescriptor::new(name, rocksdb::Options::default()));
+
+        // Open the database.
+        let rocksdb = rocksdb::DB::open_cf_descriptors(&options, &path, descriptors)
+            .map_err(|error| anyhow!("Failed to open RocksDB at '{}': {error}", path.display()))?;
+
+        let inner = Arc::new(RocksDBInner {
+            rocksdb,
+            dev,
+            atomic_state: Default::default(),
+            atomic_released: Condvar::new(),
+        });
+
+        // Register the new instance.
+        instances.retain(|_, instance| instance.strong_count() > 0);
+        instances.insert(path, Arc::downgrade(&inner));
+
+        Ok(Self { inner })
+    }
+
+    /// Opens the map with the given map ID, for the given network ID and optional development ID.
+    pub fn open_map<K, V>(network_id: u16, dev: Option<u16>, map_id: MapID) -> Result<DataMap<K, V>>
+    where
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    {
+        DataMap::open(Self::open(network_id, dev)?, map_id)
+    }
+
+    /// Returns the optional development ID.
+    pub fn dev(&self) -> Option<u16> {
+        self.inner.dev
+    }
+
+    /// Returns the column family handle for the given map ID.
+    pub(super) fn cf_handle(&self, map_id: MapID) -> Result<&rocksdb::ColumnFamily> {
+        match self.inner.rocksdb.cf_handle(map_id.name()) {
+            Some(cf_handle) => Ok(cf_handle),
+            None => bail!("Missing column family '{}' in RocksDB", map_id.name()),
+        }
+    }
+
+    /// Returns the underlying RocksDB instance.
+    pub(super) fn rocksdb(&self) -> &rocksdb::DB {
+        &self.inner.rocksdb
+    }
+
+    /// Registers the start of an atomic batch for one map.
+    ///
+    /// If another thread is writing an atomic batch, this waits until that batch has ended.
+    pub(super) fn start_atomic(&self) {
+        let current = thread::current().id();
+        // Acquire the lock on the shared atomic batch.
+        let mut state = self.inner.atomic_state.lock();
+        // Wait until no other thread is writing an atomic batch.
+        while matches!(state.writer, Some(writer) if writer != current) {
+            self.inner.atomic_released.wait(&mut state);
+        }
+        // Register the current thread as the writer.
+        state.writer = Some(current);
+        state.depth += 1;
+    }
+
+    /// Queues the given operations into the shared atomic batch, and writes the batch to disk
+    /// once every map that started an atomic batch has finished it.
+    pub(super) fn finish_atomic(&self, operations: impl FnOnce(&mut rocksdb::WriteBatch)) -> Result<()> {
+        // Acquire the lock on the shared atomic batch.
+        let mut state = self.inner.atomic_state.lock();
+        // Ensure the atomic batch was started by the current thread.
+        if state.writer != Some(thread::current().id()) {
+            bail!("Cannot finish an atomic batch that was not started by the current thread")
+        }
+        // Queue the operations.
+        operations(&mut state.batch);
+        // Decrement the atomic depth, and write the atomic batch if this was the last pending map.
+        self.end_atomic(&mut state)
+    }
+
+    /// Registers the end of an atomic batch for one map, without queueing any of its operations.
+    ///
+    /// Note: The operations of the other maps are kept, and are written once every map
+    /// that started an atomic batch has finished or aborted it.
+    pub(super) fn abort_atomic(&self) -> Result<()> {
+        // Acquire the lock on the shared atomic batch.
+        let mut state = self.inner.atomic_state.lock();
+        // Only the writer may abort its part of the atomic batch.
+        if state.writer != Some(thread::current().id()) {
+            return Ok(());
+        }
+        // Decrement the atomic depth, and write the atomic batch if this was the last pending map.
+        self.end_atomic(&mut state)
+    }
+
+    /// Decrements the atomic depth, and once no map is pending, writes the atomic batch to disk and releases the writer.
+    fn end_atomic(&self, state: &mut AtomicState) -> Result<()> {
+        // Decrement the atomic depth.
+        state.depth = state.depth.saturating_sub(1);
+
+        // If this was the last pending map, write the atomic batch to disk, and release the writer.
+        if state.depth == 0 {
+            let batch = core::mem::take(&mut state.batch);
+            state.writer = None;
+            self.inner.atomic_released.notify_all();
+            if !batch.is_empty() {
+                self.inner
+                    .rocksdb
+                    .write(batch)
+                    .map_err(|error| anyhow!("Failed to write the atomic batch: {error}"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use std::path::PathBuf;
+
+    /// A development ID whose ledger directory is removed when dropped.
+    pub(crate) struct TemporaryDev {
+        pub(crate) dev: u16,
+        path: PathBuf,
+    }
+
+    impl TemporaryDev {
+        /// Returns a new development ID for the given network ID, that is not in use by another test.
+        pub(crate) fn new(network_id: u16) -> Self {
+            use rand::Rng;
+
+            loop {
+                let dev = rand::thread_rng().gen_range(10_000..u16::MAX);
+                let path = aleo_std::aleo_ledger_dir(network_id, Some(dev));
+                if !path.exists() {
+                    return Self { dev, path };
+                }
+            }
+        }
+    }
+
+    impl Drop for TemporaryDev {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.path);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    block::Transition,
+    program::Program,
+    snark::{Certificate, Proof, VerifyingKey},
+    store::{
+        helpers::rocksdb::{DataMap, MapID, RocksDB, TransitionDB},
+        DeploymentStorage,
+        DeploymentStore,
+        ExecutionStorage,
+        ExecutionStore,
+        FinalizeStatus,
+        TransactionStorage,
+        TransactionType,
+        TransitionStore,
+    },
+};
+use console::{
+    account::Signature,
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+/// A RocksDB transaction storage.
+#[derive(Clone)]
+pub struct TransactionDB<N: Network> {
+    /// The mapping of `transaction ID` to `transaction type`.
+    id_map: DataMap<N::TransactionID, TransactionType>,
+    /// The mapping of `transaction ID` to `finalize status`.
+    finalize_status_map: DataMap<N::TransactionID, FinalizeStatus>,
+    /// The deployment store.
+    deployment_store: DeploymentStore<N, DeploymentDB<N>>,
+    /// The execution store.
+    execution_store: ExecutionStore<N, ExecutionDB<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
+    type IDMap = DataMap<N::TransactionID, TransactionType>;
+    type FinalizeStatusMap = DataMap<N::TransactionID, FinalizeStatus>;
+    type DeploymentStorage = DeploymentDB<N>;
+    type ExecutionStorage = ExecutionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the transaction storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        // Retrieve the optional development ID.
+        let dev = transition_store.dev();
+        // Initialize the deployment store.
+        let deployment_store = DeploymentStore::<N, DeploymentDB<N>>::open(transition_store.clone())?;
+        // Initialize the execution store.
+        let execution_store = ExecutionStore::<N, ExecutionDB<N>>::open(transition_store)?;
+        // Return the transaction storage.
+        Ok(Self {
+            id_map: RocksDB::open_map(N::ID, dev, MapID::TransactionID)?,
+            finalize_status_map: RocksDB::open_map(N::ID, dev, MapID::TransactionFinalizeStatus)?,
+            deployment_store,
+            execution_store,
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the finalize status map.
+    fn finalize_status_map(&self) -> &Self::FinalizeStatusMap {
+        &self.finalize_status_map
+    }
+
+    /// Returns the deployment store.
+    fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
+        &self.deployment_store
+    }
+
+    /// Returns the execution store.
+    fn execution_store(&self) -> &ExecutionStore<N, Self::ExecutionStorage> {
+        &self.execution_store
+    }
+}
+
+/// A RocksDB deployment storage.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct DeploymentDB<N: Network> {
+    /// The ID map.
+    id_map: DataMap<N::TransactionID, (ProgramID<N>, u16)>,
+    /// The edition map.
+    edition_map: DataMap<ProgramID<N>, u16>,
+    /// The reverse ID map.
+    reverse_id_map: DataMap<(ProgramID<N>, u16), N::TransactionID>,
+    /// The program map.
+    program_map: DataMap<N::TransactionID, Program<N>>,
+    /// The verifying key map.
+    verifying_key_map: DataMap<(N::TransactionID, Identifier<N>), VerifyingKey<N>>,
+    /// The certificate map.
+    certificate_map: DataMap<(N::TransactionID, Identifier<N>), Certificate<N>>,
+    /// The signature map.
+    signature_map: DataMap<N::TransactionID, Signature<N>>,
+    /// The fee map.
+    fee_map: DataMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>,
+    /// The reverse fee map.
+    reverse_fee_map: DataMap<N::TransitionID, N::TransactionID>,
+    /// The transition store.
+    transition_store: TransitionStore<N, TransitionDB<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> DeploymentStorage<N> for DeploymentDB<N> {
+    type IDMap = DataMap<N::TransactionID, (ProgramID<N>, u16)>;
+    type EditionMap = DataMap<ProgramID<N>, u16>;
+    type ReverseIDMap = DataMap<(ProgramID<N>, u16), N::TransactionID>;
+    type ProgramMap = DataMap<N::TransactionID, Program<N>>;
+    type VerifyingKeyMap = DataMap<(N::TransactionID, Identifier<N>), VerifyingKey<N>>;
+    type CertificateMap = DataMap<(N::TransactionID, Identifier<N>), Certificate<N>>;
+    type SignatureMap = DataMap<N::TransactionID, Signature<N>>;
+    type FeeMap = DataMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
+    type ReverseFeeMap = DataMap<N::TransitionID, N::TransactionID>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the deployment storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        // Retrieve the optional development ID.
+        let dev = transition_store.dev();
+        Ok(Self {
+            id_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentID)?,
+            edition_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentEdition)?,
+            reverse_id_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentReverseID)?,
+            program_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentProgram)?,
+            verifying_key_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentVerifyingKey)?,
+            certificate_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentCertificate)?,
+            signature_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentSignature)?,
+            fee_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentFee)?,
+            reverse_fee_map: RocksDB::open_map(N::ID, dev, MapID::DeploymentReverseFee)?,
+            transition_store,
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the program map.
+    fn program_map(&self) -> &Self::ProgramMap {
+        &self.program_map
+    }
+
+    /// Returns the verifying key map.
+    fn verifying_key_map(&self) -> &Self::VerifyingKeyMap {
+        &self.verifying_key_map
+    }
+
+    /// Returns the certificate map.
+    fn certificate_map(&self) -> &Self::CertificateMap {
+        &self.certificate_map
+    }
+
+    /// Returns the signature map.
+    fn signature_map(&self) -> &Self::SignatureMap {
+        &self.signature_map
+    }
+
+    /// Returns the fee map.
+    fn fee_map(&self) -> &Self::FeeMap {
+        &self.fee_map
+    }
+
+    /// Returns the reverse fee map.
+    fn reverse_fee_map(&self) -> &Self::ReverseFeeMap {
+        &self.reverse_fee_map
+    }
+
+    /// Returns the transition store.
+    fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
+        &self.transition_store
+    }
+}
+
+/// A RocksDB execution storage.
+#[derive(Clone)]
+#[allow(clippy::type_complexity)]
+pub struct ExecutionDB<N: Network> {
+    /// The ID map.
+    id_map: DataMap<N::TransactionID, (Vec<N::TransitionID>, Option<N::TransitionID>)>,
+    /// The reverse ID map.
+    reverse_id_map: DataMap<N::TransitionID, N::TransactionID>,
+    /// The transition store.
+    transition_store: TransitionStore<N, TransitionDB<N>>,
+    /// The inclusion map.
+    inclusion_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The fee map.
+    fee_map: DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The rejected map.
+    rejected_map: DataMap<N::TransitionID, Transition<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ExecutionStorage<N> for ExecutionDB<N> {
+    type IDMap = DataMap<N::TransactionID, (Vec<N::TransitionID>, Option<N::TransitionID>)>;
+    type ReverseIDMap = DataMap<N::TransitionID, N::TransactionID>;
+    type TransitionStorage = TransitionDB<N>;
+    type InclusionMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type FeeMap = DataMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type RejectedMap = DataMap<N::TransitionID, Transition<N>>;
+
+    /// Initializes the execution storage.
+    fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
+        // Retrieve the optional development ID.
+        let dev = transition_store.dev();
+        Ok(Self {
+            id_map: RocksDB::open_map(N::ID, dev, MapID::ExecutionID)?,
+            reverse_id_map: RocksDB::open_map(N::ID, dev, MapID::ExecutionReverseID)?,
+            transition_store,
+            inclusion_map: RocksDB::open_map(N::ID, dev, MapID::ExecutionInclusion)?,
+            fee_map: RocksDB::open_map(N::ID, dev, MapID::ExecutionFee)?,
+            rejected_map: RocksDB::open_map(N::ID, dev, MapID::ExecutionRejected)?,
+        })
+    }
+
+    /// Returns the ID map.
+    fn id_map(&self) -> &Self::IDMap {
+        &self.id_map
+    }
+
+    /// Returns the reverse ID map.
+    fn reverse_id_map(&self) -> &Self::ReverseIDMap {
+        &self.reverse_id_map
+    }
+
+    /// Returns the transition store.
+    fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
+        &self.transition_store
+    }
+
+    /// Returns the inclusion map.
+    fn inclusion_map(&self) -> &Self::InclusionMap {
+        &self.inclusion_map
+    }
+
+    /// Returns the fee map.
+    fn fee_map(&self) -> &Self::FeeMap {
+        &self.fee_map
+    }
+
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap {
+        &self.rejected_map
+    }
+}
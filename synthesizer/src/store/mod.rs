@@ -16,6 +16,19 @@
 
 pub mod helpers;
 
+#[cfg(feature = "rocks")]
+pub use helpers::rocksdb::{
+    BlockDB,
+    ConsensusDB,
+    DeploymentDB,
+    ExecutionDB,
+    InputDB,
+    OutputDB,
+    ProgramDB,
+    TransactionDB,
+    TransitionDB,
+};
+
 mod block;
 pub use block::*;
 
@@ -42,6 +42,18 @@ use std::collections::BTreeMap;
 /// // (program_id => (mapping_name => (key => value)))
 /// IndexMap<ProgramID<N>, IndexMap<Identifier<N>, IndexMap<Key, Value>>>
 /// ```
+///
+/// In addition, `ProgramStorage` records the finalize outputs of each finalized transition:
+/// ```text
+/// // (transition_id => [output])
+/// IndexMap<N::TransitionID, Vec<Value<N>>>
+/// ```
+///
+/// And the edition history of each deployed program:
+/// ```text
+/// // (program_id => (edition => deployment checksum))
+/// IndexMap<ProgramID<N>, IndexMap<u16, Field<N>>>
+/// ```
 pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of `program ID` to `[mapping name]`.
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
@@ -53,6 +65,10 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `transition ID` to `[finalize output]`.
+    type FinalizeOutputsMap: for<'a> Map<'a, N::TransitionID, Vec<Value<N>>>;
+    /// The mapping of `program ID` to `[(edition, deployment checksum)]`.
+    type EditionMap: for<'a> Map<'a, ProgramID<N>, IndexMap<u16, Field<N>>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -67,6 +83,10 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the finalize outputs map.
+    fn finalize_outputs_map(&self) -> &Self::FinalizeOutputsMap;
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -78,6 +98,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.finalize_outputs_map().start_atomic();
+        self.edition_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -87,6 +109,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.finalize_outputs_map().is_atomic_in_progress()
+            || self.edition_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -96,6 +120,41 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.finalize_outputs_map().abort_atomic();
+        self.edition_map().abort_atomic();
+    }
+
+    /// Saves a checkpoint of the pending atomic batch write operations.
+    fn atomic_checkpoint(&self) {
+        self.program_id_map().atomic_checkpoint();
+        self.mapping_id_map().atomic_checkpoint();
+        self.key_value_id_map().atomic_checkpoint();
+        self.key_map().atomic_checkpoint();
+        self.value_map().atomic_checkpoint();
+        self.finalize_outputs_map().atomic_checkpoint();
+        self.edition_map().atomic_checkpoint();
+    }
+
+    /// Removes the latest checkpoint of the pending atomic batch write operations.
+    fn clear_latest_checkpoint(&self) {
+        self.program_id_map().clear_latest_checkpoint();
+        self.mapping_id_map().clear_latest_checkpoint();
+        self.key_value_id_map().clear_latest_checkpoint();
+        self.key_map().clear_latest_checkpoint();
+        self.value_map().clear_latest_checkpoint();
+        self.finalize_outputs_map().clear_latest_checkpoint();
+        self.edition_map().clear_latest_checkpoint();
+    }
+
+    /// Rewinds the pending atomic batch write operations to the latest checkpoint.
+    fn atomic_rewind(&self) {
+        self.program_id_map().atomic_rewind();
+        self.mapping_id_map().atomic_rewind();
+        self.key_value_id_map().atomic_rewind();
+        self.key_map().atomic_rewind();
+        self.value_map().atomic_rewind();
+        self.finalize_outputs_map().atomic_rewind();
+        self.edition_map().atomic_rewind();
     }
 
     /// Finishes an atomic batch write operation.
@@ -104,20 +163,22 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.finalize_outputs_map().finish_atomic()?;
+        self.edition_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
     fn initialize_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<()> {
         // Ensure the mapping name does not already exist.
-        if self.mapping_id_map().contains_key(&(*program_id, *mapping_name))? {
+        if self.mapping_id_map().contains_key_speculative(&(*program_id, *mapping_name))? {
             bail!("Illegal operation: mapping '{mapping_name}' already exists in storage - cannot initialize again.")
         }
 
         // Compute the mapping ID.
         let mapping_id = N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())?;
         // Ensure the mapping ID does not already exist.
-        if self.key_value_id_map().contains_key(&mapping_id)? {
+        if self.key_value_id_map().contains_key_speculative(&mapping_id)? {
             bail!("Illegal operation: mapping ID '{mapping_id}' already exists in storage - cannot initialize again.")
         }
 
@@ -165,7 +226,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         // Ensure the key ID does not already exist.
-        if self.key_map().contains_key(&key_id)? {
+        if self.key_map().contains_key_speculative(&key_id)? {
             bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert again.")
         }
         // Retrieve the key-value IDs for the mapping ID.
@@ -222,7 +283,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             }
         };
         // If the key ID does not exist, insert it in the key-value ID map.
-        if !self.key_map().contains_key(&key_id)? {
+        if !self.key_map().contains_key_speculative(&key_id)? {
             // Ensure the key ID does not already exist.
             // If this fails, then there is inconsistent state, and likely data corruption.
             if key_value_ids.contains_key(&key_id) {
@@ -343,6 +404,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         atomic_write_batch!(self, {
             // Update the mapping names.
             self.program_id_map().remove(program_id)?;
+            // Remove the edition history.
+            self.edition_map().remove(program_id)?;
 
             // Remove each mapping.
             for mapping_name in mapping_names.iter() {
@@ -380,14 +443,44 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the finalize outputs for the given `transition ID` in storage.
+    /// If the `transition ID` already exists, the finalize outputs are overwritten.
+    fn insert_finalize_outputs(&self, transition_id: &N::TransitionID, outputs: Vec<Value<N>>) -> Result<()> {
+        self.finalize_outputs_map().insert(*transition_id, outputs)
+    }
+
+    /// Removes the finalize outputs for the given `transition ID` from storage.
+    fn remove_finalize_outputs(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.finalize_outputs_map().remove(transition_id)
+    }
+
+    /// Records the given `edition` and deployment `checksum` in the edition history of the given `program ID`.
+    /// If the program has a recorded edition, the given `edition` must be the next edition.
+    fn insert_edition(&self, program_id: &ProgramID<N>, edition: u16, checksum: Field<N>) -> Result<()> {
+        // Retrieve the edition history.
+        let mut editions = match self.edition_map().get_speculative(program_id)? {
+            Some(editions) => cow_to_cloned!(editions),
+            None => IndexMap::new(),
+        };
+        // Ensure the edition is the next edition, if the program has a recorded edition.
+        if let Some(latest_edition) = editions.keys().last() {
+            if latest_edition.checked_add(1) != Some(edition) {
+                bail!("Illegal operation: edition '{edition}' does not follow edition '{latest_edition}' of '{program_id}'")
+            }
+        }
+        // Update the edition history.
+        editions.insert(edition, checksum);
+        self.edition_map().insert(*program_id, editions)
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program(&self, program_id: &ProgramID<N>) -> Result<bool> {
-        self.program_id_map().contains_key(program_id)
+        self.program_id_map().contains_key_speculative(program_id)
     }
 
     /// Returns `true` if the given `program ID` and `mapping name` exist.
     fn contains_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
-        self.mapping_id_map().contains_key(&(*program_id, *mapping_name))
+        self.mapping_id_map().contains_key_speculative(&(*program_id, *mapping_name))
     }
 
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
@@ -405,7 +498,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Return whether the key ID exists.
-        self.key_map().contains_key(&key_id)
+        self.key_map().contains_key_speculative(&key_id)
     }
 
     /// Returns the mapping names for the given `program ID`.
@@ -440,7 +533,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Ensure the key ID exists.
-        match self.key_map().contains_key(&key_id)? {
+        match self.key_map().contains_key_speculative(&key_id)? {
             true => Ok(Some(key_id)),
             false => Ok(None),
         }
@@ -477,6 +570,27 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the finalize outputs for the given `transition ID`.
+    fn get_finalize_outputs(&self, transition_id: &N::TransitionID) -> Result<Option<Vec<Value<N>>>> {
+        match self.finalize_outputs_map().get_speculative(transition_id)? {
+            Some(outputs) => Ok(Some(cow_to_cloned!(outputs))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the latest edition for the given `program ID`.
+    fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
+        Ok(self.get_edition_history(program_id)?.and_then(|editions| editions.keys().last().copied()))
+    }
+
+    /// Returns the edition history, as `(edition, deployment checksum)` pairs, for the given `program ID`.
+    fn get_edition_history(&self, program_id: &ProgramID<N>) -> Result<Option<IndexMap<u16, Field<N>>>> {
+        match self.edition_map().get_speculative(program_id)? {
+            Some(editions) => Ok(Some(cow_to_cloned!(editions))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the checksum.
     fn get_checksum(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -513,6 +627,10 @@ pub struct ProgramMemory<N: Network> {
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
+    /// The finalize outputs map.
+    finalize_outputs_map: MemoryMap<N::TransitionID, Vec<Value<N>>>,
+    /// The edition map.
+    edition_map: MemoryMap<ProgramID<N>, IndexMap<u16, Field<N>>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -524,6 +642,8 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type FinalizeOutputsMap = MemoryMap<N::TransitionID, Vec<Value<N>>>;
+    type EditionMap = MemoryMap<ProgramID<N>, IndexMap<u16, Field<N>>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -533,6 +653,8 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            finalize_outputs_map: MemoryMap::default(),
+            edition_map: MemoryMap::default(),
             dev,
         })
     }
@@ -562,6 +684,16 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
         &self.value_map
     }
 
+    /// Returns the finalize outputs map.
+    fn finalize_outputs_map(&self) -> &Self::FinalizeOutputsMap {
+        &self.finalize_outputs_map
+    }
+
+    /// Returns the edition map.
+    fn edition_map(&self) -> &Self::EditionMap {
+        &self.edition_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -640,6 +772,23 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.remove_program(program_id)
     }
 
+    /// Stores the finalize outputs for the given `transition ID` in storage.
+    /// If the `transition ID` already exists, the finalize outputs are overwritten.
+    pub fn insert_finalize_outputs(&self, transition_id: &N::TransitionID, outputs: Vec<Value<N>>) -> Result<()> {
+        self.storage.insert_finalize_outputs(transition_id, outputs)
+    }
+
+    /// Removes the finalize outputs for the given `transition ID` from storage.
+    pub fn remove_finalize_outputs(&self, transition_id: &N::TransitionID) -> Result<()> {
+        self.storage.remove_finalize_outputs(transition_id)
+    }
+
+    /// Records the given `edition` and deployment `checksum` in the edition history of the given `program ID`.
+    /// If the program has a recorded edition, the given `edition` must be the next edition.
+    pub fn insert_edition(&self, program_id: &ProgramID<N>, edition: u16, checksum: Field<N>) -> Result<()> {
+        self.storage.insert_edition(program_id, edition, checksum)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -655,6 +804,21 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.abort_atomic();
     }
 
+    /// Saves a checkpoint of the pending atomic batch write operations.
+    pub fn atomic_checkpoint(&self) {
+        self.storage.atomic_checkpoint();
+    }
+
+    /// Removes the latest checkpoint of the pending atomic batch write operations.
+    pub fn clear_latest_checkpoint(&self) {
+        self.storage.clear_latest_checkpoint();
+    }
+
+    /// Rewinds the pending atomic batch write operations to the latest checkpoint.
+    pub fn atomic_rewind(&self) {
+        self.storage.atomic_rewind();
+    }
+
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
         self.storage.finish_atomic()
@@ -703,6 +867,21 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value(program_id, mapping_name, key)
     }
+
+    /// Returns the finalize outputs for the given `transition ID`.
+    pub fn get_finalize_outputs(&self, transition_id: &N::TransitionID) -> Result<Option<Vec<Value<N>>>> {
+        self.storage.get_finalize_outputs(transition_id)
+    }
+
+    /// Returns the latest edition for the given `program ID`.
+    pub fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
+        self.storage.get_edition(program_id)
+    }
+
+    /// Returns the edition history, as `(edition, deployment checksum)` pairs, for the given `program ID`.
+    pub fn get_edition_history(&self, program_id: &ProgramID<N>) -> Result<Option<IndexMap<u16, Field<N>>>> {
+        self.storage.get_edition_history(program_id)
+    }
 }
 
 #[cfg(test)]
@@ -924,6 +1103,39 @@ mod tests {
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
 
+    #[test]
+    fn test_get_value_in_atomic_batch() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare a key and value.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let value = Value::from_str("987654321u128").unwrap();
+
+        // Initialize a new program store, and initialize the mapping.
+        let program_store = ProgramMemory::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        // Start an atomic write batch.
+        program_store.start_atomic();
+
+        // Update a (key, value) pair, which is queued in the batch.
+        program_store.update_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        // Ensure the key and value are visible before the batch is finished.
+        assert!(program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(value, program_store.get_value(&program_id, &mapping_name, &key).unwrap().unwrap());
+
+        // Ensure removing the key is visible before the batch is finished.
+        program_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert!(program_store.get_value(&program_id, &mapping_name, &key).unwrap().is_none());
+
+        // Update the (key, value) pair again, and finish the batch.
+        program_store.update_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        program_store.finish_atomic().unwrap();
+        assert_eq!(value, program_store.get_value(&program_id, &mapping_name, &key).unwrap().unwrap());
+    }
+
     #[test]
     fn test_remove_key_value() {
         // Initialize a program ID and mapping name.
@@ -1175,4 +1387,66 @@ mod tests {
         check_initialize_insert_remove(&program_store, program_id, mapping_name);
         check_initialize_update_remove(&program_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_insert_get_remove_finalize_outputs() {
+        // Initialize a transition ID.
+        let transition_id = <CurrentNetwork as Network>::TransitionID::from(Field::from_u64(1));
+
+        // Initialize a new program store.
+        let program_store = ProgramStore::<CurrentNetwork, ProgramMemory<_>>::open(None).unwrap();
+        // Ensure the finalize outputs do not exist.
+        assert!(program_store.get_finalize_outputs(&transition_id).unwrap().is_none());
+
+        // Insert the finalize outputs.
+        let outputs = vec![Value::from_str("1u64").unwrap(), Value::from_str("true").unwrap()];
+        program_store.insert_finalize_outputs(&transition_id, outputs.clone()).unwrap();
+        // Ensure the finalize outputs exist.
+        assert_eq!(program_store.get_finalize_outputs(&transition_id).unwrap(), Some(outputs));
+
+        // Overwrite the finalize outputs.
+        let outputs = vec![Value::from_str("2u64").unwrap()];
+        program_store.insert_finalize_outputs(&transition_id, outputs.clone()).unwrap();
+        // Ensure the finalize outputs were overwritten.
+        assert_eq!(program_store.get_finalize_outputs(&transition_id).unwrap(), Some(outputs));
+
+        // Remove the finalize outputs.
+        program_store.remove_finalize_outputs(&transition_id).unwrap();
+        // Ensure the finalize outputs do not exist.
+        assert!(program_store.get_finalize_outputs(&transition_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_atomic_rewind_key_value() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Prepare a key and value.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let value = Value::from_str("987654321u128").unwrap();
+
+        // Initialize a new program store, and initialize the mapping.
+        let program_store = ProgramMemory::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+
+        // Insert a (key, value) pair after a checkpoint, and rewind to the checkpoint.
+        program_store.start_atomic();
+        program_store.atomic_checkpoint();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        program_store.atomic_rewind();
+        program_store.finish_atomic().unwrap();
+        // Ensure the key did not get initialized.
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert!(program_store.get_value(&program_id, &mapping_name, &key).unwrap().is_none());
+
+        // Insert a (key, value) pair after a checkpoint, and clear the checkpoint.
+        program_store.start_atomic();
+        program_store.atomic_checkpoint();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        program_store.clear_latest_checkpoint();
+        program_store.finish_atomic().unwrap();
+        // Ensure the key got initialized.
+        assert!(program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(program_store.get_value(&program_id, &mapping_name, &key).unwrap(), Some(value));
+    }
 }
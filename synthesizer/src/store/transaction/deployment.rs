@@ -30,6 +30,7 @@ use crate::{
     },
 };
 use console::{
+    account::Signature,
     network::prelude::*,
     program::{Identifier, ProgramID},
 };
@@ -40,18 +41,20 @@ use std::borrow::Cow;
 
 /// A trait for deployment storage.
 pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
-    /// The mapping of `transaction ID` to `program ID`.
-    type IDMap: for<'a> Map<'a, N::TransactionID, ProgramID<N>>;
-    /// The mapping of `program ID` to `edition`.
+    /// The mapping of `transaction ID` to `(program ID, edition)`.
+    type IDMap: for<'a> Map<'a, N::TransactionID, (ProgramID<N>, u16)>;
+    /// The mapping of `program ID` to the latest accepted `edition`.
     type EditionMap: for<'a> Map<'a, ProgramID<N>, u16>;
-    /// The mapping of `(program ID, edition)` to `transaction ID`.
+    /// The mapping of `(program ID, edition)` to the accepted `transaction ID`.
     type ReverseIDMap: for<'a> Map<'a, (ProgramID<N>, u16), N::TransactionID>;
-    /// The mapping of `(program ID, edition)` to `program`.
-    type ProgramMap: for<'a> Map<'a, (ProgramID<N>, u16), Program<N>>;
-    /// The mapping of `(program ID, function name, edition)` to `verifying key`.
-    type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
-    /// The mapping of `(program ID, function name, edition)` to `certificate`.
-    type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `transaction ID` to `program`.
+    type ProgramMap: for<'a> Map<'a, N::TransactionID, Program<N>>;
+    /// The mapping of `(transaction ID, function name)` to `verifying key`.
+    type VerifyingKeyMap: for<'a> Map<'a, (N::TransactionID, Identifier<N>), VerifyingKey<N>>;
+    /// The mapping of `(transaction ID, function name)` to `certificate`.
+    type CertificateMap: for<'a> Map<'a, (N::TransactionID, Identifier<N>), Certificate<N>>;
+    /// The mapping of `transaction ID` to `upgrade signature`.
+    type SignatureMap: for<'a> Map<'a, N::TransactionID, Signature<N>>;
     /// The mapping of `transaction ID` to `(fee transition ID, global state root, inclusion proof)`.
     type FeeMap: for<'a> Map<'a, N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
     /// The mapping of `fee transition ID` to `transaction ID`.
@@ -74,6 +77,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the signature map.
+    fn signature_map(&self) -> &Self::SignatureMap;
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap;
     /// Returns the reverse fee map.
@@ -94,6 +99,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.signature_map().start_atomic();
         self.fee_map().start_atomic();
         self.reverse_fee_map().start_atomic();
         self.transition_store().start_atomic();
@@ -107,6 +113,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.signature_map().is_atomic_in_progress()
             || self.fee_map().is_atomic_in_progress()
             || self.reverse_fee_map().is_atomic_in_progress()
             || self.transition_store().is_atomic_in_progress()
@@ -120,6 +127,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.signature_map().abort_atomic();
         self.fee_map().abort_atomic();
         self.reverse_fee_map().abort_atomic();
         self.transition_store().abort_atomic();
@@ -133,6 +141,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.signature_map().finish_atomic()?;
         self.fee_map().finish_atomic()?;
         self.reverse_fee_map().finish_atomic()?;
         self.transition_store().finish_atomic()
@@ -161,22 +170,22 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         let program_id = *program.id();
 
         atomic_write_batch!(self, {
-            // Store the program ID.
-            self.id_map().insert(*transaction_id, program_id)?;
-            // Store the edition.
-            self.edition_map().insert(program_id, edition)?;
-
-            // Store the reverse program ID.
-            self.reverse_id_map().insert((program_id, edition), *transaction_id)?;
+            // Store the program ID and edition.
+            // Note: The deployment is only indexed by its program ID once it is accepted, in `insert_accepted`.
+            self.id_map().insert(*transaction_id, (program_id, edition))?;
             // Store the program.
-            self.program_map().insert((program_id, edition), program.clone())?;
+            self.program_map().insert(*transaction_id, program.clone())?;
 
             // Store the verifying keys and certificates.
             for (function_name, (verifying_key, certificate)) in deployment.verifying_keys() {
                 // Store the verifying key.
-                self.verifying_key_map().insert((program_id, *function_name, edition), verifying_key.clone())?;
+                self.verifying_key_map().insert((*transaction_id, *function_name), verifying_key.clone())?;
                 // Store the certificate.
-                self.certificate_map().insert((program_id, *function_name, edition), certificate.clone())?;
+                self.certificate_map().insert((*transaction_id, *function_name), certificate.clone())?;
+            }
+            // Store the upgrade signature, if the deployment is an upgrade.
+            if let Some(signature) = deployment.signature() {
+                self.signature_map().insert(*transaction_id, *signature)?;
             }
 
             // Store the fee.
@@ -195,23 +204,53 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Indexes the deployment in the given `transaction ID` as the latest edition of its program,
+    /// once the deployment is accepted during finalize.
+    /// Note: A rejected deployment is never indexed, and so it never overwrites an accepted edition.
+    fn insert_accepted(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.id_map().get_speculative(transaction_id)? {
+            Some(id) => cow_to_copied!(id),
+            None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
+        };
+        // Ensure the edition of the program is not already taken.
+        if self.reverse_id_map().contains_key_speculative(&(program_id, edition))? {
+            bail!("Program '{program_id}' (edition {edition}) was already accepted")
+        }
+
+        atomic_write_batch!(self, {
+            // Store the edition.
+            self.edition_map().insert(program_id, edition)?;
+            // Store the reverse program ID.
+            self.reverse_id_map().insert((program_id, edition), *transaction_id)?;
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Removes the deployment transaction for the given `transaction ID`.
     fn remove(&self, transaction_id: &N::TransactionID) -> Result<()> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.id_map().get(transaction_id)? {
+            Some(id) => cow_to_copied!(id),
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
-            None => bail!("Failed to locate the edition for program '{program_id}'"),
-        };
         // Retrieve the program.
-        let program = match self.program_map().get(&(program_id, edition))? {
+        let program = match self.program_map().get(transaction_id)? {
             Some(program) => cow_to_cloned!(program),
             None => bail!("Failed to locate program '{program_id}' for transaction '{transaction_id}'"),
         };
+        // Determine if the deployment was accepted.
+        let is_accepted = match self.reverse_id_map().get(&(program_id, edition))? {
+            Some(candidate) => cow_to_copied!(candidate) == *transaction_id,
+            None => false,
+        };
+        // Determine the previous edition, if the transaction upgraded the program.
+        let previous_edition = match edition.checked_sub(1) {
+            Some(previous) if self.reverse_id_map().contains_key(&(program_id, previous))? => Some(previous),
+            _ => None,
+        };
         // Retrieve the fee transition ID.
         let (transition_id, _, _) = match self.fee_map().get(transaction_id)? {
             Some(fee_id) => cow_to_cloned!(fee_id),
@@ -221,21 +260,26 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_write_batch!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
-
-            // Remove the reverse program ID.
-            self.reverse_id_map().remove(&(program_id, edition))?;
+            // Remove the index of the deployment, restoring the previous edition if this transaction upgraded the program.
+            if is_accepted {
+                match previous_edition {
+                    Some(previous_edition) => self.edition_map().insert(program_id, previous_edition)?,
+                    None => self.edition_map().remove(&program_id)?,
+                }
+                self.reverse_id_map().remove(&(program_id, edition))?;
+            }
             // Remove the program.
-            self.program_map().remove(&(program_id, edition))?;
+            self.program_map().remove(transaction_id)?;
 
             // Remove the verifying keys and certificates.
             for function_name in program.functions().keys() {
                 // Remove the verifying key.
-                self.verifying_key_map().remove(&(program_id, *function_name, edition))?;
+                self.verifying_key_map().remove(&(*transaction_id, *function_name))?;
                 // Remove the certificate.
-                self.certificate_map().remove(&(program_id, *function_name, edition))?;
+                self.certificate_map().remove(&(*transaction_id, *function_name))?;
             }
+            // Remove the upgrade signature.
+            self.signature_map().remove(transaction_id)?;
 
             // Remove the fee.
             self.fee_map().remove(transaction_id)?;
@@ -279,12 +323,12 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         // Retrieve the program ID.
         match self.id_map().get(transaction_id)? {
-            Some(program_id) => Ok(Some(cow_to_copied!(program_id))),
+            Some(id) => Ok(Some(cow_to_copied!(id).0)),
             None => Ok(None),
         }
     }
 
-    /// Returns the edition for the given `program ID`.
+    /// Returns the latest accepted edition for the given `program ID`.
     fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         match self.edition_map().get(program_id)? {
             Some(edition) => Ok(Some(cow_to_copied!(edition))),
@@ -292,17 +336,25 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the edition of the program deployed in the given `transaction ID`.
+    fn get_deployment_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        match self.id_map().get(transaction_id)? {
+            Some(id) => Ok(Some(cow_to_copied!(id).1)),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
-        // Retrieve the edition.
-        let edition = match self.get_edition(program_id)? {
-            Some(edition) => edition,
+        // Retrieve the transaction ID of the latest accepted edition.
+        let transaction_id = match self.find_transaction_id_from_program_id(program_id)? {
+            Some(transaction_id) => transaction_id,
             None => return Ok(None),
         };
         // Retrieve the program.
-        match self.program_map().get(&(*program_id, edition))? {
+        match self.program_map().get(&transaction_id)? {
             Some(program) => Ok(Some(cow_to_cloned!(program))),
-            None => bail!("Failed to get program '{program_id}' (edition {edition})"),
+            None => bail!("Failed to get program '{program_id}' (transaction '{transaction_id}')"),
         }
     }
 
@@ -312,15 +364,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         program_id: &ProgramID<N>,
         function_name: &Identifier<N>,
     ) -> Result<Option<VerifyingKey<N>>> {
-        // Retrieve the edition.
-        let edition = match self.get_edition(program_id)? {
-            Some(edition) => edition,
+        // Retrieve the transaction ID of the latest accepted edition.
+        let transaction_id = match self.find_transaction_id_from_program_id(program_id)? {
+            Some(transaction_id) => transaction_id,
             None => return Ok(None),
         };
         // Retrieve the verifying key.
-        match self.verifying_key_map().get(&(*program_id, *function_name, edition))? {
+        match self.verifying_key_map().get(&(transaction_id, *function_name))? {
             Some(verifying_key) => Ok(Some(cow_to_cloned!(verifying_key))),
-            None => bail!("Failed to get the verifying key for '{program_id}/{function_name}' (edition {edition})"),
+            None => bail!("Failed to get the verifying key for '{program_id}/{function_name}'"),
         }
     }
 
@@ -330,32 +382,27 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         program_id: &ProgramID<N>,
         function_name: &Identifier<N>,
     ) -> Result<Option<Certificate<N>>> {
-        // Retrieve the edition.
-        let edition = match self.get_edition(program_id)? {
-            Some(edition) => edition,
+        // Retrieve the transaction ID of the latest accepted edition.
+        let transaction_id = match self.find_transaction_id_from_program_id(program_id)? {
+            Some(transaction_id) => transaction_id,
             None => return Ok(None),
         };
         // Retrieve the certificate.
-        match self.certificate_map().get(&(*program_id, *function_name, edition))? {
+        match self.certificate_map().get(&(transaction_id, *function_name))? {
             Some(certificate) => Ok(Some(cow_to_cloned!(certificate))),
-            None => bail!("Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"),
+            None => bail!("Failed to get the certificate for '{program_id}/{function_name}'"),
         }
     }
 
     /// Returns the deployment for the given `transaction ID`.
     fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
-        // Retrieve the program ID.
-        let program_id = match self.get_program_id(transaction_id)? {
-            Some(edition) => edition,
+        // Retrieve the program ID and edition.
+        let (program_id, edition) = match self.id_map().get(transaction_id)? {
+            Some(id) => cow_to_copied!(id),
             None => return Ok(None),
         };
-        // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
-            Some(edition) => edition,
-            None => bail!("Failed to get the edition for program '{program_id}'"),
-        };
         // Retrieve the program.
-        let program = match self.program_map().get(&(program_id, edition))? {
+        let program = match self.program_map().get(transaction_id)? {
             Some(program) => cow_to_cloned!(program),
             None => bail!("Failed to get the deployed program '{program_id}' (edition {edition})"),
         };
@@ -366,12 +413,12 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Retrieve the verifying keys and certificates.
         for function_name in program.functions().keys() {
             // Retrieve the verifying key.
-            let verifying_key = match self.verifying_key_map().get(&(program_id, *function_name, edition))? {
+            let verifying_key = match self.verifying_key_map().get(&(*transaction_id, *function_name))? {
                 Some(verifying_key) => cow_to_cloned!(verifying_key),
                 None => bail!("Failed to get the verifying key for '{program_id}/{function_name}' (edition {edition})"),
             };
             // Retrieve the certificate.
-            let certificate = match self.certificate_map().get(&(program_id, *function_name, edition))? {
+            let certificate = match self.certificate_map().get(&(*transaction_id, *function_name))? {
                 Some(certificate) => cow_to_cloned!(certificate),
                 None => bail!("Failed to get the certificate for '{program_id}/{function_name}' (edition {edition})"),
             };
@@ -379,8 +426,11 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             verifying_keys.push((*function_name, (verifying_key, certificate)));
         }
 
+        // Retrieve the upgrade signature, if the deployment is an upgrade.
+        let signature = self.signature_map().get(transaction_id)?.map(|signature| cow_to_copied!(signature));
+
         // Return the deployment.
-        Ok(Some(Deployment::new(edition, program, verifying_keys)?))
+        Ok(Some(Deployment::from(edition, program, verifying_keys, signature)?))
     }
 
     /// Returns the fee for the given `transaction ID`.
@@ -425,17 +475,19 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
 #[allow(clippy::type_complexity)]
 pub struct DeploymentMemory<N: Network> {
     /// The ID map.
-    id_map: MemoryMap<N::TransactionID, ProgramID<N>>,
+    id_map: MemoryMap<N::TransactionID, (ProgramID<N>, u16)>,
     /// The edition map.
     edition_map: MemoryMap<ProgramID<N>, u16>,
     /// The reverse ID map.
     reverse_id_map: MemoryMap<(ProgramID<N>, u16), N::TransactionID>,
     /// The program map.
-    program_map: MemoryMap<(ProgramID<N>, u16), Program<N>>,
+    program_map: MemoryMap<N::TransactionID, Program<N>>,
     /// The verifying key map.
-    verifying_key_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>,
+    verifying_key_map: MemoryMap<(N::TransactionID, Identifier<N>), VerifyingKey<N>>,
     /// The certificate map.
-    certificate_map: MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>,
+    certificate_map: MemoryMap<(N::TransactionID, Identifier<N>), Certificate<N>>,
+    /// The signature map.
+    signature_map: MemoryMap<N::TransactionID, Signature<N>>,
     /// The fee map.
     fee_map: MemoryMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>,
     /// The reverse fee map.
@@ -446,12 +498,13 @@ pub struct DeploymentMemory<N: Network> {
 
 #[rustfmt::skip]
 impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
-    type IDMap = MemoryMap<N::TransactionID, ProgramID<N>>;
+    type IDMap = MemoryMap<N::TransactionID, (ProgramID<N>, u16)>;
     type EditionMap = MemoryMap<ProgramID<N>, u16>;
     type ReverseIDMap = MemoryMap<(ProgramID<N>, u16), N::TransactionID>;
-    type ProgramMap = MemoryMap<(ProgramID<N>, u16), Program<N>>;
-    type VerifyingKeyMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
-    type CertificateMap = MemoryMap<(ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    type ProgramMap = MemoryMap<N::TransactionID, Program<N>>;
+    type VerifyingKeyMap = MemoryMap<(N::TransactionID, Identifier<N>), VerifyingKey<N>>;
+    type CertificateMap = MemoryMap<(N::TransactionID, Identifier<N>), Certificate<N>>;
+    type SignatureMap = MemoryMap<N::TransactionID, Signature<N>>;
     type FeeMap = MemoryMap<N::TransactionID, (N::TransitionID, N::StateRoot, Option<Proof<N>>)>;
     type ReverseFeeMap = MemoryMap<N::TransitionID, N::TransactionID>;
     type TransitionStorage = TransitionMemory<N>;
@@ -465,6 +518,7 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
             program_map: MemoryMap::default(),
             verifying_key_map: MemoryMap::default(),
             certificate_map: MemoryMap::default(),
+            signature_map: MemoryMap::default(),
             fee_map: MemoryMap::default(),
             reverse_fee_map: MemoryMap::default(),
             transition_store,
@@ -501,6 +555,11 @@ impl<N: Network> DeploymentStorage<N> for DeploymentMemory<N> {
         &self.certificate_map
     }
 
+    /// Returns the signature map.
+    fn signature_map(&self) -> &Self::SignatureMap {
+        &self.signature_map
+    }
+
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap {
         &self.fee_map
@@ -550,6 +609,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.remove(transaction_id)
     }
 
+    /// Indexes the deployment in the given `transaction ID` as the latest edition of its program.
+    pub fn insert_accepted(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.insert_accepted(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -587,11 +651,16 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_deployment(transaction_id)
     }
 
-    /// Returns the edition for the given `program ID`.
+    /// Returns the latest accepted edition for the given `program ID`.
     pub fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the edition of the program deployed in the given `transaction ID`.
+    pub fn get_deployment_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.storage.get_deployment_edition(transaction_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -654,12 +723,9 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.id_map().keys()
     }
 
-    /// Returns an iterator over the program IDs, for all deployments.
+    /// Returns an iterator over the program IDs, for all accepted deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
-        self.storage.id_map().values().map(|id| match id {
-            Cow::Borrowed(id) => Cow::Borrowed(id),
-            Cow::Owned(id) => Cow::Owned(id),
-        })
+        self.storage.edition_map().keys()
     }
 
     /// Returns an iterator over the programs, for all deployments.
@@ -670,17 +736,17 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         })
     }
 
-    /// Returns an iterator over the `((program ID, function name, edition), verifying key)`, for all deployments.
+    /// Returns an iterator over the `((transaction ID, function name), verifying key)`, for all deployments.
     pub fn verifying_keys(
         &self,
-    ) -> impl '_ + Iterator<Item = (Cow<'_, (ProgramID<N>, Identifier<N>, u16)>, Cow<'_, VerifyingKey<N>>)> {
+    ) -> impl '_ + Iterator<Item = (Cow<'_, (N::TransactionID, Identifier<N>)>, Cow<'_, VerifyingKey<N>>)> {
         self.storage.verifying_key_map().iter()
     }
 
-    /// Returns an iterator over the `((program ID, function name, edition), certificate)`, for all deployments.
+    /// Returns an iterator over the `((transaction ID, function name), certificate)`, for all deployments.
     pub fn certificates(
         &self,
-    ) -> impl '_ + Iterator<Item = (Cow<'_, (ProgramID<N>, Identifier<N>, u16)>, Cow<'_, Certificate<N>>)> {
+    ) -> impl '_ + Iterator<Item = (Cow<'_, (N::TransactionID, Identifier<N>)>, Cow<'_, Certificate<N>>)> {
         self.storage.certificate_map().iter()
     }
 }
@@ -749,6 +815,13 @@ mod tests {
         // Insert the deployment.
         deployment_store.insert(&transaction).unwrap();
 
+        // Ensure the transaction ID is not found, until the deployment is accepted.
+        let candidate = deployment_store.find_transaction_id_from_program_id(&program_id).unwrap();
+        assert_eq!(None, candidate);
+
+        // Accept the deployment.
+        deployment_store.insert_accepted(&transaction_id).unwrap();
+
         // Find the transaction ID.
         let candidate = deployment_store.find_transaction_id_from_program_id(&program_id).unwrap();
         assert_eq!(Some(transaction_id), candidate);
@@ -760,4 +833,54 @@ mod tests {
         let candidate = deployment_store.find_transaction_id_from_program_id(&program_id).unwrap();
         assert_eq!(None, candidate);
     }
+
+    #[test]
+    fn test_unaccepted_deployment_is_not_indexed() {
+        let rng = &mut TestRng::default();
+
+        // Sample the deployment transaction.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let transaction_id = transaction.id();
+        let deployment = match transaction {
+            Transaction::Deploy(_, ref deployment, _) => *deployment.clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let (program_id, edition) = (*deployment.program_id(), deployment.edition());
+
+        // Sample a duplicate deployment of the same program and edition, with a different fee.
+        let duplicate = Transaction::from_deployment(deployment, crate::vm::test_helpers::sample_fee()).unwrap();
+        let duplicate_id = duplicate.id();
+        assert_ne!(transaction_id, duplicate_id);
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        // Initialize a new deployment store.
+        let deployment_store = DeploymentMemory::open(transition_store).unwrap();
+
+        // Insert and accept the deployment.
+        deployment_store.insert(&transaction).unwrap();
+        deployment_store.insert_accepted(&transaction_id).unwrap();
+
+        // Insert the duplicate deployment, which is not accepted.
+        deployment_store.insert(&duplicate).unwrap();
+
+        // Ensure the duplicate is retrievable, but does not overwrite the accepted deployment.
+        assert_eq!(Some(duplicate.clone()), deployment_store.get_transaction(&duplicate_id).unwrap());
+        assert_eq!(Some(transaction_id), deployment_store.find_transaction_id_from_program_id(&program_id).unwrap());
+        assert_eq!(Some(edition), deployment_store.get_edition(&program_id).unwrap());
+
+        // Ensure the duplicate cannot be accepted for the same edition.
+        assert!(deployment_store.insert_accepted(&duplicate_id).is_err());
+
+        // Remove the duplicate, and ensure the accepted deployment is still indexed.
+        deployment_store.remove(&duplicate_id).unwrap();
+        assert_eq!(None, deployment_store.get_transaction(&duplicate_id).unwrap());
+        assert_eq!(Some(transaction_id), deployment_store.find_transaction_id_from_program_id(&program_id).unwrap());
+        assert_eq!(Some(transaction), deployment_store.get_transaction(&transaction_id).unwrap());
+
+        // Remove the accepted deployment.
+        deployment_store.remove(&transaction_id).unwrap();
+        assert_eq!(None, deployment_store.find_transaction_id_from_program_id(&program_id).unwrap());
+        assert_eq!(None, deployment_store.get_edition(&program_id).unwrap());
+    }
 }
@@ -46,6 +46,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
     /// The mapping of `transaction ID` to `(global state root, (optional) inclusion proof)`.
     type FeeMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transition ID` to `transition`, for the transitions of rejected executions.
+    type RejectedMap: for<'a> Map<'a, N::TransitionID, Transition<N>>;
 
     /// Initializes the execution storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self>;
@@ -60,6 +62,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn inclusion_map(&self) -> &Self::InclusionMap;
     /// Returns the fee map.
     fn fee_map(&self) -> &Self::FeeMap;
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
@@ -73,6 +77,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.transition_store().start_atomic();
         self.inclusion_map().start_atomic();
         self.fee_map().start_atomic();
+        self.rejected_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -82,6 +87,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             || self.transition_store().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
             || self.fee_map().is_atomic_in_progress()
+            || self.rejected_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -91,6 +97,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.transition_store().abort_atomic();
         self.inclusion_map().abort_atomic();
         self.fee_map().abort_atomic();
+        self.rejected_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -99,7 +106,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_id_map().finish_atomic()?;
         self.transition_store().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
-        self.fee_map().finish_atomic()
+        self.fee_map().finish_atomic()?;
+        self.rejected_map().finish_atomic()
     }
 
     /// Stores the given `execution transaction` pair into storage.
@@ -178,6 +186,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
                 self.reverse_id_map().remove(&transition_id)?;
                 // Remove the transition.
                 self.transition_store().remove(&transition_id)?;
+                // Remove the rejected transition, if the execution was rejected.
+                self.rejected_map().remove(&transition_id)?;
             }
 
             // Remove the global state root and inclusion proof.
@@ -199,6 +209,42 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Moves the transitions of the given rejected `execution transaction` out of the transition store.
+    /// The transitions of a rejected execution are kept in the rejected map, so the transaction can still be
+    /// retrieved, while its serial numbers are not spent and its output records cannot be spent.
+    /// Note: The additional fee transition remains in the transition store, as the fee is still consumed.
+    fn reject(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Ensure the transaction is an execution.
+        let execution = match transaction {
+            Transaction::Deploy(..) => bail!("Attempted to reject a non-execution transaction in execution storage."),
+            Transaction::Execute(_, execution, _) => execution,
+        };
+
+        atomic_write_batch!(self, {
+            for transition in execution.transitions() {
+                // Store the rejected transition.
+                self.rejected_map().insert(*transition.id(), transition.clone())?;
+                // Remove the transition from the transition store.
+                self.transition_store().remove(transition.id())?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
+    /// Returns the transition for the given `transition ID`, including the transitions of rejected executions.
+    fn get_transition(&self, transition_id: &N::TransitionID) -> Result<Option<Transition<N>>> {
+        match self.transition_store().get_transition(transition_id)? {
+            Some(transition) => Ok(Some(transition)),
+            None => match self.rejected_map().get(transition_id)? {
+                Some(transition) => Ok(Some(cow_to_cloned!(transition))),
+                None => Ok(None),
+            },
+        }
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -229,7 +275,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
         // Retrieve the transitions.
         for transition_id in &transition_ids {
-            match self.transition_store().get_transition(transition_id)? {
+            match self.get_transition(transition_id)? {
                 Some(transition) => transitions.push(transition),
                 None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
             };
@@ -258,7 +304,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
         // Retrieve the transitions.
         for transition_id in &transition_ids {
-            match self.transition_store().get_transition(transition_id)? {
+            match self.get_transition(transition_id)? {
                 Some(transition) => transitions.push(transition),
                 None => bail!("Failed to get transition '{transition_id}' for transaction '{transaction_id}'"),
             };
@@ -311,6 +357,8 @@ pub struct ExecutionMemory<N: Network> {
     inclusion_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
     /// The fee map.
     fee_map: MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>,
+    /// The rejected map.
+    rejected_map: MemoryMap<N::TransitionID, Transition<N>>,
 }
 
 #[rustfmt::skip]
@@ -320,6 +368,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     type TransitionStorage = TransitionMemory<N>;
     type InclusionMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
     type FeeMap = MemoryMap<N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    type RejectedMap = MemoryMap<N::TransitionID, Transition<N>>;
 
     /// Initializes the execution storage.
     fn open(transition_store: TransitionStore<N, Self::TransitionStorage>) -> Result<Self> {
@@ -329,6 +378,7 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
             transition_store,
             inclusion_map: MemoryMap::default(),
             fee_map: MemoryMap::default(),
+            rejected_map: MemoryMap::default(),
         })
     }
 
@@ -356,6 +406,11 @@ impl<N: Network> ExecutionStorage<N> for ExecutionMemory<N> {
     fn fee_map(&self) -> &Self::FeeMap {
         &self.fee_map
     }
+
+    /// Returns the rejected map.
+    fn rejected_map(&self) -> &Self::RejectedMap {
+        &self.rejected_map
+    }
 }
 
 /// The execution store.
@@ -391,6 +446,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Moves the transitions of the given rejected `execution transaction` out of the transition store.
+    pub fn reject(&self, transaction: &Transaction<N>) -> Result<()> {
+        self.storage.reject(transaction)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, E::TransitionStorage> {
         self.storage.transition_store()
@@ -444,6 +504,13 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     }
 }
 
+impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
+    /// Returns `true` if the given transition ID belongs to a rejected execution.
+    pub fn contains_rejected_transition_id(&self, transition_id: &N::TransitionID) -> Result<bool> {
+        self.storage.rejected_map().contains_key(transition_id)
+    }
+}
+
 impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
     /// Returns an iterator over the execution transaction IDs, for all executions.
     pub fn execution_transaction_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, N::TransactionID>> {
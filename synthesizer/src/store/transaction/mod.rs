@@ -51,10 +51,22 @@ pub enum TransactionType {
     Execute,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FinalizeStatus {
+    /// A transaction whose finalize scope was applied.
+    Accepted,
+    /// A transaction whose finalize scope failed, and whose finalize writes were reverted.
+    /// Note: The transaction remains in its block, which ensures its fee is still consumed,
+    /// while the transitions of its execution are moved out of the transition store.
+    Rejected,
+}
+
 /// A trait for transaction storage.
 pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `transaction type`.
     type IDMap: for<'a> Map<'a, N::TransactionID, TransactionType>;
+    /// The mapping of `transaction ID` to `finalize status`.
+    type FinalizeStatusMap: for<'a> Map<'a, N::TransactionID, FinalizeStatus>;
     /// The deployment storage.
     type DeploymentStorage: DeploymentStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The execution storage.
@@ -67,6 +79,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the finalize status map.
+    fn finalize_status_map(&self) -> &Self::FinalizeStatusMap;
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage>;
     /// Returns the execution store.
@@ -85,6 +99,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.finalize_status_map().start_atomic();
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
     }
@@ -92,6 +107,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.finalize_status_map().is_atomic_in_progress()
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
     }
@@ -99,6 +115,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.finalize_status_map().abort_atomic();
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
     }
@@ -106,6 +123,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.finalize_status_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()
     }
@@ -145,6 +163,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         atomic_write_batch!(self, {
             // Remove the transaction type.
             self.id_map().remove(transaction_id)?;
+            // Remove the finalize status.
+            self.finalize_status_map().remove(transaction_id)?;
             // Remove the transaction.
             match transaction_type {
                 // Remove the deployment transaction.
@@ -159,6 +179,36 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the finalize status for the given `transaction`.
+    /// Note: An accepted deployment is indexed as the latest edition of its program at this point,
+    /// and the transitions of a rejected execution are moved out of the transition store.
+    fn insert_finalize_status(&self, transaction: &Transaction<N>, status: FinalizeStatus) -> Result<()> {
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+        // Ensure the transaction exists.
+        if !self.id_map().contains_key_speculative(&transaction_id)? {
+            bail!("Failed to get the type for transaction '{transaction_id}'")
+        }
+
+        atomic_write_batch!(self, {
+            // Store the finalize status.
+            self.finalize_status_map().insert(transaction_id, status)?;
+            match (transaction, status) {
+                // Index the deployment, if it was accepted.
+                (Transaction::Deploy(..), FinalizeStatus::Accepted) => {
+                    self.deployment_store().insert_accepted(&transaction_id)?
+                }
+                // Move the execution out of the transition store, if it was rejected.
+                (Transaction::Execute(..), FinalizeStatus::Rejected) => self.execution_store().reject(transaction)?,
+                _ => (),
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -187,6 +237,14 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             TransactionType::Execute => self.execution_store().get_transaction(transaction_id),
         }
     }
+
+    /// Returns the finalize status for the given `transaction ID`.
+    fn get_finalize_status(&self, transaction_id: &N::TransactionID) -> Result<Option<FinalizeStatus>> {
+        match self.finalize_status_map().get(transaction_id)? {
+            Some(status) => Ok(Some(cow_to_copied!(status))),
+            None => Ok(None),
+        }
+    }
 }
 
 /// An in-memory transaction storage.
@@ -194,6 +252,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 pub struct TransactionMemory<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: MemoryMap<N::TransactionID, TransactionType>,
+    /// The mapping of `transaction ID` to `finalize status`.
+    finalize_status_map: MemoryMap<N::TransactionID, FinalizeStatus>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentMemory<N>>,
     /// The execution store.
@@ -203,6 +263,7 @@ pub struct TransactionMemory<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, TransactionType>;
+    type FinalizeStatusMap = MemoryMap<N::TransactionID, FinalizeStatus>;
     type DeploymentStorage = DeploymentMemory<N>;
     type ExecutionStorage = ExecutionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
@@ -214,7 +275,12 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(transition_store)?;
         // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store })
+        Ok(Self {
+            id_map: MemoryMap::default(),
+            finalize_status_map: MemoryMap::default(),
+            deployment_store,
+            execution_store,
+        })
     }
 
     /// Returns the ID map.
@@ -222,6 +288,11 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         &self.id_map
     }
 
+    /// Returns the finalize status map.
+    fn finalize_status_map(&self) -> &Self::FinalizeStatusMap {
+        &self.finalize_status_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
@@ -266,6 +337,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Stores the finalize status for the given `transaction`.
+    pub fn insert_finalize_status(&self, transaction: &Transaction<N>, status: FinalizeStatus) -> Result<()> {
+        self.storage.insert_finalize_status(transaction, status)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, T::TransitionStorage> {
         self.storage.transition_store()
@@ -303,6 +379,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.get_transaction(transaction_id)
     }
 
+    /// Returns the finalize status for the given `transaction ID`.
+    pub fn get_finalize_status(&self, transaction_id: &N::TransactionID) -> Result<Option<FinalizeStatus>> {
+        self.storage.get_finalize_status(transaction_id)
+    }
+
     /// Returns the deployment for the given `transaction ID`.
     pub fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
         // Retrieve the transaction type.
@@ -345,12 +426,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         // Retrieve the edition.
         match transaction_type {
             TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
-                    None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
+                // Return the edition deployed in the transaction.
+                match self.storage.deployment_store().get_deployment_edition(transaction_id)? {
+                    Some(edition) => Ok(Some(edition)),
+                    None => bail!("Failed to get the edition for deployment transaction '{transaction_id}'"),
                 }
             }
             // Return the edition.
@@ -420,6 +499,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
     pub fn contains_program_id(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.storage.deployment_store().contains_program_id(program_id)
     }
+
+    /// Returns `true` if the given transition ID belongs to a rejected execution.
+    pub fn contains_rejected_transition_id(&self, transition_id: &N::TransitionID) -> Result<bool> {
+        self.storage.execution_store().contains_rejected_transition_id(transition_id)
+    }
 }
 
 impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
@@ -438,7 +522,7 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.execution_store().execution_transaction_ids()
     }
 
-    /// Returns an iterator over the program IDs, for all deployments.
+    /// Returns an iterator over the program IDs, for all accepted deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
         self.storage.deployment_store().program_ids()
     }
@@ -448,17 +532,17 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().programs()
     }
 
-    /// Returns an iterator over the `((program ID, function name, edition), verifying key)`, for all deployments.
+    /// Returns an iterator over the `((transaction ID, function name), verifying key)`, for all deployments.
     pub fn verifying_keys(
         &self,
-    ) -> impl '_ + Iterator<Item = (Cow<'_, (ProgramID<N>, Identifier<N>, u16)>, Cow<'_, VerifyingKey<N>>)> {
+    ) -> impl '_ + Iterator<Item = (Cow<'_, (N::TransactionID, Identifier<N>)>, Cow<'_, VerifyingKey<N>>)> {
         self.storage.deployment_store().verifying_keys()
     }
 
-    /// Returns an iterator over the `((program ID, function name, edition), certificate)`, for all deployments.
+    /// Returns an iterator over the `((transaction ID, function name), certificate)`, for all deployments.
     pub fn certificates(
         &self,
-    ) -> impl '_ + Iterator<Item = (Cow<'_, (ProgramID<N>, Identifier<N>, u16)>, Cow<'_, Certificate<N>>)> {
+    ) -> impl '_ + Iterator<Item = (Cow<'_, (N::TransactionID, Identifier<N>)>, Cow<'_, Certificate<N>>)> {
         self.storage.deployment_store().certificates()
     }
 }
@@ -492,7 +576,17 @@ mod tests {
 
             // Retrieve the transaction.
             let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
-            assert_eq!(Some(transaction), candidate);
+            assert_eq!(Some(transaction.clone()), candidate);
+
+            // Ensure the finalize status does not exist.
+            assert_eq!(None, transaction_store.get_finalize_status(&transaction_id).unwrap());
+            // Insert the finalize status.
+            transaction_store.insert_finalize_status(&transaction, FinalizeStatus::Rejected).unwrap();
+            // Retrieve the finalize status.
+            assert_eq!(Some(FinalizeStatus::Rejected), transaction_store.get_finalize_status(&transaction_id).unwrap());
+            // Ensure the rejected transaction can still be retrieved.
+            let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
+            assert_eq!(Some(transaction.clone()), candidate);
 
             // Remove the transaction.
             transaction_store.remove(&transaction_id).unwrap();
@@ -500,6 +594,8 @@ mod tests {
             // Ensure the transaction does not exist.
             let candidate = transaction_store.get_transaction(&transaction_id).unwrap();
             assert_eq!(None, candidate);
+            // Ensure the finalize status does not exist.
+            assert_eq!(None, transaction_store.get_finalize_status(&transaction_id).unwrap());
         }
     }
 
@@ -152,7 +152,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
     /// Removes the input for the given `transition ID`.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         // Retrieve the input IDs.
-        let input_ids: Vec<_> = match self.id_map().get(transition_id)? {
+        let input_ids: Vec<_> = match self.id_map().get_speculative(transition_id)? {
             Some(Cow::Borrowed(ids)) => ids.to_vec(),
             Some(Cow::Owned(ids)) => ids.into_iter().collect(),
             None => return Ok(()),
@@ -168,7 +168,7 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
                 self.reverse_id_map().remove(&input_id)?;
 
                 // If the input is a record, remove the record tag.
-                if let Some(tag) = self.record_map().get(&input_id)? {
+                if let Some(tag) = self.record_map().get_speculative(&input_id)? {
                     self.record_tag_map().remove(&tag)?;
                 }
 
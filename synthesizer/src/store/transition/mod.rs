@@ -28,6 +28,7 @@ use crate::{
     store::helpers::{memory_map::MemoryMap, Map, MapRead},
 };
 use console::{
+    account::GraphKey,
     network::prelude::*,
     program::{Ciphertext, Identifier, Plaintext, ProgramID, Record, Value},
     types::{Field, Group},
@@ -36,6 +37,9 @@ use console::{
 use anyhow::Result;
 use std::borrow::Cow;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// A trait for transition storage.
 pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     /// The transition program IDs and function names.
@@ -197,14 +201,15 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Removes the input for the given `transition ID`.
+    /// Note: This also removes a transition that was inserted in the current atomic batch.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         // Retrieve the `tpk`.
-        let tpk = match self.tpk_map().get(transition_id)? {
+        let tpk = match self.tpk_map().get_speculative(transition_id)? {
             Some(tpk) => cow_to_copied!(tpk),
             None => return Ok(()),
         };
         // Retrieve the `tcm`.
-        let tcm = match self.tcm_map().get(transition_id)? {
+        let tcm = match self.tcm_map().get_speculative(transition_id)? {
             Some(tcm) => cow_to_copied!(tcm),
             None => return Ok(()),
         };
@@ -608,6 +613,23 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.inputs.contains_tag(tag)
     }
 
+    /// Returns the commitments, from the given owned commitments, whose records have been spent.
+    /// Note: This method only requires the graph key, so the spent records can be found without the private key.
+    pub fn find_spent_commitments(&self, graph_key: &GraphKey<N>, commitments: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        cfg_iter!(commitments)
+            .map(|commitment| {
+                // Compute the tag of the record.
+                let tag = Record::<N, Ciphertext<N>>::tag(graph_key.sk_tag(), *commitment)?;
+                // Determine if the record has been spent.
+                Ok((*commitment, self.contains_tag(&tag)?))
+            })
+            .filter_map(|result| match result {
+                Ok((commitment, is_spent)) => is_spent.then_some(Ok(commitment)),
+                Err(error) => Some(Err(error)),
+            })
+            .collect()
+    }
+
     /* Output */
 
     /// Returns `true` if the given output ID exists.
@@ -795,6 +817,7 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::account::{PrivateKey, ViewKey};
 
     #[test]
     fn test_insert_get_remove() {
@@ -879,4 +902,43 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_find_spent_commitments() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis and funding blocks, their owner, and the execution spending the funding records.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let funding = crate::vm::test_helpers::sample_funding_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+
+        // Derive the graph key.
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let graph_key = GraphKey::try_from(&view_key).unwrap();
+
+        // Retrieve the owned commitments.
+        let commitments = genesis.commitments().chain(funding.commitments()).copied().collect::<Vec<_>>();
+        assert_eq!(3, commitments.len());
+
+        // Initialize a new transition store.
+        let transition_store = TransitionStore::<_, TransitionMemory<_>>::open(None).unwrap();
+
+        // Ensure no records are spent.
+        assert!(transition_store.find_spent_commitments(&graph_key, &commitments).unwrap().is_empty());
+
+        // Insert the transitions of the execution.
+        for transition in transaction.transitions() {
+            transition_store.insert(transition).unwrap();
+        }
+
+        // Ensure only the funding records are spent.
+        let spent = transition_store.find_spent_commitments(&graph_key, &commitments).unwrap();
+        assert_eq!(commitments[1..].to_vec(), spent);
+
+        // Ensure another graph key does not find the spent record.
+        let other_view_key = ViewKey::try_from(&PrivateKey::new(rng).unwrap()).unwrap();
+        let other_graph_key = GraphKey::try_from(&other_view_key).unwrap();
+        assert!(transition_store.find_spent_commitments(&other_graph_key, &commitments).unwrap().is_empty());
+    }
 }
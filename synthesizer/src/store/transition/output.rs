@@ -172,7 +172,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
     /// Removes the output for the given `transition ID`.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
         // Retrieve the output IDs.
-        let output_ids: Vec<_> = match self.id_map().get(transition_id)? {
+        let output_ids: Vec<_> = match self.id_map().get_speculative(transition_id)? {
             Some(Cow::Borrowed(ids)) => ids.to_vec(),
             Some(Cow::Owned(ids)) => ids.into_iter().collect(),
             None => return Ok(()),
@@ -196,7 +196,7 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
                 self.reverse_id_map().remove(&output_id)?;
 
                 // If the output is a record, remove the record nonce.
-                if let Some(record) = self.record_map().get(&output_id)? {
+                if let Some(record) = self.record_map().get_speculative(&output_id)? {
                     if let Some(record) = &record.1 {
                         self.record_nonce_map().remove(record.nonce())?;
                     }
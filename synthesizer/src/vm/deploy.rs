@@ -44,4 +44,52 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Upgrades the program with the given program ID to the next edition, signed by the upgrade authority.
+    #[inline]
+    pub fn upgrade<R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("VM::upgrade");
+
+        // Determine the next edition of the program.
+        let program_id = program.id();
+        let edition = self.program_store().get_edition(program_id)?.unwrap_or(N::EDITION);
+        let edition = match edition.checked_add(1) {
+            Some(edition) => edition,
+            None => bail!("Program '{program_id}' has reached the maximum edition"),
+        };
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the program and private key.
+                let program = cast_ref!(&program as Program<$network>);
+                let private_key = cast_ref!(&private_key as PrivateKey<$network>);
+
+                // Compute the upgrade.
+                let deployment = $process.upgrade::<$aleo, _>(program, edition, private_key, rng)?;
+                lap!(timer, "Compute the upgrade");
+
+                // Prepare the return.
+                let deployment = cast_ref!(deployment as Deployment<N>).clone();
+                lap!(timer, "Prepare the upgrade");
+
+                finish!(timer);
+                // Return the upgrade.
+                Ok(deployment)
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
+
+    /// Returns the estimated fee (in gates) for the given deployment.
+    #[inline]
+    pub fn estimate_deployment_fee(&self, deployment: &Deployment<N>) -> Result<u64> {
+        deployment_cost(deployment)
+    }
 }
@@ -140,4 +140,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Returns the estimated fee (in gates) for the given execution.
+    #[inline]
+    pub fn estimate_execution_fee(&self, execution: &Execution<N>) -> Result<u64> {
+        execution_cost(&self.process.read(), execution)
+    }
+
+    /// Returns the estimated fee (in gates) for the given transaction.
+    #[inline]
+    pub fn estimate_fee(&self, transaction: &Transaction<N>) -> Result<u64> {
+        match transaction {
+            Transaction::Deploy(_, deployment, _) => self.estimate_deployment_fee(deployment),
+            Transaction::Execute(_, execution, _) => self.estimate_execution_fee(execution),
+        }
+    }
 }
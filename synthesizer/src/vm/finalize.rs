@@ -17,55 +17,576 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Finalizes the given transactions into the VM.
-    /// This method assumes the given transactions **are valid**.
+    /// Finalizes the given transactions into the VM, and returns the finalize receipt.
+    /// This method assumes the given transactions **are valid**, and have already been inserted into the
+    /// transaction store (e.g. as part of their block), as the finalize status of each transaction is stored.
+    ///
+    /// If the finalize scope of a transaction fails, the transaction is marked as rejected,
+    /// and its finalize writes are reverted, while the remaining transactions are still finalized.
+    /// Note: A rejected transaction remains in its block, which ensures its fee is still consumed.
+    /// However, only its fee transition remains in the transition store, so the serial numbers of its
+    /// execution are not spent, and the records output by its execution cannot be spent.
     #[inline]
-    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<()> {
+    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<FinalizeReceipt<N>> {
         let timer = timer!("VM::finalize");
-        atomic_write_batch!(self, {
+        let receipt = atomic_write_batch!(self, {
             // Acquire the write lock on the process.
             let mut process = self.process.write();
 
+            // Initialize the finalize receipt.
+            let mut receipt = FinalizeReceipt::new();
+
             for transaction in transactions.values() {
+                // Save a checkpoint, in case the transaction is rejected.
+                self.program_store().atomic_checkpoint();
+
                 // Finalize the transaction.
-                match transaction {
-                    Transaction::Deploy(_, deployment, _) => {
-                        process.finalize_deployment(self.program_store(), deployment)?;
-                        lap!(timer, "Finalize deployment");
+                let outcome = self.finalize_transaction(&mut process, transaction);
+                lap!(timer, "Finalize transaction {}", transaction.id());
+
+                // Record the outcome of the transaction.
+                let status = match outcome {
+                    Ok((outputs, mapping_transitions)) => {
+                        // Keep the finalize writes of the transaction.
+                        self.program_store().clear_latest_checkpoint();
+                        // Index the mappings touched by the accepted transaction.
+                        self.block_store().insert_mapping_transitions(&mapping_transitions)?;
+                        receipt.insert(transaction.id(), outputs);
+                        FinalizeStatus::Accepted
                     }
-                    Transaction::Execute(_, execution, _) => {
-                        process.finalize_execution(self.program_store(), execution)?;
-                        lap!(timer, "Finalize execution");
+                    Err(error) => {
+                        // Revert the finalize writes of the transaction, including its finalize outputs.
+                        self.program_store().atomic_rewind();
+                        warn!("Rejected transaction '{}' during finalize: {error}", transaction.id());
+                        receipt.reject(transaction.id());
+                        FinalizeStatus::Rejected
                     }
-                }
+                };
+                // Store the finalize status of the transaction.
+                self.transaction_store().insert_finalize_status(transaction, status)?;
             }
-            Ok(())
+            Ok(receipt)
         });
 
         finish!(timer);
 
-        Ok(())
+        Ok(receipt)
+    }
+
+    /// Finalizes the coinbase of the given block into the VM, and returns the coinbase reward of each prover.
+    /// This method assumes the given block **is valid**, and has already been inserted into the block store.
+    ///
+    /// The coinbase reward is split among the provers in proportion to the target of each partial solution.
+    /// If the block does not contain a coinbase solution, no prover is rewarded.
+    ///
+    /// The coinbase reward of each prover is credited to the public balance of the prover,
+    /// in the `account` mapping of `credits.aleo`, and is also recorded in the block store.
+    #[inline]
+    pub fn finalize_coinbase(&self, block: &Block<N>) -> Result<IndexMap<Address<N>, u64>> {
+        // Compute the coinbase reward of each prover.
+        let rewards = match block.coinbase() {
+            Some(coinbase_solution) => {
+                // Compute the coinbase reward.
+                let reward = coinbase_reward(
+                    block.height(),
+                    N::STARTING_SUPPLY,
+                    N::ANCHOR_TIME,
+                    coinbase_solution.to_cumulative_proof_target()?,
+                    block.coinbase_target(),
+                )?;
+                // Split the coinbase reward among the provers.
+                prover_rewards(coinbase_solution, reward)?
+            }
+            None => IndexMap::new(),
+        };
+        // Credit the coinbase reward of each prover to its account.
+        if !rewards.is_empty() {
+            let program_id = ProgramID::from_str("credits.aleo")?;
+            let mapping_name = Identifier::from_str("account")?;
+            // Initialize the account mapping, if this is the first coinbase reward.
+            if !self.program_store().contains_mapping(&program_id, &mapping_name)? {
+                self.program_store().initialize_mapping(&program_id, &mapping_name)?;
+            }
+            for (address, reward) in &rewards {
+                let key = Plaintext::from(Literal::Address(*address));
+                // Retrieve the current balance of the prover.
+                let balance = match self.program_store().get_value(&program_id, &mapping_name, &key)? {
+                    Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                    Some(value) => bail!("Invalid balance '{value}' for '{address}' in 'credits.aleo/account'"),
+                    None => 0,
+                };
+                // Compute the new balance of the prover.
+                let balance = match balance.checked_add(*reward) {
+                    Some(balance) => balance,
+                    None => bail!("The balance of '{address}' overflows in 'credits.aleo/account'"),
+                };
+                let value = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(balance))));
+                self.program_store().update_key_value(&program_id, &mapping_name, key, value)?;
+            }
+        }
+        // Store the coinbase reward of each prover.
+        self.block_store().insert_prover_rewards(&block.hash(), rewards.clone())?;
+        Ok(rewards)
+    }
+
+    /// Speculatively finalizes the given candidate transactions in order, without committing any changes.
+    /// Returns the IDs of the accepted transactions, and the IDs of the aborted transactions with their reasons.
+    /// This method assumes the given transactions **are valid**.
+    ///
+    /// A transaction is aborted if it spends a serial number that is already spent (in the ledger or by an
+    /// accepted transaction), or if its finalize scope fails (e.g. a mapping value overflows or underflows). Each
+    /// finalize scope observes the writes of the previously-accepted transactions, so the accepted transactions
+    /// can be finalized together, in the same order.
+    ///
+    /// Note: Transactions that read or write the same mapping keys are not treated as conflicting. Their finalize
+    /// scopes are applied in order, so the accepted transactions are only guaranteed to finalize successfully in
+    /// the given order, and on top of the current program store.
+    ///
+    /// Note: Speculation is serialized with block insertion, as both write to the program store,
+    /// but it runs against a clone of the process, so it does not block readers of the process.
+    pub fn speculate(
+        &self,
+        transactions: &[Transaction<N>],
+    ) -> Result<(Vec<N::TransactionID>, Vec<(N::TransactionID, String)>)> {
+        let timer = timer!("VM::speculate");
+
+        // Acquire the block lock, to prevent a concurrent block from being added.
+        let block_lock = self.block_lock.lock();
+        // Ensure an atomic batch is not in progress, as the speculative writes are discarded at the end.
+        ensure!(!self.program_store().is_atomic_in_progress(), "Cannot speculate while an atomic batch is in progress");
+        // Clone the process under a read lock, to ensure the speculative deployments are discarded at the end.
+        let mut process = self.process.read().clone();
+
+        // Start an atomic batch in the program store, which holds the speculative writes.
+        // Note: Speculation only writes to the program store, so the batch never includes any block writes.
+        self.program_store().start_atomic();
+        let outcome = (|| {
+            let mut accepted = Vec::with_capacity(transactions.len());
+            let mut aborted = Vec::new();
+            // Track the transaction IDs and serial numbers of the accepted transactions.
+            let mut transaction_ids = HashSet::with_capacity(transactions.len());
+            let mut serial_numbers = HashSet::new();
+
+            for transaction in transactions {
+                // Ensure the transaction does not conflict with the ledger, or with an accepted transaction.
+                if let Some(reason) = self.find_conflict(transaction, &transaction_ids, &serial_numbers)? {
+                    aborted.push((transaction.id(), reason));
+                    continue;
+                }
+
+                // Save a checkpoint, in case the transaction is aborted.
+                self.program_store().atomic_checkpoint();
+                // Finalize the transaction.
+                match self.finalize_transaction(&mut process, transaction) {
+                    Ok(_) => {
+                        self.program_store().clear_latest_checkpoint();
+                        transaction_ids.insert(transaction.id());
+                        serial_numbers.extend(transaction.serial_numbers().copied());
+                        accepted.push(transaction.id());
+                    }
+                    Err(error) => {
+                        self.program_store().atomic_rewind();
+                        aborted.push((transaction.id(), error.to_string()));
+                    }
+                }
+                lap!(timer, "Speculate transaction {}", transaction.id());
+            }
+            Ok((accepted, aborted))
+        })();
+        // Discard the speculative writes.
+        self.program_store().abort_atomic();
+        drop(block_lock);
+
+        finish!(timer);
+        outcome
+    }
+
+    /// Finalizes the given transaction with the given process, and returns the finalize outputs of each transition,
+    /// along with the `(program ID, mapping name, transition ID)` of each mapping touched by a finalize scope.
+    #[allow(clippy::type_complexity)]
+    fn finalize_transaction(
+        &self,
+        process: &mut Process<N>,
+        transaction: &Transaction<N>,
+    ) -> Result<(IndexMap<N::TransitionID, Vec<Value<N>>>, Vec<(ProgramID<N>, Identifier<N>, N::TransitionID)>)> {
+        match transaction {
+            Transaction::Deploy(_, deployment, _) => {
+                process.finalize_deployment(self.program_store(), deployment)?;
+                // Note: A deployment does not produce any finalize outputs, nor touch any mappings.
+                Ok(Default::default())
+            }
+            Transaction::Execute(_, execution, _) => {
+                process.finalize_execution_with_mappings(self.program_store(), execution)
+            }
+        }
+    }
+
+    /// Returns the reason the given transaction conflicts with the ledger, or with the given accepted
+    /// transaction IDs and serial numbers, if it conflicts.
+    ///
+    /// Note: This does not check for mapping conflicts, which are resolved by finalizing the transactions in order.
+    fn find_conflict(
+        &self,
+        transaction: &Transaction<N>,
+        transaction_ids: &HashSet<N::TransactionID>,
+        serial_numbers: &HashSet<Field<N>>,
+    ) -> Result<Option<String>> {
+        // Ensure the transaction is not a duplicate.
+        if transaction_ids.contains(&transaction.id()) {
+            return Ok(Some(format!("Transaction '{}' was already accepted", transaction.id())));
+        }
+        // Ensure the serial numbers are not already spent.
+        for serial_number in transaction.serial_numbers() {
+            if serial_numbers.contains(serial_number) {
+                return Ok(Some(format!("Serial number '{serial_number}' is spent by an accepted transaction")));
+            }
+            if self.transition_store().contains_serial_number(serial_number)? {
+                return Ok(Some(format!("Serial number '{serial_number}' is already spent in the ledger")));
+            }
+        }
+        Ok(None)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{CoinbasePuzzle, EpochChallenge, PuzzleConfig};
+    use console::account::ViewKey;
     use snarkvm_utilities::TestRng;
 
+    type CurrentNetwork = crate::vm::test_helpers::CurrentNetwork;
+
     #[test]
     fn test_finalize() {
         let rng = &mut TestRng::default();
 
         let vm = crate::vm::test_helpers::sample_vm();
 
-        // Fetch a deployment transaction.
+        // Fetch a deployment transaction, and insert it into the transaction store.
         let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        vm.transaction_store().insert(&deployment_transaction).unwrap();
 
         // Finalize the transaction.
-        vm.finalize(&Transactions::from(&[deployment_transaction.clone()])).unwrap();
+        let receipt = vm.finalize(&Transactions::from(std::slice::from_ref(&deployment_transaction))).unwrap();
+        // Ensure the receipt contains the deployment, without any finalize outputs.
+        assert_eq!(receipt.len(), 1);
+        assert!(receipt.get_transaction(&deployment_transaction.id()).unwrap().is_empty());
+
+        // Ensure the deployment is accepted.
+        assert_eq!(receipt.get_status(&deployment_transaction.id()), Some(FinalizeStatus::Accepted));
+        let status = vm.transaction_store().get_finalize_status(&deployment_transaction.id()).unwrap();
+        assert_eq!(status, Some(FinalizeStatus::Accepted));
+
+        // Ensure the VM rejects a redeployment of the same transaction.
+        let receipt = vm.finalize(&Transactions::from(std::slice::from_ref(&deployment_transaction))).unwrap();
+        assert!(receipt.is_rejected(&deployment_transaction.id()));
+        let status = vm.transaction_store().get_finalize_status(&deployment_transaction.id()).unwrap();
+        assert_eq!(status, Some(FinalizeStatus::Rejected));
+    }
+
+    #[test]
+    fn test_finalize_rejected_execution() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize a program, whose `mint` debits a balance that does not exist, and fails to finalize.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program mint_rejected.aleo;
+
+mapping supply:
+    key owner as address.public;
+    value amount as u64.public;
+
+record token:
+    owner as address.private;
+    gates as u64.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    cast r0 0u64 r1 into r2 as token.record;
+    output r2 as token.record;
+    finalize r0 r1;
+
+finalize mint:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get supply[r0] into r2;
+    sub r2 r1 into r3;
+    set r3 into supply[r0];
+
+function burn:
+    input r0 as token.record;",
+        )
+        .unwrap();
+
+        // Deploy the program, paying the estimated fee with the genesis record.
+        let credits = genesis.records().next().unwrap().1.decrypt(&caller_view_key).unwrap();
+        let deployment = vm.deploy(&program, rng).unwrap();
+        let fee_in_gates = vm.estimate_deployment_fee(&deployment).unwrap();
+        let (_, fee, _) = vm.execute_fee(&caller_private_key, credits, fee_in_gates, None, rng).unwrap();
+        let transaction = Transaction::from_deployment(deployment, fee).unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Execute `mint`.
+        let inputs = [address.to_string(), "10u64".to_string()];
+        let authorization = vm.authorize(&caller_private_key, "mint_rejected.aleo", "mint", inputs, rng).unwrap();
+        let (_, execution, _) = vm.execute(authorization, None, rng).unwrap();
+        // Ensure the execution requires an additional fee, as it has a finalize scope.
+        let transaction = Transaction::from_execution(execution.clone(), None).unwrap();
+        assert!(vm.check_transaction(&transaction).is_err());
+
+        // Pay the estimated fee of the execution as an additional fee, and add it in a block.
+        let credits = block.records().next().unwrap().1.decrypt(&caller_view_key).unwrap();
+        let fee_in_gates = vm.estimate_execution_fee(&execution).unwrap();
+        let (_, fee, _) = vm.execute_fee(&caller_private_key, credits, fee_in_gates, None, rng).unwrap();
+        let transaction = Transaction::from_execution(execution.clone(), Some(fee.clone())).unwrap();
+        assert!(vm.check_transaction(&transaction).is_ok());
+        let transactions = std::slice::from_ref(&transaction);
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, transactions, rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the execution is rejected, and remains in its block.
+        assert_eq!(vm.block_store().get_finalize_status(&transaction.id()).unwrap(), Some(FinalizeStatus::Rejected));
+        assert_eq!(vm.block_store().get_block(&block.hash()).unwrap(), Some(block));
+
+        // Ensure the additional fee is still consumed.
+        for serial_number in fee.serial_numbers() {
+            assert!(vm.transition_store().contains_serial_number(serial_number).unwrap());
+        }
+        for (commitment, _) in fee.records() {
+            assert!(vm.transition_store().contains_commitment(commitment).unwrap());
+        }
+
+        // Ensure the output record of the rejected execution does not exist in the ledger.
+        let (commitment, record) = execution.transitions().flat_map(Transition::records).next().unwrap();
+        assert!(!vm.transition_store().contains_commitment(commitment).unwrap());
+        assert!(vm.block_store().get_state_path_for_commitment(commitment).is_err());
+
+        // Ensure the output record of the rejected execution cannot be spent.
+        let record = record.decrypt(&caller_view_key).unwrap();
+        let authorization =
+            vm.authorize(&caller_private_key, "mint_rejected.aleo", "burn", [Value::Record(record)], rng).unwrap();
+        assert!(vm.execute(authorization, None, rng).is_err());
+
+        // Ensure the transitions of the rejected execution cannot be included again.
+        for transition in execution.transitions() {
+            assert!(vm.transaction_store().contains_rejected_transition_id(transition.id()).unwrap());
+        }
+        assert!(!vm.transaction_store().contains_rejected_transition_id(fee.id()).unwrap());
+    }
+
+    #[test]
+    fn test_finalize_coinbase() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Sample a coinbase solution, with two partial solutions from the same prover.
+        let config = PuzzleConfig { degree: (1 << 5) - 1 };
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(config).unwrap();
+        let puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, config).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.gen(), Default::default(), config.degree).unwrap();
+        let prover = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let solutions =
+            (0..2).map(|_| puzzle.prove(&epoch_challenge, prover, rng.gen(), None).unwrap()).collect::<Vec<_>>();
+        let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
+
+        // Construct the next block, with the coinbase solution.
+        let transactions = [crate::vm::test_helpers::sample_fee_execution_transaction(rng)];
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &transactions, rng).unwrap();
+        let header = Header::from(
+            block.header().previous_state_root(),
+            block.header().transactions_root(),
+            coinbase_solution.to_accumulator_point().unwrap(),
+            *block.header().metadata(),
+        )
+        .unwrap();
+        let block = Block::new(
+            &caller_private_key,
+            block.previous_hash(),
+            header,
+            block.transactions().clone(),
+            Some(coinbase_solution),
+            rng,
+        )
+        .unwrap();
+
+        // Add the block, and ensure the coinbase reward is credited to the account of the prover.
+        vm.add_next_block(&block).unwrap();
+        let rewards = vm.block_store().get_prover_rewards(&block.hash()).unwrap().unwrap();
+        assert_eq!(rewards.keys().collect::<Vec<_>>(), vec![&prover]);
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(prover));
+        let balance = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(rewards[&prover]))));
+        assert_eq!(vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), Some(balance.clone()));
+
+        // Remove the block, and ensure the coinbase reward is debited from the account of the prover.
+        vm.store.remove_last_n(1).unwrap();
+        assert_eq!(vm.block_store().get_prover_rewards(&block.hash()).unwrap(), None);
+        assert_eq!(vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), None);
+
+        // Add the block again, and ensure the prover is rewarded only once.
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), Some(balance));
+
+        // Ensure the coinbase reward is added to the existing balance of the prover.
+        vm.finalize_coinbase(&block).unwrap();
+        let balance = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(2 * rewards[&prover]))));
+        assert_eq!(vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), Some(balance));
+
+        // Move part of the balance of the prover, and ensure the block can still be removed.
+        let balance = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(rewards[&prover] / 2))));
+        vm.program_store().update_key_value(&program_id, &mapping_name, key.clone(), balance).unwrap();
+        vm.store.remove_last_n(1).unwrap();
+        assert_eq!(vm.block_store().get_prover_rewards(&block.hash()).unwrap(), None);
+        assert_eq!(vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), None);
+    }
+
+    #[test]
+    fn test_speculate() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm_with_funding_block(rng);
+
+        // Fetch a deployment transaction and an execution transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let execution_transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
+        let program_id = *crate::vm::test_helpers::sample_program().id();
+
+        // Speculate on the transactions, including a duplicate deployment.
+        let candidates = [deployment_transaction.clone(), execution_transaction.clone(), deployment_transaction];
+        let (accepted, aborted) = vm.speculate(&candidates).unwrap();
+        // Ensure the duplicate deployment is aborted.
+        assert_eq!(accepted, vec![candidates[0].id(), execution_transaction.id()]);
+        assert_eq!(aborted.len(), 1);
+        assert_eq!(aborted[0].0, candidates[0].id());
+
+        // Ensure nothing was committed.
+        assert!(!vm.contains_program(&program_id));
+        assert!(!vm.program_store().contains_program(&program_id).unwrap());
+        assert!(!vm.is_atomic_in_progress());
+
+        // Ensure the accepted transactions can be finalized together, in a block.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let block =
+            crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &candidates[..2], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        for transaction in &candidates[..2] {
+            assert_eq!(
+                vm.block_store().get_finalize_status(&transaction.id()).unwrap(),
+                Some(FinalizeStatus::Accepted)
+            );
+        }
+        assert!(vm.contains_program(&program_id));
+
+        // Ensure a redeployment is now aborted.
+        let (accepted, aborted) = vm.speculate(&candidates[..1]).unwrap();
+        assert!(accepted.is_empty());
+        assert_eq!(aborted.len(), 1);
+    }
+
+    #[test]
+    fn test_speculate_keeps_block_writes() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Queue a block write in an atomic batch of the block store.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        vm.block_store().start_atomic();
+        vm.transaction_store().insert(&transaction).unwrap();
+        vm.transaction_store().insert_finalize_status(&transaction, FinalizeStatus::Accepted).unwrap();
+
+        // Speculate, and ensure the block write is not discarded.
+        assert_eq!(vm.speculate(&[]).unwrap(), (vec![], vec![]));
+        vm.block_store().finish_atomic().unwrap();
+        assert_eq!(vm.transaction_store().get_transaction(&transaction.id()).unwrap(), Some(transaction.clone()));
+        assert_eq!(vm.block_store().get_finalize_status(&transaction.id()).unwrap(), Some(FinalizeStatus::Accepted));
+    }
+
+    #[test]
+    fn test_speculate_shared_mapping_keys() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize a program, whose `bump` increments and `spend` decrements the count of the given address.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program counter_speculate.aleo;
+
+mapping counts:
+    key owner as address.public;
+    value count as u64.public;
+
+function bump:
+    input r0 as address.public;
+    finalize r0;
+
+finalize bump:
+    input r0 as address.public;
+    increment counts[r0] by 1u64;
+
+function spend:
+    input r0 as address.public;
+    finalize r0;
+
+finalize spend:
+    input r0 as address.public;
+    decrement counts[r0] by 1u64;",
+        )
+        .unwrap();
+
+        // Deploy the program, paying the estimated fee with the genesis record.
+        let credits = genesis.records().next().unwrap().1.decrypt(&caller_view_key).unwrap();
+        let deployment = vm.deploy(&program, rng).unwrap();
+        let fee_in_gates = vm.estimate_deployment_fee(&deployment).unwrap();
+        let (_, fee, _) = vm.execute_fee(&caller_private_key, credits, fee_in_gates, None, rng).unwrap();
+        let transaction = Transaction::from_deployment(deployment, fee).unwrap();
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Executes the given function for the caller.
+        let address = Address::try_from(&caller_private_key).unwrap();
+        let mut execute = |function_name: &str| {
+            let inputs = [address.to_string()];
+            let authorization =
+                vm.authorize(&caller_private_key, "counter_speculate.aleo", function_name, inputs, rng).unwrap();
+            let (_, execution, _) = vm.execute(authorization, None, rng).unwrap();
+            Transaction::from_execution(execution, None).unwrap()
+        };
+        let candidates = [execute("bump"), execute("bump"), execute("spend"), execute("spend"), execute("spend")];
+
+        // Ensure the transactions on the same key are accepted, each on top of the previous ones,
+        // and only the last `spend` is aborted, as the count underflows.
+        let (accepted, aborted) = vm.speculate(&candidates).unwrap();
+        assert_eq!(accepted, candidates[..4].iter().map(Transaction::id).collect::<Vec<_>>());
+        assert_eq!(aborted.len(), 1);
+        assert_eq!(aborted[0].0, candidates[4].id());
 
-        // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(&Transactions::from(&[deployment_transaction])).is_err());
+        // Ensure nothing was committed.
+        let program_id = ProgramID::from_str("counter_speculate.aleo").unwrap();
+        let mapping_name = Identifier::from_str("counts").unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        assert_eq!(vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap(), None);
     }
 }
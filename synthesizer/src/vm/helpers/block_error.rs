@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::coinbase_puzzle::PuzzleCommitment;
+use console::{
+    network::prelude::*,
+    types::{Field, Group},
+};
+
+/// The reason a block is rejected as the next block of the chain.
+/// Note: This error is returned as the source of an `anyhow::Error`, and may be recovered via `downcast_ref`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockError<N: Network> {
+    /// The block header is malformed.
+    InvalidHeader,
+    /// The block is not a valid genesis block, while the chain is empty.
+    InvalidGenesis,
+    /// The block already exists in the chain.
+    AlreadyExists(N::BlockHash),
+    /// The previous block hash does not match the hash of the chain tip.
+    InvalidPreviousHash { expected: N::BlockHash, found: N::BlockHash },
+    /// The block height does not follow the chain tip.
+    InvalidHeight { expected: u32, found: u32 },
+    /// The block round does not advance past the chain tip.
+    InvalidRound { previous: u64, found: u64 },
+    /// The block timestamp does not advance past the chain tip.
+    InvalidTimestamp { previous: i64, found: i64 },
+    /// The coinbase target is incorrect.
+    InvalidCoinbaseTarget { expected: u64, found: u64 },
+    /// The proof target is incorrect.
+    InvalidProofTarget { expected: u64, found: u64 },
+    /// The last coinbase target is incorrect.
+    InvalidLastCoinbaseTarget { expected: u64, found: u64 },
+    /// The last coinbase timestamp is incorrect.
+    InvalidLastCoinbaseTimestamp { expected: i64, found: i64 },
+    /// The previous state root does not match the current state root.
+    InvalidPreviousStateRoot { expected: Field<N>, found: Field<N> },
+    /// The transactions root does not match the transactions in the block.
+    InvalidTransactionsRoot { expected: Field<N>, found: Field<N> },
+    /// The coinbase solution is invalid.
+    InvalidCoinbaseSolution(String),
+    /// The puzzle commitment is duplicated in the block, or already exists in the chain.
+    DuplicatePuzzleCommitment(PuzzleCommitment<N>),
+    /// The transaction is duplicated in the block, or already exists in the chain.
+    DuplicateTransaction(N::TransactionID),
+    /// The transition is duplicated in the block, or already exists in the chain.
+    DuplicateTransition(N::TransitionID),
+    /// The transition public key is duplicated in the block, or already exists in the chain.
+    DuplicateTransitionPublicKey(Group<N>),
+    /// The serial number is duplicated in the block, or already exists in the chain (i.e. a double-spend).
+    DuplicateSerialNumber(Field<N>),
+    /// The tag is duplicated in the block, or already exists in the chain.
+    DuplicateTag(Field<N>),
+    /// The commitment is duplicated in the block, or already exists in the chain.
+    DuplicateCommitment(Field<N>),
+    /// The nonce is duplicated in the block, or already exists in the chain.
+    DuplicateNonce(Group<N>),
+    /// The transaction is invalid.
+    InvalidTransaction { id: N::TransactionID, error: String },
+}
+
+impl<N: Network> Display for BlockError<N> {
+    /// Prints the block error as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidHeader => write!(f, "Invalid block header"),
+            Self::InvalidGenesis => write!(f, "The first block must be a valid genesis block"),
+            Self::AlreadyExists(hash) => write!(f, "Block '{hash}' already exists in the chain"),
+            Self::InvalidPreviousHash { expected, found } => {
+                write!(f, "Invalid previous block hash: expected '{expected}', found '{found}'")
+            }
+            Self::InvalidHeight { expected, found } => {
+                write!(f, "Invalid block height: expected {expected}, found {found}")
+            }
+            Self::InvalidRound { previous, found } => {
+                write!(f, "Invalid block round: expected a round after {previous}, found {found}")
+            }
+            Self::InvalidTimestamp { previous, found } => {
+                write!(f, "Invalid block timestamp: expected a timestamp after {previous}, found {found}")
+            }
+            Self::InvalidCoinbaseTarget { expected, found } => {
+                write!(f, "Invalid coinbase target: expected {expected}, found {found}")
+            }
+            Self::InvalidProofTarget { expected, found } => {
+                write!(f, "Invalid proof target: expected {expected}, found {found}")
+            }
+            Self::InvalidLastCoinbaseTarget { expected, found } => {
+                write!(f, "Invalid last coinbase target: expected {expected}, found {found}")
+            }
+            Self::InvalidLastCoinbaseTimestamp { expected, found } => {
+                write!(f, "Invalid last coinbase timestamp: expected {expected}, found {found}")
+            }
+            Self::InvalidPreviousStateRoot { expected, found } => {
+                write!(f, "Invalid previous state root: expected '{expected}', found '{found}'")
+            }
+            Self::InvalidTransactionsRoot { expected, found } => {
+                write!(f, "Invalid transactions root: expected '{expected}', found '{found}'")
+            }
+            Self::InvalidCoinbaseSolution(error) => write!(f, "Invalid coinbase solution: {error}"),
+            Self::DuplicatePuzzleCommitment(commitment) => {
+                write!(f, "Found a duplicate puzzle commitment '{commitment}'")
+            }
+            Self::DuplicateTransaction(id) => write!(f, "Found a duplicate transaction '{id}'"),
+            Self::DuplicateTransition(id) => write!(f, "Found a duplicate transition '{id}'"),
+            Self::DuplicateTransitionPublicKey(tpk) => write!(f, "Found a duplicate transition public key '{tpk}'"),
+            Self::DuplicateSerialNumber(serial_number) => {
+                write!(f, "Found a duplicate serial number '{serial_number}'")
+            }
+            Self::DuplicateTag(tag) => write!(f, "Found a duplicate tag '{tag}'"),
+            Self::DuplicateCommitment(commitment) => write!(f, "Found a duplicate commitment '{commitment}'"),
+            Self::DuplicateNonce(nonce) => write!(f, "Found a duplicate nonce '{nonce}'"),
+            Self::InvalidTransaction { id, error } => write!(f, "Invalid transaction '{id}': {error}"),
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for BlockError<N> {}
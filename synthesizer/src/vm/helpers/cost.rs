@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    block::Transaction,
+    finalize::Command,
+    process::{Deployment, Execution, Process},
+    program::{Function, Instruction, Opcode, Program},
+};
+use console::network::prelude::*;
+
+/// The cost, in gates, of each byte of a deployment that is stored on chain.
+const DEPLOYMENT_COST_PER_BYTE: u64 = 1_000;
+/// The cost, in gates, of each constraint that is synthesized to verify a deployment.
+const SYNTHESIS_COST_PER_CONSTRAINT: u64 = 25;
+/// The cost, in gates, of each byte of an execution that is stored on chain.
+const EXECUTION_COST_PER_BYTE: u64 = 1;
+/// The cost, in gates, of each constraint of a function that is executed.
+const EXECUTION_COST_PER_CONSTRAINT: u64 = 1;
+
+/// The cost, in gates, of an arithmetic, logical, or comparison operation in finalize.
+const FINALIZE_OPERATION_COST: u64 = 100;
+/// The cost, in gates, of a division, exponentiation, inversion, or square root operation in finalize.
+const FINALIZE_EXPENSIVE_OPERATION_COST: u64 = 1_000;
+/// The cost, in gates, of a hash or commit operation in finalize.
+const FINALIZE_HASH_COST: u64 = 10_000;
+/// The cost, in gates, of a branch in finalize.
+const FINALIZE_BRANCH_COST: u64 = 100;
+/// The cost, in gates, of reading a value from a mapping.
+const MAPPING_READ_COST: u64 = 10_000;
+/// The cost, in gates, of writing a value to a mapping.
+const MAPPING_WRITE_COST: u64 = 50_000;
+
+/// Returns the cost, in gates, of the given deployment.
+/// The cost prices the bytes stored on chain, and the constraints synthesized for each function.
+pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<u64> {
+    // Compute the storage cost.
+    let num_bytes = deployment.to_bytes_le()?.len() as u64;
+    let mut cost = num_bytes.saturating_mul(DEPLOYMENT_COST_PER_BYTE);
+    // Compute the synthesis cost of each function.
+    for (_, (verifying_key, _)) in deployment.verifying_keys() {
+        let num_constraints = verifying_key.circuit_info.num_constraints as u64;
+        cost = cost.saturating_add(num_constraints.saturating_mul(SYNTHESIS_COST_PER_CONSTRAINT));
+    }
+    Ok(cost)
+}
+
+/// Returns the cost, in gates, of the given execution.
+/// The cost prices the bytes stored on chain, the constraints of each function,
+/// and the finalize commands of each function that has a finalize scope.
+pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>) -> Result<u64> {
+    // Compute the storage cost.
+    let num_bytes = execution.to_bytes_le()?.len() as u64;
+    let mut cost = num_bytes.saturating_mul(EXECUTION_COST_PER_BYTE);
+    // Compute the cost of each transition.
+    for transition in execution.transitions() {
+        // Retrieve the stack and verifying key of the function.
+        let stack = process.get_stack(transition.program_id())?;
+        let verifying_key = stack.get_verifying_key(transition.function_name())?;
+        // Compute the execution cost of the function.
+        let num_constraints = verifying_key.circuit_info.num_constraints as u64;
+        cost = cost.saturating_add(num_constraints.saturating_mul(EXECUTION_COST_PER_CONSTRAINT));
+        // Compute the finalize cost of the function.
+        cost = cost.saturating_add(finalize_cost(&stack.get_function(transition.function_name())?));
+    }
+    Ok(cost)
+}
+
+/// Ensures the fee of the given transaction covers its cost (in gates). On failure, returns an error.
+/// Note: Coinbase executions are exempt, as a coinbase transition mints credits instead of paying a fee.
+///
+/// An execution with a finalize scope must pay for its cost with an additional fee, as only the additional fee
+/// remains spent if its finalize scope is rejected. Hence, the fees within its execution are not counted.
+pub fn check_minimum_fee<N: Network>(process: &Process<N>, transaction: &Transaction<N>) -> Result<()> {
+    // Compute the cost of the transaction, and retrieve the fee that pays for it.
+    let (cost, fee) = match transaction {
+        Transaction::Deploy(_, deployment, _) => (deployment_cost(deployment)?, transaction.fee()?),
+        Transaction::Execute(_, execution, additional_fee) => {
+            if execution
+                .transitions()
+                .all(|transition| Program::is_coinbase(transition.program_id(), transition.function_name()))
+            {
+                return Ok(());
+            }
+            let cost = execution_cost(process, execution)?;
+            // Determine whether the execution can be rejected during finalize.
+            match execution.transitions().any(|transition| transition.finalize().is_some()) {
+                true => match additional_fee {
+                    Some(additional_fee) => (cost, *additional_fee.fee()),
+                    None => bail!("Transaction '{}' has a finalize scope, but no additional fee", transaction.id()),
+                },
+                false => (cost, transaction.fee()?),
+            }
+        }
+    };
+    // Ensure the fee covers the cost.
+    if fee < 0 || (fee as u64) < cost {
+        bail!("Transaction '{}' has a fee of {fee} gates, which is less than its cost of {cost}", transaction.id())
+    }
+    Ok(())
+}
+
+/// Returns the cost, in gates, of the finalize scope of the given function.
+/// Note: Every command is priced, regardless of branching, which bounds the cost of any path through finalize.
+pub fn finalize_cost<N: Network>(function: &Function<N>) -> u64 {
+    match function.finalize_logic() {
+        Some(finalize) => {
+            finalize.commands().iter().fold(0u64, |cost, command| cost.saturating_add(command_cost(command)))
+        }
+        None => 0,
+    }
+}
+
+/// Returns the cost, in gates, of the given finalize command.
+fn command_cost<N: Network>(command: &Command<N>) -> u64 {
+    match command {
+        Command::Instruction(instruction) => instruction_cost(instruction),
+        Command::Get(..) => MAPPING_READ_COST,
+        Command::Set(..) => MAPPING_WRITE_COST,
+        Command::GetOrInit(..) | Command::Increment(..) | Command::Decrement(..) => {
+            MAPPING_READ_COST.saturating_add(MAPPING_WRITE_COST)
+        }
+        Command::BranchEq(..) | Command::BranchNeq(..) => FINALIZE_BRANCH_COST,
+        Command::Position(..) => 0,
+    }
+}
+
+/// Returns the cost, in gates, of the given instruction in finalize.
+fn instruction_cost<N: Network>(instruction: &Instruction<N>) -> u64 {
+    match instruction.opcode() {
+        Opcode::Literal(opcode) => match opcode {
+            "div" | "div.w" | "inv" | "pow" | "pow.w" | "rem" | "rem.w" | "mod" | "sqrt" => {
+                FINALIZE_EXPENSIVE_OPERATION_COST
+            }
+            _ => FINALIZE_OPERATION_COST,
+        },
+        Opcode::Hash(..) | Opcode::Commit(..) | Opcode::Sign => FINALIZE_HASH_COST,
+        Opcode::Assert(..) | Opcode::Cast | Opcode::Is(..) => FINALIZE_OPERATION_COST,
+        // Note: These opcodes are not permitted in finalize, and are rejected when the program is deployed.
+        Opcode::Call | Opcode::Command(..) | Opcode::Finalize(..) => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::program::Program;
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_finalize_cost() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    div r1 2u64 into r3;
+    hash.bhp256 r2 into r4;
+    set r3 into account[r0];
+
+function noop:
+    input r0 as u64.public;
+    output r0 as u64.public;",
+        )
+        .unwrap();
+
+        // Ensure the finalize commands are priced by their opcode.
+        let function = program.get_function(&Identifier::from_str("compute").unwrap()).unwrap();
+        let expected = (MAPPING_READ_COST + MAPPING_WRITE_COST)
+            + FINALIZE_EXPENSIVE_OPERATION_COST
+            + FINALIZE_HASH_COST
+            + MAPPING_WRITE_COST;
+        assert_eq!(finalize_cost(&function), expected);
+
+        // Ensure a function without a finalize scope has no finalize cost.
+        let function = program.get_function(&Identifier::from_str("noop").unwrap()).unwrap();
+        assert_eq!(finalize_cost(&function), 0);
+    }
+}
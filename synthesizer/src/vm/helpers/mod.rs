@@ -14,4 +14,13 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod block_error;
+pub use block_error::*;
+
+mod cost;
+pub use cost::*;
+
 mod macros;
+
+mod receipt;
+pub use receipt::*;
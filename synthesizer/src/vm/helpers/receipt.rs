@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::FinalizeStatus;
+use console::{network::prelude::*, program::Value};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// The receipt of finalizing a set of transactions.
+/// The receipt records the finalize outputs of each finalized transition, grouped by transaction,
+/// along with the transactions that were rejected during finalize.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinalizeReceipt<N: Network> {
+    /// The mapping of `transaction ID` to `(transition ID => [finalize output])`.
+    transactions: IndexMap<N::TransactionID, IndexMap<N::TransitionID, Vec<Value<N>>>>,
+    /// The set of rejected transaction IDs.
+    rejected: IndexSet<N::TransactionID>,
+}
+
+impl<N: Network> FinalizeReceipt<N> {
+    /// Initializes a new, empty finalize receipt.
+    pub fn new() -> Self {
+        Self { transactions: IndexMap::new(), rejected: IndexSet::new() }
+    }
+
+    /// Records the finalize outputs of the given transaction.
+    pub fn insert(&mut self, transaction_id: N::TransactionID, outputs: IndexMap<N::TransitionID, Vec<Value<N>>>) {
+        self.transactions.insert(transaction_id, outputs);
+    }
+
+    /// Records the given transaction as rejected, without any finalize outputs.
+    pub fn reject(&mut self, transaction_id: N::TransactionID) {
+        self.transactions.insert(transaction_id, Default::default());
+        self.rejected.insert(transaction_id);
+    }
+
+    /// Returns `true` if the given transaction was finalized or rejected.
+    pub fn contains_transaction(&self, transaction_id: &N::TransactionID) -> bool {
+        self.transactions.contains_key(transaction_id)
+    }
+
+    /// Returns `true` if the given transaction was rejected.
+    pub fn is_rejected(&self, transaction_id: &N::TransactionID) -> bool {
+        self.rejected.contains(transaction_id)
+    }
+
+    /// Returns the finalize status of the given transaction.
+    pub fn get_status(&self, transaction_id: &N::TransactionID) -> Option<FinalizeStatus> {
+        match (self.transactions.contains_key(transaction_id), self.rejected.contains(transaction_id)) {
+            (true, false) => Some(FinalizeStatus::Accepted),
+            (true, true) => Some(FinalizeStatus::Rejected),
+            (false, _) => None,
+        }
+    }
+
+    /// Returns the finalize outputs of each transition in the given transaction.
+    pub fn get_transaction(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> Option<&IndexMap<N::TransitionID, Vec<Value<N>>>> {
+        self.transactions.get(transaction_id)
+    }
+
+    /// Returns the finalize outputs of the given transition.
+    pub fn get_outputs(&self, transition_id: &N::TransitionID) -> Option<&Vec<Value<N>>> {
+        self.transactions.values().find_map(|outputs| outputs.get(transition_id))
+    }
+
+    /// Returns an iterator over the rejected transaction IDs.
+    pub fn rejected(&self) -> impl '_ + Iterator<Item = &N::TransactionID> {
+        self.rejected.iter()
+    }
+
+    /// Returns the number of finalized and rejected transactions.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if there are no finalized or rejected transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns an iterator over the transaction IDs and their finalize outputs.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (&N::TransactionID, &IndexMap<N::TransitionID, Vec<Value<N>>>)> {
+        self.transactions.iter()
+    }
+}
+
+impl<N: Network> Default for FinalizeReceipt<N> {
+    /// Returns a new, empty finalize receipt.
+    fn default() -> Self {
+        Self::new()
+    }
+}
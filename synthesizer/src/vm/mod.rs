@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod helpers;
+pub use helpers::*;
 
 mod authorize;
 mod deploy;
@@ -24,24 +25,35 @@ mod verify;
 
 use crate::{
     atomic_write_batch,
-    block::{Block, Transaction, Transactions, Transition},
+    block::{Block, Header, Transaction, Transactions, Transition},
     cast_ref,
+    coinbase_reward,
     process,
     process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process, Query},
     program::Program,
-    store::{BlockStore, ConsensusStorage, ConsensusStore, ProgramStore, TransactionStore, TransitionStore},
+    prover_rewards,
+    store::{
+        BlockStore,
+        ConsensusStorage,
+        ConsensusStore,
+        FinalizeStatus,
+        ProgramStore,
+        TransactionStore,
+        TransitionStore,
+    },
     CallMetrics,
 };
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Record, Response, Value},
-    types::Field,
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Response, Value},
+    types::{Address, Field, U64},
 };
 
 use aleo_std::prelude::{finish, lap, timer};
-use parking_lot::RwLock;
-use std::sync::Arc;
+use indexmap::IndexMap;
+use parking_lot::{Mutex, RwLock};
+use std::{collections::HashSet, sync::Arc};
 
 #[derive(Clone)]
 pub struct VM<N: Network, C: ConsensusStorage<N>> {
@@ -49,6 +61,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The lock held while a block is added, or while transactions are speculated.
+    block_lock: Arc<Mutex<()>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -60,18 +74,48 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Retrieve the transaction store.
         let transaction_store = store.transaction_store();
-        // Load the deployments from the store.
+        // Retrieve the latest edition of each deployed program from the store.
+        let mut latest_deployments = IndexMap::<ProgramID<N>, Deployment<N>>::new();
         for transaction_id in transaction_store.deployment_transaction_ids() {
+            // Skip the deployment, if it was not accepted during finalize.
+            if transaction_store.get_finalize_status(&transaction_id)? != Some(FinalizeStatus::Accepted) {
+                continue;
+            }
             // Retrieve the deployment.
-            match transaction_store.get_deployment(&transaction_id)? {
-                // Load the deployment.
-                Some(deployment) => process.load_deployment(&deployment)?,
+            let deployment = match transaction_store.get_deployment(&transaction_id)? {
+                Some(deployment) => deployment,
                 None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
             };
+            // Keep the deployment, if it is the latest edition of the program seen so far.
+            match latest_deployments.get(deployment.program_id()) {
+                Some(latest) if latest.edition() >= deployment.edition() => (),
+                _ => {
+                    latest_deployments.insert(*deployment.program_id(), deployment);
+                }
+            }
+        }
+        let mut deployments: Vec<_> = latest_deployments.into_values().collect();
+
+        // Load the deployments, ensuring each program is loaded after its imports.
+        // Note: Persistent storage does not preserve the insertion order of the deployments.
+        while !deployments.is_empty() {
+            // Track the number of deployments remaining before this pass.
+            let num_deployments = deployments.len();
+            // Load every deployment whose imports have already been loaded.
+            let mut pending = Vec::with_capacity(num_deployments);
+            for deployment in deployments {
+                match deployment.program().imports().keys().all(|import| process.contains_program(import)) {
+                    true => process.load_deployment(&deployment)?,
+                    false => pending.push(deployment),
+                }
+            }
+            // Ensure progress was made, otherwise the remaining programs have missing imports.
+            ensure!(pending.len() < num_deployments, "Failed to load deployments with missing imports from storage");
+            deployments = pending;
         }
 
         // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(Self { process: Arc::new(RwLock::new(process)), store, block_lock: Default::default() })
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -81,16 +125,37 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Adds the given block into the VM.
+    /// Note: A transaction that fails to finalize is marked as rejected, and does not reject the block.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
-        // First, insert the block.
-        self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(block.transactions()) {
-            Ok(_) => Ok(()),
+        // Acquire the block lock, to prevent a concurrent speculation.
+        let _block_lock = self.block_lock.lock();
+        // Save the process if the block contains a deployment, in case the block is not added.
+        let has_deployments =
+            block.transactions().values().any(|transaction| matches!(transaction, Transaction::Deploy(..)));
+        let process = has_deployments.then(|| self.process.read().clone());
+
+        // Insert the block, and finalize its transactions and coinbase, in one atomic batch.
+        // Note: If any step fails, the batch is aborted, so none of the writes of the block persist.
+        let add_block = || -> Result<()> {
+            atomic_write_batch!(self, {
+                // First, insert the block.
+                self.block_store().insert(block)?;
+                // Next, finalize the transactions.
+                self.finalize(block.transactions())?;
+                // Lastly, finalize the coinbase.
+                self.finalize_coinbase(block)?;
+                Ok(())
+            });
+            Ok(())
+        };
+        match add_block() {
+            Ok(()) => Ok(()),
             Err(error) => {
-                // Rollback the block.
-                self.block_store().remove_last_n(1)?;
+                // Revert the programs deployed in the block.
+                if let Some(process) = process {
+                    *self.process.write() = process;
+                }
                 // Return the error.
                 Err(error)
             }
@@ -151,7 +216,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 #[cfg(test)]
 pub(crate) mod test_helpers {
     use super::*;
-    use crate::{program::Program, Block, ConsensusMemory, Fee, Inclusion, Transition};
+    use crate::{program::Program, Block, ConsensusMemory, Fee, Inclusion, Metadata, Transition};
     use console::{
         account::{Address, ViewKey},
         network::Testnet3,
@@ -203,6 +268,113 @@ pub(crate) mod test_helpers {
         vm
     }
 
+    /// Returns a new block with the given transactions, that follows the latest block of the given VM.
+    pub(crate) fn sample_next_block<C: ConsensusStorage<CurrentNetwork>>(
+        vm: &VM<CurrentNetwork, C>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        transactions: &[Transaction<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<Block<CurrentNetwork>> {
+        // Retrieve the latest block header.
+        let (_, previous_header) = latest_block_header(vm)?;
+        // Construct the block, one anchor time after the latest block.
+        let height = previous_header.height() + 1;
+        let timestamp = previous_header.timestamp() + CurrentNetwork::ANCHOR_TIME as i64;
+        sample_block(vm, private_key, transactions, height, timestamp, rng)
+    }
+
+    /// Returns a new block with the given transactions, height, and timestamp,
+    /// that follows the latest block of the given VM.
+    pub(crate) fn sample_block<C: ConsensusStorage<CurrentNetwork>>(
+        vm: &VM<CurrentNetwork, C>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        transactions: &[Transaction<CurrentNetwork>],
+        height: u32,
+        timestamp: i64,
+        rng: &mut TestRng,
+    ) -> Result<Block<CurrentNetwork>> {
+        // Retrieve the latest block hash and header.
+        let (previous_hash, previous_header) = latest_block_header(vm)?;
+
+        // Construct the metadata, retargeted from the latest block.
+        let (coinbase_target, proof_target) = previous_header.metadata().next_targets(timestamp)?;
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            previous_header.round() + 1,
+            height,
+            coinbase_target,
+            proof_target,
+            previous_header.last_coinbase_target(),
+            previous_header.last_coinbase_timestamp(),
+            timestamp,
+        )?;
+
+        // Construct the block.
+        let transactions = Transactions::from(transactions);
+        let header =
+            Header::from(*vm.block_store().current_state_root(), transactions.to_root()?, Field::zero(), metadata)?;
+        Block::new(private_key, previous_hash, header, transactions, None, rng)
+    }
+
+    /// Returns the hash and header of the latest block of the given VM.
+    fn latest_block_header<C: ConsensusStorage<CurrentNetwork>>(
+        vm: &VM<CurrentNetwork, C>,
+    ) -> Result<(<CurrentNetwork as Network>::BlockHash, Header<CurrentNetwork>)> {
+        let height = match vm.block_store().current_block_height() {
+            Some(height) => height,
+            None => bail!("The chain is empty"),
+        };
+        let block_hash = match vm.block_store().get_block_hash(height)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block {height}"),
+        };
+        match vm.block_store().get_block_header(&block_hash)? {
+            Some(header) => Ok((block_hash, header)),
+            None => bail!("Missing block header {height}"),
+        }
+    }
+
+    /// Returns the block after the genesis block, which mints two records to the genesis caller.
+    pub(crate) fn sample_funding_block(rng: &mut TestRng) -> Block<CurrentNetwork> {
+        static INSTANCE: OnceCell<Block<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize a new caller.
+                let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+                let address = Address::try_from(&caller_private_key).unwrap();
+
+                // Initialize the VM.
+                let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+                // Mint two records to the caller.
+                let transactions = (0..2)
+                    .map(|_| {
+                        let inputs = [address.to_string(), format!("{}_u64", CurrentNetwork::STARTING_SUPPLY)];
+                        let authorization =
+                            vm.authorize(&caller_private_key, "credits.aleo", "mint", inputs, rng).unwrap();
+                        Transaction::execute_authorization(&vm, authorization, None, rng).unwrap()
+                    })
+                    .collect::<Vec<_>>();
+
+                // Return the block.
+                crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &transactions, rng).unwrap()
+            })
+            .clone()
+    }
+
+    pub(crate) fn sample_vm_with_funding_block(
+        rng: &mut TestRng,
+    ) -> VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        // Initialize the funding block.
+        let funding = crate::vm::test_helpers::sample_funding_block(rng);
+        // Update the VM.
+        vm.add_next_block(&funding).unwrap();
+        // Return the VM.
+        vm
+    }
+
     pub(crate) fn sample_program() -> Program<CurrentNetwork> {
         static INSTANCE: OnceCell<Program<CurrentNetwork>> = OnceCell::new();
         INSTANCE
@@ -260,9 +432,8 @@ function compute:
                     genesis.transitions().cloned().flat_map(Transition::into_records).collect::<IndexMap<_, _>>();
                 trace!("Unspent Records:\n{:#?}", records);
 
-                // Prepare the additional fee.
+                // Prepare the credits record for the fee.
                 let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-                let additional_fee = (credits, 10);
 
                 // Initialize the VM.
                 let vm = sample_vm();
@@ -270,8 +441,11 @@ function compute:
                 vm.add_next_block(&genesis).unwrap();
 
                 // Deploy.
-                let transaction =
-                    Transaction::deploy(&vm, &caller_private_key, &program, additional_fee, None, rng).unwrap();
+                let deployment = vm.deploy(&program, rng).unwrap();
+                // Pay the estimated fee of the deployment.
+                let fee_in_gates = vm.estimate_deployment_fee(&deployment).unwrap();
+                let (_, fee, _) = vm.execute_fee(&caller_private_key, credits, fee_in_gates, None, rng).unwrap();
+                let transaction = Transaction::from_deployment(deployment, fee).unwrap();
                 // Verify.
                 assert!(vm.verify_transaction(&transaction));
                 // Return the transaction.
@@ -289,6 +463,72 @@ function compute:
                 let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
                 let address = Address::try_from(&caller_private_key).unwrap();
 
+                // Initialize the funding block.
+                let funding = crate::vm::test_helpers::sample_funding_block(rng);
+
+                // Fetch the unspent records.
+                let records =
+                    funding.transitions().cloned().flat_map(Transition::into_records).collect::<IndexMap<_, _>>();
+                trace!("Unspent Records:\n{:#?}", records);
+
+                // Select a record to spend, and a record for the fee.
+                let mut records = records.values().map(|record| record.decrypt(&caller_view_key).unwrap());
+                let (record, credits) = (records.next().unwrap(), records.next().unwrap());
+
+                // Initialize the VM.
+                let vm = crate::vm::test_helpers::sample_vm_with_funding_block(rng);
+
+                // Authorize.
+                let authorize = |rng: &mut TestRng| {
+                    let authorization = vm
+                        .authorize(
+                            &caller_private_key,
+                            "credits.aleo",
+                            "transfer",
+                            [
+                                Value::<CurrentNetwork>::Record(record.clone()),
+                                Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                                Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+                            ],
+                            rng,
+                        )
+                        .unwrap();
+                    assert_eq!(authorization.len(), 1);
+                    authorization
+                };
+
+                // Estimate the fee of the execution.
+                let (_, execution, _) = vm.execute(authorize(rng), None, rng).unwrap();
+                let fee_in_gates = vm.estimate_execution_fee(&execution).unwrap();
+
+                // Execute, paying the estimated fee as an additional fee.
+                let transaction = Transaction::execute_authorization_with_additional_fee(
+                    &vm,
+                    &caller_private_key,
+                    authorize(rng),
+                    Some((credits, fee_in_gates)),
+                    None,
+                    rng,
+                )
+                .unwrap();
+                // Verify.
+                assert!(vm.verify_transaction(&transaction));
+                // Return the transaction.
+                transaction
+            })
+            .clone()
+    }
+
+    /// Returns an execution of `credits.aleo/fee`, which pays its own fee with the genesis record,
+    /// and is valid on top of the genesis block.
+    pub(crate) fn sample_fee_execution_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
+        static INSTANCE: OnceCell<Transaction<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize a new caller.
+                let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+                let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
                 // Initialize the genesis block.
                 let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
 
@@ -305,25 +545,31 @@ function compute:
                 // Update the VM.
                 vm.add_next_block(&genesis).unwrap();
 
-                // Authorize.
-                let authorization = vm
-                    .authorize(
-                        &caller_private_key,
-                        "credits.aleo",
-                        "transfer",
-                        [
-                            Value::<CurrentNetwork>::Record(record),
-                            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
-                            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
-                        ]
-                        .into_iter(),
-                        rng,
-                    )
-                    .unwrap();
-                assert_eq!(authorization.len(), 1);
-
-                // Execute.
-                let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+                // Authorize a call to fee, which burns the given amount of gates as the fee of its transition.
+                let authorize = |fee_in_gates: u64, rng: &mut TestRng| {
+                    let authorization = vm
+                        .authorize(
+                            &caller_private_key,
+                            "credits.aleo",
+                            "fee",
+                            [
+                                Value::<CurrentNetwork>::Record(record.clone()),
+                                Value::<CurrentNetwork>::from_str(&format!("{fee_in_gates}u64")).unwrap(),
+                            ],
+                            rng,
+                        )
+                        .unwrap();
+                    assert_eq!(authorization.len(), 1);
+                    authorization
+                };
+
+                // Estimate the fee of the execution.
+                let (_, execution, _) = vm.execute(authorize(0, rng), None, rng).unwrap();
+                let fee_in_gates = vm.estimate_execution_fee(&execution).unwrap();
+
+                // Execute, paying the estimated fee.
+                let transaction =
+                    Transaction::execute_authorization(&vm, authorize(fee_in_gates, rng), None, rng).unwrap();
                 // Verify.
                 assert!(vm.verify_transaction(&transaction));
                 // Return the transaction.
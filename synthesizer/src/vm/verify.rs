@@ -28,6 +28,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
     }
 
+    /// Returns `true` if the transaction is valid, and does not conflict with the ledger state.
+    pub fn verify_unconfirmed_transaction(&self, transaction: &Transaction<N>) -> bool {
+        match self.check_unconfirmed_transaction(transaction) {
+            Ok(_) => true,
+            Err(error) => {
+                warn!("{error}");
+                false
+            }
+        }
+    }
+
     /// Returns `true` if the deployment is valid.
     pub fn verify_deployment(&self, deployment: &Deployment<N>) -> bool {
         match self.check_deployment(deployment) {
@@ -61,6 +72,289 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
     }
 
+    /// Verifies the transaction against the ledger state, and then in the VM. On failure, returns an error.
+    /// This method is intended to admit transactions (e.g. into a mempool) before they are included in a block.
+    #[inline]
+    pub fn check_unconfirmed_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Check the ledger state first, as it is cheaper than verifying the proofs.
+        self.check_transaction_state(transaction)?;
+        self.check_transaction(transaction)
+    }
+
+    /// Verifies the transaction does not conflict with the ledger state. On failure, returns an error.
+    /// This ensures the transaction is not a replay, and does not spend a record that was already spent.
+    /// Note: This does *not* verify the transaction itself, which is done by `check_transaction`.
+    pub fn check_transaction_state(&self, transaction: &Transaction<N>) -> Result<()> {
+        let timer = timer!("VM::check_transaction_state");
+
+        // Ensure the transaction does not already exist.
+        if self.transaction_store().contains_transaction_id(&transaction.id())? {
+            bail!("Transaction '{}' already exists in the ledger", transaction.id());
+        }
+
+        // Ensure the program ID is not already taken, or that an upgrade is the next edition of the program.
+        if let Transaction::Deploy(_, deployment, _) = transaction {
+            let program_id = deployment.program_id();
+            match deployment.is_upgrade() {
+                true => {
+                    // Ensure the program exists.
+                    if !self.contains_program(program_id) {
+                        bail!("Program '{program_id}' does not exist in the ledger, and cannot be upgraded");
+                    }
+                    // Ensure the upgrade is the next edition of the program.
+                    let (edition, upgrade) =
+                        (self.program_store().get_edition(program_id)?.unwrap_or(N::EDITION), deployment.edition());
+                    if edition.checked_add(1) != Some(upgrade) {
+                        bail!("Program '{program_id}' is at edition {edition}, found an upgrade to edition {upgrade}");
+                    }
+                }
+                false => {
+                    if self.transaction_store().contains_program_id(program_id)? || self.contains_program(program_id) {
+                        bail!("Program '{program_id}' already exists in the ledger");
+                    }
+                }
+            }
+        }
+        lap!(timer, "Check the transaction ID");
+
+        // Retrieve the transition store.
+        let transition_store = self.transition_store();
+
+        // Ensure the transition IDs do not already exist, including in a rejected execution.
+        for transition_id in transaction.transition_ids() {
+            if transition_store.contains_transition_id(transition_id)?
+                || self.transaction_store().contains_rejected_transition_id(transition_id)?
+            {
+                bail!("Transition '{transition_id}' already exists in the ledger");
+            }
+        }
+        // Ensure the transition public keys do not already exist.
+        for tpk in transaction.transition_public_keys() {
+            if transition_store.contains_tpk(tpk)? {
+                bail!("Transition public key '{tpk}' already exists in the ledger");
+            }
+        }
+        // Ensure the serial numbers do not already exist.
+        for serial_number in transaction.serial_numbers() {
+            if transition_store.contains_serial_number(serial_number)? {
+                bail!("Serial number '{serial_number}' already exists in the ledger (the record is already spent)");
+            }
+        }
+        // Ensure the tags do not already exist.
+        for tag in transaction.tags() {
+            if transition_store.contains_tag(tag)? {
+                bail!("Tag '{tag}' already exists in the ledger");
+            }
+        }
+        // Ensure the commitments do not already exist.
+        for commitment in transaction.commitments() {
+            if transition_store.contains_commitment(commitment)? {
+                bail!("Commitment '{commitment}' already exists in the ledger");
+            }
+        }
+        // Ensure the nonces do not already exist.
+        for nonce in transaction.nonces() {
+            if transition_store.contains_nonce(nonce)? {
+                bail!("Nonce '{nonce}' already exists in the ledger");
+            }
+        }
+        lap!(timer, "Check the transitions");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given block as the next block of the chain. On failure, returns an error.
+    /// The error is a `BlockError`, unless the failure occurred while reading from storage.
+    ///
+    /// Note: The coinbase proofs are not verified, as the VM does not hold the coinbase puzzle.
+    pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        let timer = timer!("VM::check_next_block");
+
+        // Ensure the block header is well-formed.
+        if !block.header().is_valid() {
+            return Err(BlockError::<N>::InvalidHeader.into());
+        }
+        // Ensure the block does not already exist.
+        if self.block_store().contains_block_hash(&block.hash())? {
+            return Err(BlockError::<N>::AlreadyExists(block.hash()).into());
+        }
+
+        match self.block_store().current_block_height() {
+            // If the chain is empty, ensure the block is a genesis block.
+            None => {
+                if !block.is_genesis() {
+                    return Err(BlockError::<N>::InvalidGenesis.into());
+                }
+            }
+            // Otherwise, ensure the block follows the chain tip.
+            Some(height) => {
+                // Retrieve the chain tip.
+                let previous_hash = match self.block_store().get_block_hash(height)? {
+                    Some(previous_hash) => previous_hash,
+                    None => bail!("Missing block hash for block {height}"),
+                };
+                let previous_header = match self.block_store().get_block_header(&previous_hash)? {
+                    Some(previous_header) => previous_header,
+                    None => bail!("Missing block header for block {height}"),
+                };
+                self.check_block_header(block, height, previous_hash, &previous_header)?;
+            }
+        }
+        lap!(timer, "Check the block header");
+
+        // Ensure the transactions root is correct.
+        let transactions_root = block.transactions().to_root()?;
+        if block.transactions_root() != transactions_root {
+            return Err(BlockError::<N>::InvalidTransactionsRoot {
+                expected: transactions_root,
+                found: block.transactions_root(),
+            }
+            .into());
+        }
+
+        // Ensure the coinbase solution is valid, if it exists.
+        if let Some(coinbase) = block.coinbase() {
+            // Ensure each partial solution meets the proof target.
+            for partial_solution in coinbase.partial_solutions() {
+                if partial_solution.to_target()? < block.proof_target() {
+                    let error = format!("A partial solution does not meet the proof target {}", block.proof_target());
+                    return Err(BlockError::<N>::InvalidCoinbaseSolution(error).into());
+                }
+            }
+            // Ensure the cumulative proof target meets the coinbase target.
+            if coinbase.to_cumulative_proof_target()? < u128::from(block.coinbase_target()) {
+                let error = "The cumulative proof target does not meet the coinbase target".to_string();
+                return Err(BlockError::<N>::InvalidCoinbaseSolution(error).into());
+            }
+            // Ensure the puzzle commitments are new.
+            ensure_unique(
+                coinbase.puzzle_commitments(),
+                |commitment| self.block_store().contains_puzzle_commitment(commitment),
+                BlockError::<N>::DuplicatePuzzleCommitment,
+            )?;
+        }
+        lap!(timer, "Check the coinbase solution");
+
+        // Ensure the transactions, transitions, and their components are new.
+        // Note: A serial number that already exists in storage indicates a double-spend.
+        let transaction_store = self.transaction_store();
+        let transition_store = self.transition_store();
+        ensure_unique(
+            block.transaction_ids().copied(),
+            |id| transaction_store.contains_transaction_id(id),
+            BlockError::<N>::DuplicateTransaction,
+        )?;
+        ensure_unique(
+            block.transition_ids().copied(),
+            |id| {
+                Ok(transition_store.contains_transition_id(id)?
+                    || transaction_store.contains_rejected_transition_id(id)?)
+            },
+            BlockError::<N>::DuplicateTransition,
+        )?;
+        ensure_unique(
+            block.transition_public_keys().copied(),
+            |tpk| transition_store.contains_tpk(tpk),
+            BlockError::<N>::DuplicateTransitionPublicKey,
+        )?;
+        ensure_unique(
+            block.serial_numbers().copied(),
+            |serial_number| transition_store.contains_serial_number(serial_number),
+            BlockError::<N>::DuplicateSerialNumber,
+        )?;
+        ensure_unique(block.tags().copied(), |tag| transition_store.contains_tag(tag), BlockError::<N>::DuplicateTag)?;
+        ensure_unique(
+            block.commitments().copied(),
+            |commitment| transition_store.contains_commitment(commitment),
+            BlockError::<N>::DuplicateCommitment,
+        )?;
+        ensure_unique(
+            block.nonces().copied(),
+            |nonce| transition_store.contains_nonce(nonce),
+            BlockError::<N>::DuplicateNonce,
+        )?;
+        lap!(timer, "Check for duplicate elements");
+
+        // Ensure each transaction is valid.
+        for transaction in block.transactions().values() {
+            if let Err(error) = self.check_transaction(transaction) {
+                let (id, error) = (transaction.id(), error.to_string());
+                return Err(BlockError::<N>::InvalidTransaction { id, error }.into());
+            }
+        }
+        lap!(timer, "Verify the transactions");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the header of the given block follows the header of the chain tip, at the given height.
+    fn check_block_header(
+        &self,
+        block: &Block<N>,
+        previous_height: u32,
+        previous_hash: N::BlockHash,
+        previous_header: &Header<N>,
+    ) -> Result<()> {
+        // Ensure the previous block hash is correct.
+        if block.previous_hash() != previous_hash {
+            let (expected, found) = (previous_hash, block.previous_hash());
+            return Err(BlockError::<N>::InvalidPreviousHash { expected, found }.into());
+        }
+        // Ensure the block height is correct.
+        let expected_height = previous_height.saturating_add(1);
+        if block.height() != expected_height {
+            return Err(BlockError::<N>::InvalidHeight { expected: expected_height, found: block.height() }.into());
+        }
+        // Ensure the block round advances.
+        if block.round() <= previous_header.round() {
+            let (previous, found) = (previous_header.round(), block.round());
+            return Err(BlockError::<N>::InvalidRound { previous, found }.into());
+        }
+        // Ensure the block timestamp advances.
+        if block.timestamp() <= previous_header.timestamp() {
+            let (previous, found) = (previous_header.timestamp(), block.timestamp());
+            return Err(BlockError::<N>::InvalidTimestamp { previous, found }.into());
+        }
+
+        // Compute the expected coinbase target and proof target, retargeted from the last coinbase.
+        let (expected_coinbase_target, expected_proof_target) =
+            previous_header.metadata().next_targets(block.timestamp())?;
+        // Ensure the coinbase target is correct.
+        if block.coinbase_target() != expected_coinbase_target {
+            let (expected, found) = (expected_coinbase_target, block.coinbase_target());
+            return Err(BlockError::<N>::InvalidCoinbaseTarget { expected, found }.into());
+        }
+        // Ensure the proof target is correct.
+        if block.proof_target() != expected_proof_target {
+            let (expected, found) = (expected_proof_target, block.proof_target());
+            return Err(BlockError::<N>::InvalidProofTarget { expected, found }.into());
+        }
+
+        // Ensure the last coinbase target and timestamp are correct.
+        let (expected_last_coinbase_target, expected_last_coinbase_timestamp) = match block.coinbase() {
+            Some(_) => (block.coinbase_target(), block.timestamp()),
+            None => (previous_header.last_coinbase_target(), previous_header.last_coinbase_timestamp()),
+        };
+        if block.last_coinbase_target() != expected_last_coinbase_target {
+            let (expected, found) = (expected_last_coinbase_target, block.last_coinbase_target());
+            return Err(BlockError::<N>::InvalidLastCoinbaseTarget { expected, found }.into());
+        }
+        if block.last_coinbase_timestamp() != expected_last_coinbase_timestamp {
+            let (expected, found) = (expected_last_coinbase_timestamp, block.last_coinbase_timestamp());
+            return Err(BlockError::<N>::InvalidLastCoinbaseTimestamp { expected, found }.into());
+        }
+
+        // Ensure the previous state root is the current state root.
+        let current_state_root = *self.block_store().current_state_root();
+        if block.previous_state_root() != current_state_root {
+            let (expected, found) = (current_state_root, block.previous_state_root());
+            return Err(BlockError::<N>::InvalidPreviousStateRoot { expected, found }.into());
+        }
+        Ok(())
+    }
+
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
@@ -112,6 +406,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if let Err(error) = Transaction::check_deployment_size(deployment) {
                     bail!("Invalid transaction size (deployment): {error}");
                 }
+                // Ensure the fee covers the cost of the deployment.
+                check_minimum_fee(&self.process.read(), transaction)?;
                 // Verify the deployment.
                 self.check_deployment(deployment)?;
 
@@ -123,6 +419,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if let Err(error) = Transaction::check_execution_size(execution) {
                     bail!("Invalid transaction size (execution): {error}");
                 }
+                // Ensure the fee covers the cost of the execution.
+                check_minimum_fee(&self.process.read(), transaction)?;
 
                 // Verify the additional fee, if it exists.
                 if let Some(additional_fee) = additional_fee {
@@ -200,7 +498,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let timer = timer!("VM::verify_fee");
 
         // Verify the fee.
-        let verification = self.process.read().verify_fee(fee);
+        let verification = self.process.read().verify_fee::<true>(fee);
         finish!(timer);
 
         match verification {
@@ -215,6 +513,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 }
 
+/// Ensures the given items are unique, and do not already exist in storage.
+fn ensure_unique<N: Network, T: Copy + Eq + core::hash::Hash>(
+    items: impl Iterator<Item = T>,
+    contains: impl Fn(&T) -> Result<bool>,
+    error: impl Fn(T) -> BlockError<N>,
+) -> Result<()> {
+    let mut seen = std::collections::HashSet::new();
+    for item in items {
+        if !seen.insert(item) || contains(&item)? {
+            return Err(error(item).into());
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -230,7 +543,7 @@ mod tests {
     #[test]
     fn test_verify() {
         let rng = &mut TestRng::default();
-        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let vm = crate::vm::test_helpers::sample_vm_with_funding_block(rng);
 
         // Fetch a deployment transaction.
         let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
@@ -270,7 +583,7 @@ mod tests {
     #[test]
     fn test_verify_execution() {
         let rng = &mut TestRng::default();
-        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let vm = crate::vm::test_helpers::sample_vm_with_funding_block(rng);
 
         // Fetch a execution transaction.
         let transaction = crate::vm::test_helpers::sample_execution_transaction(rng);
@@ -312,9 +625,8 @@ mod tests {
         // Fetch the unspent records.
         let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
 
-        // Prepare the additional fee.
+        // Prepare the credits record for the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let additional_fee = (credits, 10);
 
         // Initialize the VM.
         let vm = crate::vm::test_helpers::sample_vm();
@@ -323,35 +635,21 @@ mod tests {
 
         // Deploy.
         let program = crate::vm::test_helpers::sample_program();
-        let deployment_transaction =
-            Transaction::deploy(&vm, &caller_private_key, &program, additional_fee, None, rng).unwrap();
-
-        // Construct the new block header.
-        let transactions = Transactions::from(&[deployment_transaction]);
-        // Construct the metadata associated with the block.
-        let deployment_metadata = Metadata::new(
-            CurrentNetwork::ID,
-            1,
-            1,
-            CurrentNetwork::GENESIS_COINBASE_TARGET,
-            CurrentNetwork::GENESIS_PROOF_TARGET,
-            genesis.last_coinbase_target(),
-            genesis.last_coinbase_timestamp(),
-            CurrentNetwork::GENESIS_TIMESTAMP + 1,
-        )
-        .unwrap();
-
-        let deployment_header = Header::from(
-            *vm.block_store().current_state_root(),
-            transactions.to_root().unwrap(),
-            Field::zero(),
-            deployment_metadata,
-        )
-        .unwrap();
+        let deployment = vm.deploy(&program, rng).unwrap();
+        // Ensure a fee below the estimated fee is rejected.
+        let fee_in_gates = vm.estimate_deployment_fee(&deployment).unwrap();
+        let (_, fee, _) = vm.execute_fee(&caller_private_key, credits.clone(), fee_in_gates - 1, None, rng).unwrap();
+        let deployment_transaction = Transaction::from_deployment(deployment.clone(), fee).unwrap();
+        assert!(vm.check_transaction(&deployment_transaction).is_err());
+        // Pay the estimated fee of the deployment.
+        let (_, fee, _) = vm.execute_fee(&caller_private_key, credits, fee_in_gates, None, rng).unwrap();
+        let deployment_transaction = Transaction::from_deployment(deployment, fee).unwrap();
+        assert_eq!(vm.estimate_fee(&deployment_transaction).unwrap(), fee_in_gates);
 
         // Construct a new block for the deploy transaction.
         let deployment_block =
-            Block::new(&caller_private_key, genesis.hash(), deployment_header, transactions, None, rng).unwrap();
+            crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[deployment_transaction], rng)
+                .unwrap();
 
         // Add the deployment block.
         vm.add_next_block(&deployment_block).unwrap();
@@ -359,9 +657,8 @@ mod tests {
         // Fetch the unspent records.
         let records = deployment_block.records().collect::<indexmap::IndexMap<_, _>>();
 
-        // Prepare the additional fee.
+        // Prepare the credits record for the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let additional_fee = (credits, 10);
 
         // Authorize.
         let authorization = vm
@@ -380,18 +677,189 @@ mod tests {
         assert_eq!(authorization.len(), 1);
 
         // Execute.
-        let transaction = Transaction::execute_authorization_with_additional_fee(
-            &vm,
+        let (_, execution, _) = vm.execute(authorization, None, rng).unwrap();
+        // Pay the estimated fee of the execution.
+        let fee_in_gates = vm.estimate_execution_fee(&execution).unwrap();
+        let (_, fee, _) = vm.execute_fee(&caller_private_key, credits, fee_in_gates, None, rng).unwrap();
+        let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
+
+        // Verify.
+        assert!(vm.check_transaction(&transaction).is_ok());
+        assert!(vm.verify_transaction(&transaction));
+    }
+
+    #[test]
+    fn test_check_transaction_prices_credits_executions() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Ensure the coinbase execution of the genesis block is exempt from the fee.
+        let genesis_transaction = genesis.transactions().values().next().unwrap();
+        assert!(vm.check_transaction(genesis_transaction).is_ok());
+
+        // Fetch the unspent records.
+        let records = genesis.records().collect::<indexmap::IndexMap<_, _>>();
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Authorize a call to 'credits.aleo/transfer'.
+        let authorization = vm
+            .authorize(
+                &caller_private_key,
+                "credits.aleo",
+                "transfer",
+                [
+                    Value::<CurrentNetwork>::Record(record),
+                    Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                    Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+                ],
+                rng,
+            )
+            .unwrap();
+
+        // Ensure a transfer without a fee is rejected.
+        let transaction = Transaction::execute_authorization(&vm, authorization, None, rng).unwrap();
+        assert!(vm.check_transaction(&transaction).is_err());
+        assert!(!vm.verify_transaction(&transaction));
+    }
+
+    #[test]
+    fn test_check_next_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Ensure the genesis block is valid for an empty chain.
+        vm.check_next_block(&genesis).unwrap();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Ensure the genesis block cannot be added again.
+        let error = vm.check_next_block(&genesis).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(&BlockError::AlreadyExists(genesis.hash()))
+        );
+
+        let transactions = [crate::vm::test_helpers::sample_fee_execution_transaction(rng)];
+
+        // Ensure a block with an incorrect previous hash is rejected.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &transactions, rng).unwrap();
+        let block = Block::new(
             &caller_private_key,
-            authorization,
-            Some(additional_fee),
+            Default::default(),
+            *block.header(),
+            block.transactions().clone(),
             None,
             rng,
         )
         .unwrap();
+        let error = vm.check_next_block(&block).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(BlockError::InvalidPreviousHash { .. })
+        ));
+
+        // Ensure a block with an incorrect height is rejected.
+        let timestamp = genesis.timestamp() + CurrentNetwork::ANCHOR_TIME as i64;
+        let block =
+            crate::vm::test_helpers::sample_block(&vm, &caller_private_key, &transactions, 2, timestamp, rng).unwrap();
+        let error = vm.check_next_block(&block).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(&BlockError::InvalidHeight { expected: 1, found: 2 })
+        );
+
+        // Ensure a block that replays the genesis transaction is rejected.
+        let genesis_transactions = genesis.transactions().values().cloned().collect::<Vec<_>>();
+        let block =
+            crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &genesis_transactions, rng).unwrap();
+        let error = vm.check_next_block(&block).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(BlockError::DuplicateTransaction(..))
+        ));
+
+        // Fetch a deployment transaction, whose fee spends the same record as the execution transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let serial_number = *deployment_transaction.serial_numbers().next().unwrap();
+        assert!(transactions[0].serial_numbers().any(|sn| *sn == serial_number));
+
+        // Ensure a block that spends the same record twice is rejected.
+        let double_spend = [transactions[0].clone(), deployment_transaction.clone()];
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &double_spend, rng).unwrap();
+        let error = vm.check_next_block(&block).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(&BlockError::DuplicateSerialNumber(serial_number))
+        );
+
+        // Ensure a valid block is accepted.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &transactions, rng).unwrap();
+        vm.check_next_block(&block).unwrap();
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure a block that spends a record already spent in the ledger is rejected.
+        let block = crate::vm::test_helpers::sample_next_block(
+            &vm,
+            &caller_private_key,
+            std::slice::from_ref(&deployment_transaction),
+            rng,
+        )
+        .unwrap();
+        let error = vm.check_next_block(&block).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BlockError<CurrentNetwork>>(),
+            Some(&BlockError::DuplicateSerialNumber(serial_number))
+        );
+    }
 
-        // Verify.
-        assert!(vm.check_transaction(&transaction).is_ok());
-        assert!(vm.verify_transaction(&transaction));
+    #[test]
+    fn test_check_transaction_state() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Ensure a new transaction is admitted.
+        let execution_transaction = crate::vm::test_helpers::sample_fee_execution_transaction(rng);
+        assert!(vm.check_transaction_state(&execution_transaction).is_ok());
+        assert!(vm.verify_unconfirmed_transaction(&execution_transaction));
+
+        // Ensure a replay of the genesis transaction is rejected, even though its proofs are valid.
+        let genesis_transaction = genesis.transactions().values().next().unwrap();
+        assert!(vm.check_transaction(genesis_transaction).is_ok());
+        assert!(vm.check_transaction_state(genesis_transaction).is_err());
+        assert!(!vm.verify_unconfirmed_transaction(genesis_transaction));
+
+        // Ensure a redeployment of an existing program is rejected.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        assert!(vm.check_transaction_state(&deployment_transaction).is_ok());
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let block = crate::vm::test_helpers::sample_next_block(
+            &vm,
+            &caller_private_key,
+            std::slice::from_ref(&deployment_transaction),
+            rng,
+        )
+        .unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(
+            vm.block_store().get_finalize_status(&deployment_transaction.id()).unwrap(),
+            Some(FinalizeStatus::Accepted)
+        );
+        assert!(vm.check_transaction_state(&deployment_transaction).is_err());
+        assert!(!vm.verify_unconfirmed_transaction(&deployment_transaction));
     }
 }
@@ -14,9 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::Updater;
+use crate::cli::Command;
 
-use anyhow::Result;
 use clap::Parser;
 
 #[derive(Debug, Parser)]
@@ -29,49 +28,3 @@ pub struct CLI {
     #[clap(subcommand)]
     pub command: Command,
 }
-
-#[derive(Debug, Parser)]
-pub enum Command {
-    /// Update snarkVM to the latest version
-    Update {
-        /// Lists all available versions of snarkVM
-        #[clap(short = 'l', long)]
-        list: bool,
-        /// Suppress outputs to terminal
-        #[clap(short = 'q', long)]
-        quiet: bool,
-    },
-}
-
-impl Command {
-    /// Parse the command.
-    pub fn start(&self) -> Result<String> {
-        match self {
-            Command::Update { list, quiet } => match list {
-                true => match Updater::show_available_releases() {
-                    Ok(output) => Ok(output),
-                    Err(error) => Ok(format!("Failed to list the available versions of snarkVM\n{error}\n")),
-                },
-                false => {
-                    let result = Updater::update_to_latest_release(!quiet);
-                    if !quiet {
-                        match result {
-                            Ok(status) => {
-                                if status.uptodate() {
-                                    Ok("\nsnarkVM is already on the latest version".to_string())
-                                } else if status.updated() {
-                                    Ok(format!("\nsnarkVM has updated to version {}", status.version()))
-                                } else {
-                                    Ok("".to_string())
-                                }
-                            }
-                            Err(e) => Ok(format!("\nFailed to update snarkVM to the latest version\n{e}\n")),
-                        }
-                    } else {
-                        Ok("".to_string())
-                    }
-                }
-            }, // _ => Err(anyhow!("\nUnknown command\n")),
-        }
-    }
-}
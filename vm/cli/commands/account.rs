@@ -0,0 +1,351 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    console::network::prelude::*,
+    file::KeyFile,
+    prelude::{Address, Field, Plaintext, Signature, ViewKey},
+};
+
+use colored::Colorize;
+use std::path::Path;
+
+/// The environment variable from which the key file password is read, if it is set.
+pub(crate) const PASSWORD_ENV_VAR: &str = "SNARKVM_KEYFILE_PASSWORD";
+/// The environment variable from which the private key is read, if it is set.
+pub(crate) const PRIVATE_KEY_ENV_VAR: &str = "SNARKVM_PRIVATE_KEY";
+
+/// Manages Aleo accounts.
+#[derive(Debug, Parser)]
+pub enum Account {
+    /// Generates a new Aleo account.
+    #[clap(name = "new")]
+    New(NewAccount),
+    /// Derives the view key and address of an existing private key.
+    #[clap(name = "import")]
+    Import(ImportAccount),
+    /// Signs a message with a private key.
+    #[clap(name = "sign")]
+    Sign(SignMessage),
+    /// Verifies the signature of a message.
+    #[clap(name = "verify")]
+    Verify(VerifySignature),
+}
+
+impl Account {
+    /// Parses the account command.
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::New(command) => command.parse(),
+            Self::Import(command) => command.parse(),
+            Self::Sign(command) => command.parse(),
+            Self::Verify(command) => command.parse(),
+        }
+    }
+}
+
+/// Generates a new Aleo account.
+#[derive(Debug, Parser)]
+pub struct NewAccount {
+    /// The account seed, as 32 bytes in hex, which are reduced to a field element.
+    #[clap(long)]
+    pub seed: Option<String>,
+    /// The key file in which to save the account, instead of printing the private key.
+    #[clap(long)]
+    pub keyfile: Option<PathBuf>,
+    /// Encrypts the key file with a password, read from `SNARKVM_KEYFILE_PASSWORD`, a prompt, or stdin.
+    #[clap(long, requires = "keyfile")]
+    pub encrypt: bool,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl NewAccount {
+    /// Generates a new Aleo account.
+    pub fn parse(self) -> Result<String> {
+        // Read the key file password, if the key file is encrypted.
+        let password = read_new_password(self.encrypt)?;
+        self.parse_with_password(password.as_deref())
+    }
+
+    /// Generates a new Aleo account, encrypting the key file with the given password if `encrypt` is set.
+    pub(crate) fn parse_with_password(self, password: Option<&str>) -> Result<String> {
+        // Sample the private key, or derive it from the given seed.
+        let private_key = match self.seed {
+            Some(seed) => PrivateKey::try_from(parse_seed(&seed)?)?,
+            None => PrivateKey::new(&mut rand::thread_rng())?,
+        };
+        account_output(&private_key, self.keyfile.as_deref(), self.encrypt, password, self.json)
+    }
+}
+
+/// Derives the view key and address of an existing private key.
+/// The private key is read from `SNARKVM_PRIVATE_KEY`, a prompt, or stdin.
+#[derive(Debug, Parser)]
+pub struct ImportAccount {
+    /// The key file in which to save the account.
+    #[clap(long)]
+    pub keyfile: Option<PathBuf>,
+    /// Encrypts the key file with a password, read from `SNARKVM_KEYFILE_PASSWORD`, a prompt, or stdin.
+    #[clap(long, requires = "keyfile")]
+    pub encrypt: bool,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl ImportAccount {
+    /// Derives the view key and address of an existing private key.
+    pub fn parse(self) -> Result<String> {
+        // Read the private key.
+        let private_key = read_private_key()?;
+        // Read the key file password, if the key file is encrypted.
+        let password = read_new_password(self.encrypt)?;
+        self.parse_with(&private_key, password.as_deref())
+    }
+
+    /// Derives the view key and address of the given private key,
+    /// encrypting the key file with the given password if `encrypt` is set.
+    pub(crate) fn parse_with(self, private_key: &PrivateKey<CurrentNetwork>, password: Option<&str>) -> Result<String> {
+        account_output(private_key, self.keyfile.as_deref(), self.encrypt, password, self.json)
+    }
+}
+
+/// Signs a message with a private key.
+#[derive(Debug, Parser)]
+pub struct SignMessage {
+    /// The message, as a plaintext value (or as bytes, with `--raw`).
+    /// The signature is over the field elements of the plaintext, which matches `sign.verify`.
+    #[clap(long)]
+    pub message: String,
+    /// Signs the message as raw bytes. Such a signature cannot be checked with `sign.verify`.
+    #[clap(long)]
+    pub raw: bool,
+    /// The key file containing the private key used to sign the message.
+    /// If the key file is encrypted, its password is read from `SNARKVM_KEYFILE_PASSWORD`, a prompt, or stdin.
+    /// Without a key file, the private key is read from `SNARKVM_PRIVATE_KEY`, a prompt, or stdin.
+    #[clap(long)]
+    pub keyfile: Option<PathBuf>,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl SignMessage {
+    /// Signs a message with a private key.
+    pub fn parse(self) -> Result<String> {
+        // Read the key file password, if the key file is encrypted.
+        let password = match &self.keyfile {
+            Some(keyfile) => read_keyfile_password(keyfile)?,
+            None => None,
+        };
+        self.parse_with_password(password.as_deref())
+    }
+
+    /// Signs a message with a private key, decrypting the key file with the given password if it is encrypted.
+    pub(crate) fn parse_with_password(self, password: Option<&str>) -> Result<String> {
+        // Load the private key.
+        let private_key = match &self.keyfile {
+            Some(keyfile) => *KeyFile::open(keyfile, password)?.private_key(),
+            None => read_private_key()?,
+        };
+
+        // Sign the message.
+        let rng = &mut rand::thread_rng();
+        let signature = match self.raw {
+            true => private_key.sign_bytes(self.message.as_bytes(), rng)?,
+            false => private_key.sign(&parse_message(&self.message)?, rng)?,
+        };
+
+        // Prepare the output.
+        let message = format!("✅ Signed the message\n\n • Signature: {}", signature.to_string().bold());
+        let value = serde_json::json!({
+            "address": Address::try_from(&private_key)?.to_string(),
+            "signature": signature.to_string(),
+        });
+        format_output(self.json, message, value)
+    }
+}
+
+/// Verifies the signature of a message.
+#[derive(Debug, Parser)]
+pub struct VerifySignature {
+    /// The address of the signer.
+    #[clap(long)]
+    pub address: String,
+    /// The signature.
+    #[clap(long)]
+    pub signature: String,
+    /// The message, as a plaintext value (or as bytes, with `--raw`).
+    #[clap(long)]
+    pub message: String,
+    /// Verifies the message as raw bytes.
+    #[clap(long)]
+    pub raw: bool,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl VerifySignature {
+    /// Verifies the signature of a message.
+    pub fn parse(self) -> Result<String> {
+        // Parse the address and signature.
+        let address = Address::<CurrentNetwork>::from_str(&self.address)?;
+        let signature = Signature::<CurrentNetwork>::from_str(&self.signature)?;
+
+        // Verify the signature.
+        let is_valid = match self.raw {
+            true => signature.verify_bytes(&address, self.message.as_bytes()),
+            false => signature.verify(&address, &parse_message(&self.message)?),
+        };
+
+        // Prepare the output.
+        let message = match is_valid {
+            true => format!("✅ The signature is valid for '{}'", address.to_string().bold()),
+            false => format!("❌ The signature is invalid for '{}'", address.to_string().bold()),
+        };
+        let value = serde_json::json!({ "address": address.to_string(), "valid": is_valid });
+        format_output(self.json, message, value)
+    }
+}
+
+/// Reads a secret from the given environment variable if it is set, and otherwise
+/// prompts for it on a terminal (confirming it, if `confirm` is set), or reads a line from stdin.
+fn read_secret(env_var: &str, prompt: &str, confirm: bool) -> Result<String> {
+    // Read the secret from the environment variable, if it is set.
+    if let Ok(secret) = std::env::var(env_var) {
+        return Ok(secret);
+    }
+    // Prompt for the secret on a terminal, without echoing it.
+    if atty::is(atty::Stream::Stdin) {
+        let secret = rpassword::prompt_password(prompt)?;
+        if confirm {
+            ensure!(secret == rpassword::prompt_password("Confirm the password: ")?, "The passwords do not match");
+        }
+        return Ok(secret);
+    }
+    // Otherwise, read the secret from stdin.
+    let mut secret = String::new();
+    std::io::stdin().read_line(&mut secret)?;
+    Ok(secret.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Reads the key file password from `SNARKVM_KEYFILE_PASSWORD` if it is set, and otherwise
+/// prompts for it on a terminal (confirming it, if `confirm` is set), or reads a line from stdin.
+fn read_password(prompt: &str, confirm: bool) -> Result<String> {
+    read_secret(PASSWORD_ENV_VAR, prompt, confirm)
+}
+
+/// Reads the key file password, if the given key file is encrypted.
+pub(crate) fn read_keyfile_password(keyfile: &Path) -> Result<Option<String>> {
+    match KeyFile::<CurrentNetwork>::is_encrypted_at(keyfile)? {
+        true => Ok(Some(read_password("Enter the key file password: ", false)?)),
+        false => Ok(None),
+    }
+}
+
+/// Reads the private key from `SNARKVM_PRIVATE_KEY` if it is set, and otherwise
+/// prompts for it on a terminal, or reads a line from stdin.
+/// Note: The private key is never taken as a command-line argument, as those are visible to other processes.
+pub(crate) fn read_private_key() -> Result<PrivateKey<CurrentNetwork>> {
+    parse_private_key(&read_secret(PRIVATE_KEY_ENV_VAR, "Enter the private key: ", false)?)
+}
+
+/// Parses the given private key.
+pub(crate) fn parse_private_key(private_key: &str) -> Result<PrivateKey<CurrentNetwork>> {
+    PrivateKey::from_str(private_key.trim()).map_err(|error| anyhow!("Invalid private key: {error}"))
+}
+
+/// Reads a new key file password, confirming it, if the key file is encrypted.
+fn read_new_password(encrypt: bool) -> Result<Option<String>> {
+    match encrypt {
+        true => Ok(Some(read_password("Enter a password for the key file: ", true)?)),
+        false => Ok(None),
+    }
+}
+
+/// Parses the given account seed, as 32 bytes in hex, which are reduced to a field element.
+/// Note: A full-size seed is required, as a short seed (e.g. a small integer) can be brute-forced.
+fn parse_seed(seed: &str) -> Result<Field<CurrentNetwork>> {
+    let bytes = hex::decode(seed.trim_start_matches("0x")).map_err(|error| anyhow!("Invalid seed: {error}"))?;
+    ensure!(bytes.len() == 32, "Invalid seed: expected 32 bytes in hex, found {} bytes", bytes.len());
+    Ok(Field::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&bytes)))
+}
+
+/// Parses the given message as a plaintext value, and returns its field elements.
+/// This is the same encoding that `sign.verify` uses for its message operand.
+fn parse_message(message: &str) -> Result<Vec<Field<CurrentNetwork>>> {
+    let plaintext = Plaintext::<CurrentNetwork>::from_str(message.trim())
+        .map_err(|error| anyhow!("Invalid message '{message}': {error}"))?;
+    Value::Plaintext(plaintext).to_fields()
+}
+
+/// Returns the output for the given account, saving it to a key file if one is given.
+/// If `encrypt` is set, the key file is encrypted with the given password.
+fn account_output(
+    private_key: &PrivateKey<CurrentNetwork>,
+    keyfile: Option<&Path>,
+    encrypt: bool,
+    password: Option<&str>,
+    json: bool,
+) -> Result<String> {
+    // Derive the view key and address.
+    let view_key = ViewKey::try_from(private_key)?;
+    let address = Address::try_from(private_key)?;
+
+    // Prepare the output.
+    let (message, value) = match keyfile {
+        // Save the account to the key file, and omit the private key from the output.
+        Some(keyfile) => {
+            let password = match encrypt {
+                true => Some(password.ok_or_else(|| anyhow!("Missing the password to encrypt the key file"))?),
+                false => None,
+            };
+            let keyfile = KeyFile::create(keyfile, private_key, password)?;
+            let message = format!(
+                "✅ Saved the account to \"{}\"\n\n • View Key: {}\n • Address: {}",
+                keyfile.path().display(),
+                view_key.to_string().bold(),
+                address.to_string().bold()
+            );
+            let value = serde_json::json!({
+                "keyfile": keyfile.path().display().to_string(),
+                "encrypted": keyfile.is_encrypted(),
+                "view_key": view_key.to_string(),
+                "address": address.to_string(),
+            });
+            (message, value)
+        }
+        None => {
+            let message = format!(
+                " • Private Key: {}\n • View Key: {}\n • Address: {}",
+                private_key.to_string().bold(),
+                view_key.to_string().bold(),
+                address.to_string().bold()
+            );
+            let value = serde_json::json!({
+                "private_key": private_key.to_string(),
+                "view_key": view_key.to_string(),
+                "address": address.to_string(),
+            });
+            (message, value)
+        }
+    };
+    format_output(json, message, value)
+}
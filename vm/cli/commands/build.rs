@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use colored::Colorize;
+
+/// Compiles an Aleo package.
+#[derive(Debug, Parser)]
+pub struct Build {
+    /// The endpoint used to synthesize the proving and verifying keys, if any.
+    #[clap(long)]
+    pub endpoint: Option<String>,
+    /// The package directory, which defaults to the current directory.
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl Build {
+    /// Compiles an Aleo package.
+    pub fn parse(self) -> Result<String> {
+        // Open the package.
+        let package = Package::<CurrentNetwork>::open(&package_directory(self.path)?)?;
+        // Build the package, if the package requires building.
+        package.build::<Aleo>(self.endpoint)?;
+
+        // Prepare the output.
+        let program_id = package.program_id().to_string();
+        let build_directory = package.build_directory().display().to_string();
+        let message = format!("✅ Built '{}' (in \"{build_directory}\")", program_id.bold());
+        let value = serde_json::json!({ "program_id": program_id, "build_directory": build_directory });
+        format_output(self.json, message, value)
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Cleans the build directory of an Aleo package.
+#[derive(Debug, Parser)]
+pub struct Clean {
+    /// The package directory, which defaults to the current directory.
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl Clean {
+    /// Cleans the build directory of an Aleo package.
+    pub fn parse(self) -> Result<String> {
+        // Retrieve the package directory.
+        let directory = package_directory(self.path)?;
+        // Remove the build directory.
+        Package::<CurrentNetwork>::clean(&directory)?;
+
+        // Prepare the output.
+        let build_directory = directory.join("build").display().to_string();
+        let message = format!("✅ Cleaned the build directory (in \"{build_directory}\")");
+        let value = serde_json::json!({ "build_directory": build_directory });
+        format_output(self.json, message, value)
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use colored::Colorize;
+
+/// Deploys an Aleo package.
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// The endpoint to send the deployment to, if any.
+    #[clap(long)]
+    pub endpoint: Option<String>,
+    /// The package directory, which defaults to the current directory.
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl Deploy {
+    /// Deploys an Aleo package.
+    pub fn parse(self) -> Result<String> {
+        // Open the package.
+        let package = Package::<CurrentNetwork>::open(&package_directory(self.path)?)?;
+        // Deploy the package.
+        let deployment = package.deploy::<Aleo>(self.endpoint)?;
+
+        // Prepare the output.
+        let program_id = deployment.program_id().to_string();
+        let message = format!("✅ Deployed '{}' (edition {})", program_id.bold(), deployment.edition());
+        let value = serde_json::json!({
+            "program_id": program_id,
+            "edition": deployment.edition(),
+            "deployment": serde_json::to_value(&deployment)?,
+        });
+        format_output(self.json, message, value)
+    }
+}
@@ -0,0 +1,448 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod account;
+pub use account::*;
+
+mod build;
+pub use build::*;
+
+mod clean;
+pub use clean::*;
+
+mod deploy;
+pub use deploy::*;
+
+mod execute;
+pub use execute::*;
+
+mod new;
+pub use new::*;
+
+mod run;
+pub use run::*;
+
+mod update;
+pub use update::*;
+
+mod verify;
+pub use verify::*;
+
+use crate::{
+    file::KeyFile,
+    package::Package,
+    prelude::{Identifier, Locator, PrivateKey, Response, Testnet3, Value},
+    synthesizer::Execution,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use clap::Parser;
+use core::str::FromStr;
+use std::path::{Path, PathBuf};
+
+pub(crate) type CurrentNetwork = Testnet3;
+pub(crate) type Aleo = crate::circuit::AleoV0;
+
+#[derive(Debug, Parser)]
+pub enum Command {
+    #[clap(subcommand)]
+    Account(Account),
+    #[clap(name = "build")]
+    Build(Build),
+    #[clap(name = "clean")]
+    Clean(Clean),
+    #[clap(name = "deploy")]
+    Deploy(Deploy),
+    #[clap(name = "execute")]
+    Execute(Execute),
+    #[clap(name = "new")]
+    New(New),
+    #[clap(name = "run")]
+    Run(Run),
+    #[clap(name = "update")]
+    Update(Update),
+    #[clap(name = "verify")]
+    Verify(Verify),
+}
+
+impl Command {
+    /// Parses the command.
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::Account(command) => command.parse(),
+            Self::Build(command) => command.parse(),
+            Self::Clean(command) => command.parse(),
+            Self::Deploy(command) => command.parse(),
+            Self::Execute(command) => command.parse(),
+            Self::New(command) => command.parse(),
+            Self::Run(command) => command.parse(),
+            Self::Update(command) => command.parse(),
+            Self::Verify(command) => command.parse(),
+        }
+    }
+}
+
+/// Returns the package directory, which defaults to the current working directory.
+fn package_directory(path: Option<PathBuf>) -> Result<PathBuf> {
+    match path {
+        Some(path) => Ok(path),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+/// Returns the private key from the given key file, or from `SNARKVM_PRIVATE_KEY` if it is set,
+/// which defaults to the development private key of the package.
+fn load_private_key(keyfile: Option<&Path>, package: &Package<CurrentNetwork>) -> Result<PrivateKey<CurrentNetwork>> {
+    match keyfile {
+        Some(keyfile) => {
+            // Read the key file password, if the key file is encrypted.
+            let password = read_keyfile_password(keyfile)?;
+            Ok(*KeyFile::open(keyfile, password.as_deref())?.private_key())
+        }
+        None => match std::env::var(PRIVATE_KEY_ENV_VAR) {
+            Ok(private_key) => parse_private_key(&private_key),
+            Err(_) => Ok(*package.manifest_file().development_private_key()),
+        },
+    }
+}
+
+/// Parses the given function inputs into values.
+fn parse_inputs(inputs: &[String]) -> Result<Vec<Value<CurrentNetwork>>> {
+    inputs
+        .iter()
+        .map(|input| Value::from_str(input).map_err(|error| anyhow!("Failed to parse input '{input}': {error}")))
+        .collect()
+}
+
+/// Executes the given function of the package, and returns the locator, response, and execution.
+fn execute_function(
+    path: Option<PathBuf>,
+    function: &str,
+    inputs: &[String],
+    keyfile: Option<&Path>,
+    endpoint: Option<String>,
+) -> Result<(Locator<CurrentNetwork>, Response<CurrentNetwork>, Execution<CurrentNetwork>)> {
+    // Open the package.
+    let package = Package::<CurrentNetwork>::open(&package_directory(path)?)?;
+    // Parse the function name.
+    let function_name = Identifier::from_str(function)?;
+    // Parse the function inputs.
+    let inputs = parse_inputs(inputs)?;
+    // Load the private key.
+    let private_key = load_private_key(keyfile, &package)?;
+
+    // Execute the function.
+    let (response, execution, _, _) =
+        package.run::<Aleo, _>(endpoint, &private_key, function_name, &inputs, &mut rand::thread_rng())?;
+    // Prepare the locator.
+    let locator = Locator::from_str(&format!("{}/{function_name}", package.program_id()))?;
+    // Return the locator, response, and execution.
+    Ok((locator, response, execution))
+}
+
+/// Returns the command output, formatted as JSON if `json` is set, or as the given message otherwise.
+fn format_output(json: bool, message: String, value: serde_json::Value) -> Result<String> {
+    match json {
+        true => Ok(serde_json::to_string_pretty(&value)?),
+        false => Ok(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::CLI;
+
+    #[test]
+    fn test_parse_run() {
+        let cli = CLI::try_parse_from(["snarkvm", "run", "hello", "1u32", "2u32", "--json"]).unwrap();
+        match cli.command {
+            Command::Run(run) => {
+                assert_eq!(run.function, "hello");
+                assert_eq!(run.inputs, vec!["1u32".to_string(), "2u32".to_string()]);
+                assert!(run.json);
+                assert!(run.endpoint.is_none());
+            }
+            command => panic!("Unexpected command: {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_deploy_with_endpoint() {
+        let cli = CLI::try_parse_from(["snarkvm", "deploy", "--endpoint", "http://localhost:4180"]).unwrap();
+        match cli.command {
+            Command::Deploy(deploy) => {
+                assert_eq!(deploy.endpoint.as_deref(), Some("http://localhost:4180"));
+                assert!(!deploy.json);
+            }
+            command => panic!("Unexpected command: {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let cli = CLI::try_parse_from(["snarkvm", "verify", "--file", "transaction.json", "--skip-inclusion"]).unwrap();
+        match cli.command {
+            Command::Verify(verify) => {
+                assert_eq!(verify.file, Some(PathBuf::from("transaction.json")));
+                assert!(verify.skip_inclusion);
+                assert!(verify.path.is_none() && verify.programs.is_none());
+            }
+            command => panic!("Unexpected command: {command:?}"),
+        }
+        // Ensure a package and a directory of programs cannot both be given.
+        assert!(CLI::try_parse_from(["snarkvm", "verify", "--path", "hello", "--programs", "programs"]).is_err());
+    }
+
+    #[test]
+    fn test_account_sign_and_verify() {
+        let directory = tempfile::tempdir().unwrap();
+        let keyfile = directory.path().join("account.json");
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).unwrap();
+        let address = crate::prelude::Address::try_from(&private_key).unwrap().to_string();
+        KeyFile::create(&keyfile, &private_key, None).unwrap();
+
+        for (message, raw) in [("[1field, 2field]", false), ("hello world", true)] {
+            // Sign the message.
+            let mut args = vec!["snarkvm", "account", "sign", "--message", message, "--json"];
+            args.extend(["--keyfile", keyfile.to_str().unwrap()]);
+            if raw {
+                args.push("--raw");
+            }
+            let output = CLI::try_parse_from(args).unwrap().command.parse().unwrap();
+            let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+            let signature = json["signature"].as_str().unwrap();
+
+            // Verify the signature.
+            let verify = |message: &str| {
+                let mut args = vec!["snarkvm", "account", "verify", "--json"];
+                args.extend(["--address", &address, "--signature", signature, "--message", message]);
+                if raw {
+                    args.push("--raw");
+                }
+                let output = CLI::try_parse_from(args).unwrap().command.parse().unwrap();
+                serde_json::from_str::<serde_json::Value>(&output).unwrap()["valid"].as_bool().unwrap()
+            };
+            assert!(verify(message));
+            assert!(!verify(if raw { "goodbye world" } else { "[1field, 3field]" }));
+        }
+
+        // Ensure the private key is not accepted as a command-line argument.
+        let private_key = private_key.to_string();
+        assert!(
+            CLI::try_parse_from(["snarkvm", "account", "sign", "--message", "1field", "--private-key", &private_key])
+                .is_err()
+        );
+        assert!(CLI::try_parse_from(["snarkvm", "account", "import", &private_key]).is_err());
+        assert!(CLI::try_parse_from(["snarkvm", "run", "hello", "--private-key", &private_key]).is_err());
+        assert!(CLI::try_parse_from(["snarkvm", "execute", "hello", "--private-key", &private_key]).is_err());
+    }
+
+    #[test]
+    fn test_account_sign_and_sign_verify() {
+        use crate::{
+            prelude::{Address, Literal, Plaintext, Signature},
+            synthesizer::{Process, Program},
+        };
+
+        let rng = &mut rand::thread_rng();
+        let directory = tempfile::tempdir().unwrap();
+        let keyfile = directory.path().join("account.json");
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        KeyFile::create(&keyfile, &private_key, None).unwrap();
+
+        // Sign a message with the CLI.
+        let message = "{ amount: 5u64, recipients: [1field, 2field] }";
+        let args =
+            ["snarkvm", "account", "sign", "--message", message, "--keyfile", keyfile.to_str().unwrap(), "--json"];
+        let output = CLI::try_parse_from(args).unwrap().command.parse().unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        let signature = Signature::<CurrentNetwork>::from_str(json["signature"].as_str().unwrap()).unwrap();
+
+        // Initialize a program that checks the signature with `sign.verify`.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program signature_check.aleo;
+
+struct payment:
+    amount as u64;
+    recipients as [field; 2u32];
+
+function check:
+    input r0 as signature.private;
+    input r1 as address.private;
+    input r2 as payment.private;
+    sign.verify r0 r1 r2 into r3;
+    output r3 as boolean.private;",
+        )
+        .unwrap();
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Ensure `sign.verify` accepts the signature only for the signed message.
+        let mut check = |message: &str| {
+            let inputs = [
+                Value::Plaintext(Plaintext::from(Literal::Signature(Box::new(signature)))),
+                Value::Plaintext(Plaintext::from(Literal::Address(address))),
+                Value::Plaintext(Plaintext::from_str(message).unwrap()),
+            ];
+            let authorization =
+                process.authorize::<Aleo, _>(&private_key, program.id(), "check", inputs.into_iter(), rng).unwrap();
+            let response = process.evaluate::<Aleo>(authorization).unwrap();
+            response.outputs()[0].to_string()
+        };
+        assert_eq!(check(message), "true");
+        assert_eq!(check("{ amount: 6u64, recipients: [1field, 2field] }"), "false");
+    }
+
+    #[test]
+    fn test_account_new_with_seed() {
+        let directory = tempfile::tempdir().unwrap();
+        let keyfile = directory.path().join("account.json");
+
+        // Parse the given account command, providing the key file password explicitly.
+        let parse = |args: &[&str], password: Option<&str>| {
+            let output = match CLI::try_parse_from(args).unwrap().command {
+                Command::Account(Account::New(command)) => command.parse_with_password(password),
+                Command::Account(Account::Sign(command)) => command.parse_with_password(password),
+                command => panic!("Unexpected command: {command:?}"),
+            };
+            output.map(|output| serde_json::from_str::<serde_json::Value>(&output).unwrap())
+        };
+
+        // Ensure the same seed derives the same account.
+        let seed = "7b".repeat(32);
+        let first = parse(&["snarkvm", "account", "new", "--seed", &seed, "--json"], None).unwrap();
+        let second = parse(
+            &[
+                "snarkvm",
+                "account",
+                "new",
+                "--seed",
+                &format!("0x{seed}"),
+                "--keyfile",
+                keyfile.to_str().unwrap(),
+                "--encrypt",
+                "--json",
+            ],
+            Some("password"),
+        )
+        .unwrap();
+        assert_eq!(first["address"], second["address"]);
+        assert!(second["private_key"].is_null());
+        assert!(second["encrypted"].as_bool().unwrap());
+
+        // Ensure a seed that is not 32 bytes is rejected.
+        assert!(parse(&["snarkvm", "account", "new", "--seed", "123", "--json"], None).is_err());
+        assert!(parse(&["snarkvm", "account", "new", "--seed", "123field", "--json"], None).is_err());
+        assert!(parse(&["snarkvm", "account", "new", "--seed", &"7b".repeat(16), "--json"], None).is_err());
+
+        // Ensure the key file can be used to sign a message, only with the correct password.
+        let sign_args =
+            ["snarkvm", "account", "sign", "--message", "1field", "--keyfile", keyfile.to_str().unwrap(), "--json"];
+        let signed = parse(&sign_args, Some("password")).unwrap();
+        assert_eq!(signed["address"], first["address"]);
+        assert!(parse(&sign_args, Some("wrong password")).is_err());
+
+        // Ensure the password is not accepted as a command-line argument.
+        let args =
+            ["snarkvm", "account", "sign", "--message", "1field", "--keyfile", "account.json", "--password", "1"];
+        assert!(CLI::try_parse_from(args).is_err());
+    }
+
+    #[test]
+    fn test_parse_inputs() {
+        let inputs = parse_inputs(&["1u32".to_string(), "true".to_string()]).unwrap();
+        assert_eq!(inputs, vec![Value::from_str("1u32").unwrap(), Value::from_str("true").unwrap()]);
+        // Ensure an invalid input fails to parse.
+        assert!(parse_inputs(&["1u32".to_string(), "hello world".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_new_and_clean() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("hello");
+
+        // Create a new package.
+        let new = New { name: "hello".to_string(), path: Some(directory.path().to_path_buf()), json: true };
+        let output: serde_json::Value = serde_json::from_str(&new.parse().unwrap()).unwrap();
+        assert_eq!(output["program_id"], "hello.aleo");
+        assert!(Package::<CurrentNetwork>::open(&path).is_ok());
+
+        // Ensure the package cannot be created twice.
+        let new = New { name: "hello".to_string(), path: Some(directory.path().to_path_buf()), json: false };
+        assert!(new.parse().is_err());
+
+        // Clean the package.
+        let clean = Clean { path: Some(path.clone()), json: false };
+        assert!(clean.parse().is_ok());
+        assert!(!path.join("build").exists());
+    }
+
+    #[test]
+    fn test_verify_deploy_transaction() {
+        use crate::synthesizer::{Block, ConsensusMemory, ConsensusStore, Transaction, VM};
+
+        let rng = &mut rand::thread_rng();
+
+        // Sample a package.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+
+        // Initialize a VM with a genesis block.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = crate::prelude::ViewKey::try_from(&private_key).unwrap();
+        let vm = VM::from(ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap()).unwrap();
+        let genesis = Block::genesis(&vm, &private_key, rng).unwrap();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Deploy the program of the package, paying the estimated fee with a genesis record.
+        let record = genesis.transitions().flat_map(|transition| transition.records()).next().unwrap().1;
+        let credits = record.decrypt(&view_key).unwrap();
+        let deployment = vm.deploy(package.program(), rng).unwrap();
+        let fee_in_gates = vm.estimate_deployment_fee(&deployment).unwrap();
+        let (_, fee, _) = vm.execute_fee(&private_key, credits.clone(), fee_in_gates, None, rng).unwrap();
+        let transaction = Transaction::from_deployment(deployment.clone(), fee).unwrap();
+        // Ensure the transaction pays the minimum fee.
+        assert!(vm.verify_transaction(&transaction));
+
+        // Verifies the given transaction against the package, and returns the output.
+        let verify = |transaction: &Transaction<CurrentNetwork>| {
+            // Write the transaction to a file.
+            let file = directory.join("transaction.json");
+            std::fs::write(&file, transaction.to_string()).unwrap();
+            let verify = Verify {
+                file: Some(file),
+                path: Some(directory.clone()),
+                programs: None,
+                skip_inclusion: false,
+                json: true,
+            };
+            serde_json::from_str::<serde_json::Value>(&verify.parse().unwrap()).unwrap()
+        };
+
+        // Ensure the deployment is verified against the package, which already contains the deployed program.
+        let output = verify(&transaction);
+        assert_eq!(output["valid"], true, "{output}");
+
+        // Ensure a deployment that pays less than the minimum fee is invalid.
+        let (_, fee, _) = vm.execute_fee(&private_key, credits, fee_in_gates - 1, None, rng).unwrap();
+        let output = verify(&Transaction::from_deployment(deployment, fee).unwrap());
+        assert_eq!(output["valid"], false, "{output}");
+        let check = output["checks"].as_array().unwrap().iter().find(|check| check["check"] == "minimum fee").unwrap();
+        assert_eq!(check["valid"], false, "{output}");
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::prelude::ProgramID;
+
+use colored::Colorize;
+
+/// Creates a new Aleo package.
+#[derive(Debug, Parser)]
+pub struct New {
+    /// The program name.
+    pub name: String,
+    /// The directory in which to create the package, which defaults to the current directory.
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl New {
+    /// Creates a new Aleo package.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program ID.
+        let program_id = ProgramID::<CurrentNetwork>::from_str(&format!("{}.aleo", self.name))?;
+        // Prepare the package directory.
+        let directory = package_directory(self.path)?.join(&self.name);
+
+        // Create the package.
+        Package::create(&directory, &program_id)?;
+
+        // Prepare the output.
+        let message =
+            format!("✅ Created an Aleo program '{}' (in \"{}\")", program_id.to_string().bold(), directory.display());
+        let value =
+            serde_json::json!({ "program_id": program_id.to_string(), "path": directory.display().to_string() });
+        format_output(self.json, message, value)
+    }
+}
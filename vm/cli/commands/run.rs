@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use colored::Colorize;
+
+/// Executes an Aleo program function locally.
+#[derive(Debug, Parser)]
+pub struct Run {
+    /// The function name.
+    pub function: String,
+    /// The function inputs.
+    pub inputs: Vec<String>,
+    /// The key file containing the private key used to authorize the function call.
+    /// Without a key file, the private key is read from `SNARKVM_PRIVATE_KEY` if it is set,
+    /// and otherwise defaults to the development private key.
+    #[clap(long)]
+    pub keyfile: Option<PathBuf>,
+    /// The endpoint used to synthesize the proving and verifying keys, if any.
+    #[clap(long)]
+    pub endpoint: Option<String>,
+    /// The package directory, which defaults to the current directory.
+    #[clap(long)]
+    pub path: Option<PathBuf>,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl Run {
+    /// Executes an Aleo program function locally, and returns its outputs.
+    pub fn parse(self) -> Result<String> {
+        // Execute the function.
+        let (locator, response, _) =
+            execute_function(self.path, &self.function, &self.inputs, self.keyfile.as_deref(), self.endpoint)?;
+
+        // Prepare the output.
+        let outputs = response.outputs().iter().map(|output| output.to_string()).collect::<Vec<_>>();
+        let mut message = format!("✅ Executed '{}'", locator.to_string().bold());
+        if !outputs.is_empty() {
+            message.push_str("\n\n➡️  Outputs\n");
+            outputs.iter().for_each(|output| message.push_str(&format!("\n • {output}")));
+        }
+        let value = serde_json::json!({ "locator": locator.to_string(), "outputs": outputs });
+        format_output(self.json, message, value)
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    file::{AleoFile, VerifierFile},
+    prelude::{has_duplicates, FromBytes, ProgramID},
+    synthesizer::{check_minimum_fee, Process, Program, Transaction},
+};
+
+use colored::Colorize;
+use std::{io::Read, path::Path};
+
+/// Verifies a transaction offline, against the programs of a local package or directory.
+/// Note: The checks that require the ledger state, such as whether a record is already spent, are not performed.
+#[derive(Debug, Parser)]
+pub struct Verify {
+    /// The file containing the transaction (as JSON or bytes), which defaults to stdin.
+    #[clap(long)]
+    pub file: Option<PathBuf>,
+    /// The package directory containing the programs, which defaults to the current directory.
+    #[clap(long, conflicts_with = "programs")]
+    pub path: Option<PathBuf>,
+    /// The directory containing the programs as `{program}.aleo` files, with their verifying keys in `{name}-{network}/`.
+    #[clap(long)]
+    pub programs: Option<PathBuf>,
+    /// Skips the verification of the inclusion proofs.
+    #[clap(long)]
+    pub skip_inclusion: bool,
+    /// Prints the output as JSON.
+    #[clap(long)]
+    pub json: bool,
+}
+
+impl Verify {
+    /// Verifies the transaction, and returns a report of each check.
+    pub fn parse(self) -> Result<String> {
+        // Read the transaction.
+        let transaction = read_transaction(self.file.as_deref())?;
+        // Load the programs and the directories of their verifying keys.
+        let programs = match self.programs {
+            Some(directory) => load_directory(&directory)?,
+            None => load_package(&package_directory(self.path)?)?,
+        };
+        // Construct the process, without the deployed program, if the transaction is a deployment.
+        let deployed_program_id = match &transaction {
+            Transaction::Deploy(_, deployment, _) => Some(*deployment.program_id()),
+            Transaction::Execute(..) => None,
+        };
+        let process = load_process(programs, deployed_program_id.as_ref())?;
+
+        // Verify the transaction.
+        let checks = match self.skip_inclusion {
+            true => verify_transaction::<false>(&process, &transaction),
+            false => verify_transaction::<true>(&process, &transaction),
+        };
+        let is_valid = checks.iter().all(|(_, outcome)| outcome.is_ok());
+
+        // Prepare the output.
+        let mut message = String::new();
+        for (check, outcome) in &checks {
+            match outcome {
+                Ok(()) => message.push_str(&format!("✅ {check}\n")),
+                Err(error) => message.push_str(&format!("❌ {check}: {error}\n")),
+            }
+        }
+        let transaction_id = transaction.id().to_string();
+        match is_valid {
+            true => message.push_str(&format!("\n✅ Transaction '{}' is valid", transaction_id.bold())),
+            false => message.push_str(&format!("\n❌ Transaction '{}' is invalid", transaction_id.bold())),
+        }
+        let value = serde_json::json!({
+            "transaction_id": transaction_id,
+            "valid": is_valid,
+            "checks": checks.iter().map(|(check, outcome)| serde_json::json!({
+                "check": check,
+                "valid": outcome.is_ok(),
+                "error": outcome.as_ref().err().map(|error| error.to_string()),
+            })).collect::<Vec<_>>(),
+        });
+        format_output(self.json, message, value)
+    }
+}
+
+/// Reads a transaction (as JSON or bytes) from the given file, or from stdin if no file is given.
+fn read_transaction(file: Option<&Path>) -> Result<Transaction<CurrentNetwork>> {
+    // Read the contents.
+    let bytes = match file {
+        Some(file) => std::fs::read(file)?,
+        None => {
+            let mut bytes = Vec::new();
+            std::io::stdin().read_to_end(&mut bytes)?;
+            bytes
+        }
+    };
+    // Parse the transaction from JSON, or from bytes otherwise.
+    match std::str::from_utf8(&bytes) {
+        Ok(string) if string.trim_start().starts_with('{') => Transaction::from_str(string.trim()),
+        _ => Transaction::from_bytes_le(&bytes),
+    }
+}
+
+/// Returns the programs of the package at the given directory, with the directories of their verifying keys.
+fn load_package(directory: &Path) -> Result<Vec<(Program<CurrentNetwork>, PathBuf)>> {
+    // Open the package.
+    let package = Package::<CurrentNetwork>::open(directory)?;
+
+    // Load the imported programs.
+    let mut programs = Vec::new();
+    for program_id in package.program().imports().keys() {
+        let import = AleoFile::<CurrentNetwork>::open(&package.imports_directory(), program_id, false)?;
+        let import_build_directory =
+            package.build_directory().join(format!("{}-{}", program_id.name(), program_id.network()));
+        programs.push((import.program().clone(), import_build_directory));
+    }
+    // Load the main program.
+    programs.push((package.program().clone(), package.build_directory()));
+
+    Ok(programs)
+}
+
+/// Returns the programs in the given directory, with the directories of their verifying keys.
+fn load_directory(directory: &Path) -> Result<Vec<(Program<CurrentNetwork>, PathBuf)>> {
+    let mut programs = Vec::new();
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.extension().and_then(|extension| extension.to_str()) == Some("aleo") {
+            let program = Program::<CurrentNetwork>::from_str(&std::fs::read_to_string(&path)?)?;
+            let keys_directory = directory.join(format!("{}-{}", program.id().name(), program.id().network()));
+            programs.push((program, keys_directory));
+        }
+    }
+    Ok(programs)
+}
+
+/// Returns a process with the given programs and their verifying keys.
+///
+/// If a deployed program ID is given, the deployed program, and every program that imports it,
+/// are left out of the process, as the deployment is verified against its imports only.
+fn load_process(
+    mut programs: Vec<(Program<CurrentNetwork>, PathBuf)>,
+    deployed_program_id: Option<&ProgramID<CurrentNetwork>>,
+) -> Result<Process<CurrentNetwork>> {
+    // Remove the deployed program, and every program that (transitively) imports it.
+    if let Some(deployed_program_id) = deployed_program_id {
+        let mut excluded = vec![*deployed_program_id];
+        loop {
+            let num_programs = programs.len();
+            programs.retain(|(program, _)| {
+                let is_excluded =
+                    excluded.contains(program.id()) || program.imports().keys().any(|import| excluded.contains(import));
+                if is_excluded && !excluded.contains(program.id()) {
+                    excluded.push(*program.id());
+                }
+                !is_excluded
+            });
+            if programs.len() == num_programs {
+                break;
+            }
+        }
+    }
+
+    // Construct the process.
+    let mut process = Process::load()?;
+
+    // Add the programs, ensuring each program is added after its imports.
+    let mut pending: Vec<_> = programs.iter().map(|(program, _)| program).collect();
+    while !pending.is_empty() {
+        // Track the number of programs remaining before this pass.
+        let num_pending = pending.len();
+        // Add every program whose imports have already been added.
+        let mut remaining = Vec::with_capacity(num_pending);
+        for program in pending {
+            match program.imports().keys().all(|import| process.contains_program(import)) {
+                true => process.add_program(program)?,
+                false => remaining.push(program),
+            }
+        }
+        // Ensure progress was made, otherwise the remaining programs have missing imports.
+        ensure!(remaining.len() < num_pending, "Failed to load programs with missing imports");
+        pending = remaining;
+    }
+
+    // Load the verifying keys of each program.
+    for (program, keys_directory) in &programs {
+        load_verifying_keys(&process, program, keys_directory)?;
+    }
+
+    Ok(process)
+}
+
+/// Inserts the verifying keys in the given directory, for each function in the given program.
+fn load_verifying_keys(
+    process: &Process<CurrentNetwork>,
+    program: &Program<CurrentNetwork>,
+    directory: &Path,
+) -> Result<()> {
+    for function_name in program.functions().keys() {
+        // Note: A missing verifying key is reported when verifying the corresponding transition.
+        if VerifierFile::<CurrentNetwork>::exists_at(directory, function_name) {
+            let verifier = VerifierFile::open(directory, function_name)?;
+            process.insert_verifying_key(program.id(), function_name, verifier.verifying_key().clone())?;
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the given transaction, and returns the outcome of each check.
+fn verify_transaction<const VERIFY_INCLUSION: bool>(
+    process: &Process<CurrentNetwork>,
+    transaction: &Transaction<CurrentNetwork>,
+) -> Vec<(String, Result<()>)> {
+    let mut checks = Vec::new();
+
+    // Ensure the transaction ID is correct.
+    let outcome = transaction.to_root().and_then(|root| match *transaction.id() == root {
+        true => Ok(()),
+        false => bail!("Incorrect transaction ID"),
+    });
+    checks.push(("transaction ID".to_string(), outcome));
+
+    // Ensure the transaction does not contain duplicate elements.
+    let outcome = (|| {
+        ensure!(!has_duplicates(transaction.transition_ids()), "Found duplicate transition IDs");
+        ensure!(!has_duplicates(transaction.transition_public_keys()), "Found duplicate transition public keys");
+        ensure!(!has_duplicates(transaction.serial_numbers()), "Found duplicate serial numbers");
+        ensure!(!has_duplicates(transaction.commitments()), "Found duplicate commitments");
+        ensure!(!has_duplicates(transaction.nonces()), "Found duplicate nonces");
+        Ok(())
+    })();
+    checks.push(("duplicate elements".to_string(), outcome));
+
+    // Ensure the fee covers the cost of the transaction.
+    checks.push(("minimum fee".to_string(), check_minimum_fee(process, transaction)));
+
+    match transaction {
+        Transaction::Deploy(_, deployment, fee) => {
+            // Verify the deployment.
+            checks.push(("deployment size".to_string(), Transaction::check_deployment_size(deployment)));
+            let outcome = process.verify_deployment::<Aleo, _>(deployment, &mut rand::thread_rng());
+            checks.push((format!("deployment of '{}'", deployment.program_id()), outcome));
+            // Verify the fee.
+            checks.push((format!("fee '{}'", fee.id()), process.verify_fee::<VERIFY_INCLUSION>(fee)));
+        }
+        Transaction::Execute(_, execution, additional_fee) => {
+            // Verify the execution.
+            checks.push(("execution size".to_string(), Transaction::check_execution_size(execution)));
+            match process.verify_execution_transitions::<VERIFY_INCLUSION>(execution) {
+                Ok(outcomes) => {
+                    checks.push(("execution".to_string(), Ok(())));
+                    // Report the outcome of each transition.
+                    for (transition, (_, outcome)) in execution.transitions().zip(outcomes) {
+                        let check = format!(
+                            "transition '{}' ({}/{})",
+                            transition.id(),
+                            transition.program_id(),
+                            transition.function_name()
+                        );
+                        checks.push((check, outcome));
+                    }
+                }
+                Err(error) => checks.push(("execution".to_string(), Err(error))),
+            }
+            // Verify the additional fee, if it exists.
+            if let Some(fee) = additional_fee {
+                checks.push((format!("fee '{}'", fee.id()), process.verify_fee::<VERIFY_INCLUSION>(fee)));
+            }
+        }
+    }
+    checks
+}
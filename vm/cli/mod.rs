@@ -17,6 +17,9 @@
 mod cli;
 pub use cli::*;
 
+mod commands;
+pub use commands::*;
+
 mod errors;
 pub use errors::*;
 
@@ -0,0 +1,313 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    console::network::prelude::*,
+    prelude::{Address, Field, PrivateKey},
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+};
+use core::str::FromStr;
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// The version of the key file format.
+const KEY_FILE_VERSION: u64 = 1;
+/// The base-2 logarithm of the scrypt cost parameter used to derive the encryption key from the password.
+const KEY_FILE_SCRYPT_LOG_N: u8 = 15;
+/// The scrypt block size parameter.
+const KEY_FILE_SCRYPT_R: u32 = 8;
+/// The scrypt parallelization parameter.
+const KEY_FILE_SCRYPT_P: u32 = 1;
+
+pub struct KeyFile<N: Network> {
+    /// The file path.
+    path: PathBuf,
+    /// The private key.
+    private_key: PrivateKey<N>,
+    /// The address.
+    address: Address<N>,
+    /// A boolean indicator of whether the private key is encrypted.
+    is_encrypted: bool,
+}
+
+impl<N: Network> KeyFile<N> {
+    /// Creates a new key file at the given path, for the given private key.
+    /// If a password is given, the private key is encrypted, otherwise it is stored in plaintext.
+    ///
+    /// The encryption key is derived from the password with scrypt, and the account seed is encrypted
+    /// with ChaCha20-Poly1305, authenticating the address as associated data.
+    /// On Unix, the file is only readable and writable by its owner.
+    pub fn create(path: &Path, private_key: &PrivateKey<N>, password: Option<&str>) -> Result<Self> {
+        // Ensure the file path does not already exist.
+        ensure!(!path.exists(), "Key file already exists: '{}'", path.display());
+
+        // Derive the address.
+        let address = Address::try_from(private_key)?;
+
+        // Construct the key file string.
+        let key_file_string = match password {
+            Some(password) => {
+                // Sample a random salt and nonce.
+                let rng = &mut rand::thread_rng();
+                let salt: [u8; 32] = rng.gen();
+                let nonce: [u8; 12] = rng.gen();
+                // Derive the encryption key from the password.
+                let encryption_key = derive_encryption_key(password, &salt)?;
+                // Encrypt the account seed, authenticating the address.
+                let address_string = address.to_string();
+                let plaintext = private_key.seed().to_bytes_le()?;
+                let ciphertext = ChaCha20Poly1305::new(&encryption_key.into())
+                    .encrypt(&nonce.into(), Payload { msg: &plaintext, aad: address_string.as_bytes() })
+                    .map_err(|_| anyhow!("Failed to encrypt the private key."))?;
+                let (salt, nonce, ciphertext) = (hex::encode(salt), hex::encode(nonce), hex::encode(ciphertext));
+                format!(
+                    r#"{{
+    "version": {KEY_FILE_VERSION},
+    "address": "{address}",
+    "encryption": {{
+        "kdf": "scrypt",
+        "log_n": {KEY_FILE_SCRYPT_LOG_N},
+        "r": {KEY_FILE_SCRYPT_R},
+        "p": {KEY_FILE_SCRYPT_P},
+        "salt": "{salt}",
+        "cipher": "chacha20-poly1305",
+        "nonce": "{nonce}",
+        "ciphertext": "{ciphertext}"
+    }}
+}}
+"#
+                )
+            }
+            None => format!(
+                r#"{{
+    "version": {KEY_FILE_VERSION},
+    "address": "{address}",
+    "private_key": "{private_key}"
+}}
+"#
+            ),
+        };
+
+        // Create the file, ensuring it is only accessible by its owner.
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        // Write the file.
+        options.open(path)?.write_all(key_file_string.as_bytes())?;
+
+        // Return the key file.
+        Ok(Self { path: path.to_path_buf(), private_key: *private_key, address, is_encrypted: password.is_some() })
+    }
+
+    /// Opens the key file at the given path, decrypting the private key with the given password if it is encrypted.
+    pub fn open(path: &Path, password: Option<&str>) -> Result<Self> {
+        // Read the key file.
+        let json = read_key_file(path)?;
+
+        // Retrieve the address.
+        let address_string = json["address"].as_str().ok_or_else(|| anyhow!("Address not found."))?;
+        let address = Address::from_str(address_string)?;
+
+        // Retrieve the private key.
+        let (private_key, is_encrypted) = match json["encryption"].is_object() {
+            true => {
+                // Ensure a password is given.
+                let password = match password {
+                    Some(password) => password,
+                    None => bail!("The key file is encrypted, a password is required."),
+                };
+
+                // Retrieve the encryption parameters.
+                let encryption = &json["encryption"];
+                let field = |name: &str| {
+                    encryption[name].as_str().ok_or_else(|| anyhow!("Encryption field '{name}' not found."))
+                };
+                let parameter = |name: &str| {
+                    encryption[name].as_u64().ok_or_else(|| anyhow!("Encryption parameter '{name}' not found."))
+                };
+                ensure!(field("kdf")? == "scrypt", "Unsupported key derivation function: {}", field("kdf")?);
+                ensure!(field("cipher")? == "chacha20-poly1305", "Unsupported cipher: {}", field("cipher")?);
+                // Ensure the scrypt parameters are the supported ones, as a key file is untrusted input,
+                // and larger parameters would make the key derivation arbitrarily expensive.
+                let (log_n, r, p) = (parameter("log_n")?, parameter("r")?, parameter("p")?);
+                ensure!(
+                    (log_n, r, p) == (KEY_FILE_SCRYPT_LOG_N.into(), KEY_FILE_SCRYPT_R.into(), KEY_FILE_SCRYPT_P.into()),
+                    "Unsupported scrypt parameters: log_n = {log_n}, r = {r}, p = {p}"
+                );
+                let salt = hex::decode(field("salt")?)?;
+                let nonce: [u8; 12] =
+                    hex::decode(field("nonce")?)?.try_into().map_err(|_| anyhow!("Invalid encryption nonce."))?;
+                let ciphertext = hex::decode(field("ciphertext")?)?;
+
+                // Derive the encryption key from the password.
+                let encryption_key = derive_encryption_key(password, &salt)?;
+                // Decrypt the account seed, authenticating the address.
+                // Note: A failed decryption indicates an incorrect password, or a tampered key file.
+                let plaintext = ChaCha20Poly1305::new(&encryption_key.into())
+                    .decrypt(&nonce.into(), Payload { msg: &ciphertext, aad: address_string.as_bytes() })
+                    .map_err(|_| anyhow!("Incorrect password for the key file."))?;
+                (PrivateKey::try_from(Field::<N>::from_bytes_le(&plaintext)?)?, true)
+            }
+            false => {
+                let private_key_string =
+                    json["private_key"].as_str().ok_or_else(|| anyhow!("Private key not found."))?;
+                (PrivateKey::from_str(private_key_string)?, false)
+            }
+        };
+
+        // Ensure the address matches the private key.
+        ensure!(address == Address::try_from(&private_key)?, "Address does not match private key.");
+
+        // Return the key file.
+        Ok(Self { path: path.to_path_buf(), private_key, address, is_encrypted })
+    }
+
+    /// Returns `true` if the key file at the given path contains an encrypted private key.
+    pub fn is_encrypted_at(path: &Path) -> Result<bool> {
+        Ok(read_key_file(path)?["encryption"].is_object())
+    }
+
+    /// Returns the file path.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the private key.
+    pub const fn private_key(&self) -> &PrivateKey<N> {
+        &self.private_key
+    }
+
+    /// Returns the address.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns `true` if the private key is encrypted in the key file.
+    pub const fn is_encrypted(&self) -> bool {
+        self.is_encrypted
+    }
+}
+
+/// Reads the key file at the given path, ensuring its version is supported.
+fn read_key_file(path: &Path) -> Result<serde_json::Value> {
+    // Ensure the file path exists.
+    ensure!(path.exists(), "Key file is missing: '{}'", path.display());
+
+    // Read the file to a string.
+    let key_file_string = fs::read_to_string(path)?;
+    let json: serde_json::Value = serde_json::from_str(&key_file_string)?;
+
+    // Ensure the version is supported.
+    let version = json["version"].as_u64().ok_or_else(|| anyhow!("Key file version not found."))?;
+    ensure!(version == KEY_FILE_VERSION, "Unsupported key file version: {version}");
+    Ok(json)
+}
+
+/// Derives a 256-bit encryption key from the given password and salt, using scrypt with the key file parameters.
+fn derive_encryption_key(password: &str, salt: &[u8]) -> Result<[u8; 32]> {
+    // Ensure the password is not empty.
+    ensure!(!password.is_empty(), "The password cannot be empty.");
+
+    let params = scrypt::Params::new(KEY_FILE_SCRYPT_LOG_N, KEY_FILE_SCRYPT_R, KEY_FILE_SCRYPT_P, 32)
+        .map_err(|error| anyhow!("Invalid scrypt parameters: {error}"))?;
+    let mut encryption_key = [0u8; 32];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut encryption_key)
+        .map_err(|error| anyhow!("Failed to derive the encryption key: {error}"))?;
+    Ok(encryption_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_key_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).unwrap();
+
+        // Ensure a plaintext key file can be opened without a password.
+        let path = directory.path().join("plaintext.json");
+        let key_file = KeyFile::create(&path, &private_key, None).unwrap();
+        assert!(!key_file.is_encrypted());
+        let key_file = KeyFile::<CurrentNetwork>::open(&path, None).unwrap();
+        assert_eq!(key_file.private_key(), &private_key);
+        assert!(!key_file.is_encrypted());
+
+        // Ensure an encrypted key file does not contain the private key.
+        let path = directory.path().join("encrypted.json");
+        KeyFile::create(&path, &private_key, Some("password")).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains(&private_key.to_string()));
+        assert!(!contents.contains(&private_key.seed().to_string()));
+
+        // Ensure an encrypted key file can only be opened with the correct password.
+        let key_file = KeyFile::<CurrentNetwork>::open(&path, Some("password")).unwrap();
+        assert_eq!(key_file.private_key(), &private_key);
+        assert_eq!(key_file.address(), &Address::try_from(&private_key).unwrap());
+        assert!(key_file.is_encrypted());
+        assert!(KeyFile::<CurrentNetwork>::is_encrypted_at(&path).unwrap());
+        assert!(KeyFile::<CurrentNetwork>::open(&path, None).is_err());
+        assert!(KeyFile::<CurrentNetwork>::open(&path, Some("incorrect")).is_err());
+
+        // Ensure the key file is only accessible by its owner.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        // Ensure a key file with a tampered ciphertext or address cannot be opened.
+        let mut json: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        let ciphertext = json["encryption"]["ciphertext"].as_str().unwrap().to_string();
+        let tampered_ciphertext = format!("{}{}", if &ciphertext[..1] == "0" { "1" } else { "0" }, &ciphertext[1..]);
+        json["encryption"]["ciphertext"] = serde_json::Value::from(tampered_ciphertext);
+        let tampered_path = directory.path().join("tampered_ciphertext.json");
+        fs::write(&tampered_path, json.to_string()).unwrap();
+        assert!(KeyFile::<CurrentNetwork>::open(&tampered_path, Some("password")).is_err());
+
+        let mut json: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).unwrap();
+        json["address"] = serde_json::Value::from(Address::try_from(&other_private_key).unwrap().to_string());
+        let tampered_path = directory.path().join("tampered_address.json");
+        fs::write(&tampered_path, json.to_string()).unwrap();
+        assert!(KeyFile::<CurrentNetwork>::open(&tampered_path, Some("password")).is_err());
+
+        // Ensure a key file with unsupported scrypt parameters cannot be opened.
+        for (parameter, value) in [("log_n", 64u64), ("r", u64::from(u32::MAX)), ("p", 2)] {
+            let mut json: serde_json::Value = serde_json::from_str(&contents).unwrap();
+            json["encryption"][parameter] = serde_json::Value::from(value);
+            let tampered_path = directory.path().join(format!("tampered_{parameter}.json"));
+            fs::write(&tampered_path, json.to_string()).unwrap();
+            let result = KeyFile::<CurrentNetwork>::open(&tampered_path, Some("password"));
+            assert!(result.err().unwrap().to_string().contains("Unsupported scrypt parameters"));
+        }
+
+        // Ensure an existing key file is not overwritten.
+        assert!(KeyFile::create(&path, &private_key, None).is_err());
+    }
+}
@@ -20,6 +20,9 @@ pub use aleo::AleoFile;
 mod avm;
 pub use avm::AVMFile;
 
+mod keyfile;
+pub use keyfile::KeyFile;
+
 mod manifest;
 pub use manifest::Manifest;
 